@@ -0,0 +1,17 @@
+use anchor_lang::prelude::*;
+
+#[error_code]
+pub enum HookError {
+    #[msg("Signer is not the authority for this mint's transfer hook.")]
+    UnauthorizedAuthority,
+    #[msg("Invalid transfer rules.")]
+    InvalidTransferRules,
+    #[msg("The hook may only be invoked during a Token-2022 transfer.")]
+    NotTransferring,
+    #[msg("Wallet is in its post-claim cooldown and cannot transfer yet.")]
+    TransferInCooldown,
+    #[msg("Transfer exceeds the per-slot limit for recently rewarded wallets.")]
+    SlotTransferLimitExceeded,
+    #[msg("Calculation error.")]
+    CalculationError,
+}
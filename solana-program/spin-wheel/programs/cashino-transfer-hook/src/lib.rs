@@ -0,0 +1,404 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_2022::spl_token_2022::{
+    extension::{transfer_hook::TransferHookAccount, BaseStateWithExtensions, StateWithExtensions},
+    state::Account as TokenAccountState,
+};
+use anchor_spl::token_interface::{Mint, TokenAccount};
+use spl_tlv_account_resolution::{
+    account::ExtraAccountMeta, seeds::Seed, state::ExtraAccountMetaList,
+};
+use spl_transfer_hook_interface::instruction::ExecuteInstruction;
+
+declare_id!("YWhVu8UhJ1kW8V8YMAJy1u2DeYjqHekcSr97M3Xv89W");
+
+mod error;
+pub use error::HookError;
+
+pub const EXTRA_ACCOUNT_METAS_SEED: &[u8] = b"extra-account-metas";
+pub const HOOK_CONFIG_SEED: &[u8] = b"hook_config";
+pub const WALLET_ACTIVITY_SEED: &[u8] = b"wallet_activity";
+/// Offset of the owner field in an SPL token account.
+const TOKEN_ACCOUNT_OWNER_OFFSET: u8 = 32;
+const EXTRA_ACCOUNT_META_COUNT: usize = 3;
+
+/// Anti-bot rules applied to wallets that recently claimed rewards.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, Debug, PartialEq, Eq)]
+pub struct TransferRules {
+    /// Seconds after a reward claim during which the wallet cannot send tokens.
+    pub post_claim_cooldown: i64,
+    /// Seconds after a reward claim during which `max_transfer_per_slot` applies.
+    pub rate_limit_window: i64,
+    /// Tokens the wallet may send per slot inside the rate limit window. Zero means no cap.
+    pub max_transfer_per_slot: u64,
+}
+
+impl TransferRules {
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.post_claim_cooldown >= 0 && self.rate_limit_window >= 0,
+            HookError::InvalidTransferRules
+        );
+        Ok(())
+    }
+}
+
+#[account]
+#[derive(Default, Debug)]
+pub struct HookConfig {
+    pub mint: Pubkey,
+    /// The mint authority at initialization; it alone may change rules and record claims.
+    pub authority: Pubkey,
+    pub rules: TransferRules,
+    pub bump: u8,
+}
+
+#[account]
+#[derive(Default, Debug)]
+pub struct WalletActivity {
+    pub owner: Pubkey,
+    pub mint: Pubkey,
+    pub total_sent: u64,
+    pub total_received: u64,
+    pub transfer_count: u64,
+    pub last_claim_at: i64,
+    pub last_claim_amount: u64,
+    /// Slot that `sent_in_slot` refers to.
+    pub current_slot: u64,
+    pub sent_in_slot: u64,
+    pub bump: u8,
+}
+
+impl WalletActivity {
+    /// Applies `rules` to an outgoing transfer and records it.
+    pub fn record_send(
+        &mut self,
+        amount: u64,
+        rules: &TransferRules,
+        now: i64,
+        slot: u64,
+    ) -> Result<()> {
+        if self.last_claim_at > 0 {
+            let since_claim = now.saturating_sub(self.last_claim_at);
+            require!(
+                since_claim >= rules.post_claim_cooldown,
+                HookError::TransferInCooldown
+            );
+            if slot != self.current_slot {
+                self.current_slot = slot;
+                self.sent_in_slot = 0;
+            }
+            self.sent_in_slot = self
+                .sent_in_slot
+                .checked_add(amount)
+                .ok_or(HookError::CalculationError)?;
+            if since_claim < rules.rate_limit_window && rules.max_transfer_per_slot > 0 {
+                require!(
+                    self.sent_in_slot <= rules.max_transfer_per_slot,
+                    HookError::SlotTransferLimitExceeded
+                );
+            }
+        }
+        self.total_sent = self
+            .total_sent
+            .checked_add(amount)
+            .ok_or(HookError::CalculationError)?;
+        self.transfer_count = self
+            .transfer_count
+            .checked_add(1)
+            .ok_or(HookError::CalculationError)?;
+        Ok(())
+    }
+
+    pub fn record_receive(&mut self, amount: u64) -> Result<()> {
+        self.total_received = self
+            .total_received
+            .checked_add(amount)
+            .ok_or(HookError::CalculationError)?;
+        Ok(())
+    }
+}
+
+/// Accounts the Token-2022 program resolves and appends to every `execute` call:
+/// the hook config, then the source and destination owners' activity PDAs.
+pub fn extra_account_metas() -> Result<Vec<ExtraAccountMeta>> {
+    let wallet_activity_for = |token_account_index: u8| {
+        ExtraAccountMeta::new_with_seeds(
+            &[
+                Seed::Literal {
+                    bytes: WALLET_ACTIVITY_SEED.to_vec(),
+                },
+                Seed::AccountKey { index: 1 },
+                Seed::AccountData {
+                    account_index: token_account_index,
+                    data_index: TOKEN_ACCOUNT_OWNER_OFFSET,
+                    length: 32,
+                },
+            ],
+            false,
+            true,
+        )
+    };
+    Ok(vec![
+        ExtraAccountMeta::new_with_seeds(
+            &[
+                Seed::Literal {
+                    bytes: HOOK_CONFIG_SEED.to_vec(),
+                },
+                Seed::AccountKey { index: 1 },
+            ],
+            false,
+            false,
+        )?,
+        wallet_activity_for(0)?,
+        wallet_activity_for(2)?,
+    ])
+}
+
+#[derive(Accounts)]
+pub struct InitializeExtraAccountMetaList<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// Must be the mint authority, so only the token's issuer can attach rules to it.
+    pub authority: Signer<'info>,
+
+    #[account(
+        constraint = mint.mint_authority == Some(authority.key()).into() @ HookError::UnauthorizedAuthority
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// CHECK: Validation state read by Token-2022; written with the TLV layout below.
+    #[account(
+        init,
+        payer = payer,
+        space = ExtraAccountMetaList::size_of(EXTRA_ACCOUNT_META_COUNT)?,
+        seeds = [EXTRA_ACCOUNT_METAS_SEED, mint.key().as_ref()],
+        bump
+    )]
+    pub extra_account_meta_list: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + std::mem::size_of::<HookConfig>(),
+        seeds = [HOOK_CONFIG_SEED, mint.key().as_ref()],
+        bump
+    )]
+    pub hook_config: Account<'info, HookConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateTransferRules<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [HOOK_CONFIG_SEED, hook_config.mint.as_ref()],
+        bump = hook_config.bump,
+        has_one = authority @ HookError::UnauthorizedAuthority
+    )]
+    pub hook_config: Account<'info, HookConfig>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeWalletActivity<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: Any wallet; only used as a seed.
+    pub owner: UncheckedAccount<'info>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + std::mem::size_of::<WalletActivity>(),
+        seeds = [WALLET_ACTIVITY_SEED, mint.key().as_ref(), owner.key().as_ref()],
+        bump
+    )]
+    pub wallet_activity: Account<'info, WalletActivity>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RecordRewardClaim<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [HOOK_CONFIG_SEED, mint.key().as_ref()],
+        bump = hook_config.bump,
+        has_one = authority @ HookError::UnauthorizedAuthority,
+        has_one = mint
+    )]
+    pub hook_config: Account<'info, HookConfig>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// CHECK: The wallet that received the rewards; only used as a seed.
+    pub owner: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + std::mem::size_of::<WalletActivity>(),
+        seeds = [WALLET_ACTIVITY_SEED, mint.key().as_ref(), owner.key().as_ref()],
+        bump
+    )]
+    pub wallet_activity: Account<'info, WalletActivity>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Account order is fixed by the transfer hook interface; the last three come from
+/// `extra_account_metas`.
+#[derive(Accounts)]
+pub struct Execute<'info> {
+    #[account(token::mint = mint)]
+    pub source_token: InterfaceAccount<'info, TokenAccount>,
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(token::mint = mint)]
+    pub destination_token: InterfaceAccount<'info, TokenAccount>,
+    /// CHECK: Source owner or delegate, already verified by Token-2022.
+    pub owner: UncheckedAccount<'info>,
+    /// CHECK: Validation state, checked by seeds.
+    #[account(
+        seeds = [EXTRA_ACCOUNT_METAS_SEED, mint.key().as_ref()],
+        bump
+    )]
+    pub extra_account_meta_list: UncheckedAccount<'info>,
+    #[account(
+        seeds = [HOOK_CONFIG_SEED, mint.key().as_ref()],
+        bump = hook_config.bump
+    )]
+    pub hook_config: Account<'info, HookConfig>,
+    /// CHECK: Source owner's WalletActivity PDA; may not exist yet.
+    #[account(mut)]
+    pub source_activity: UncheckedAccount<'info>,
+    /// CHECK: Destination owner's WalletActivity PDA; may not exist yet.
+    #[account(mut)]
+    pub destination_activity: UncheckedAccount<'info>,
+}
+
+/// Applies `update` to a WalletActivity PDA if it has been created. Untracked wallets are skipped.
+fn update_wallet_activity(
+    info: &AccountInfo,
+    update: impl FnOnce(&mut WalletActivity) -> Result<()>,
+) -> Result<()> {
+    if info.owner != &crate::ID || info.data_is_empty() {
+        return Ok(());
+    }
+    let mut data = info.try_borrow_mut_data()?;
+    let mut wallet_activity = WalletActivity::try_deserialize(&mut &data[..])?;
+    update(&mut wallet_activity)?;
+    wallet_activity.try_serialize(&mut &mut data[..])
+}
+
+#[program]
+pub mod cashino_transfer_hook {
+    use super::*;
+
+    pub fn initialize_extra_account_meta_list(
+        ctx: Context<InitializeExtraAccountMetaList>,
+        rules: TransferRules,
+    ) -> Result<()> {
+        msg!("--- Instruction: InitializeExtraAccountMetaList ---");
+        rules.validate()?;
+
+        let metas = extra_account_metas()?;
+        ExtraAccountMetaList::init::<ExecuteInstruction>(
+            &mut ctx.accounts.extra_account_meta_list.try_borrow_mut_data()?,
+            &metas,
+        )?;
+
+        let hook_config = &mut ctx.accounts.hook_config;
+        hook_config.mint = ctx.accounts.mint.key();
+        hook_config.authority = ctx.accounts.authority.key();
+        hook_config.rules = rules;
+        hook_config.bump = ctx.bumps.hook_config;
+        msg!(
+            "Transfer hook initialized for mint {} with rules {:?}",
+            hook_config.mint,
+            rules
+        );
+        msg!("--- InitializeExtraAccountMetaList finished ---");
+        Ok(())
+    }
+
+    pub fn update_transfer_rules(
+        ctx: Context<UpdateTransferRules>,
+        rules: TransferRules,
+    ) -> Result<()> {
+        msg!("--- Instruction: UpdateTransferRules ---");
+        rules.validate()?;
+        ctx.accounts.hook_config.rules = rules;
+        msg!("Transfer rules updated: {:?}", rules);
+        msg!("--- UpdateTransferRules finished ---");
+        Ok(())
+    }
+
+    /// Opts a wallet into volume tracking. Permissionless; the payer funds the PDA.
+    pub fn initialize_wallet_activity(ctx: Context<InitializeWalletActivity>) -> Result<()> {
+        let wallet_activity = &mut ctx.accounts.wallet_activity;
+        wallet_activity.owner = ctx.accounts.owner.key();
+        wallet_activity.mint = ctx.accounts.mint.key();
+        wallet_activity.bump = ctx.bumps.wallet_activity;
+        msg!(
+            "Wallet activity tracking enabled for {}",
+            wallet_activity.owner
+        );
+        Ok(())
+    }
+
+    /// Marks `owner` as freshly rewarded, starting its cooldown and rate limit window.
+    pub fn record_reward_claim(ctx: Context<RecordRewardClaim>, amount: u64) -> Result<()> {
+        let wallet_activity = &mut ctx.accounts.wallet_activity;
+        if wallet_activity.owner == Pubkey::default() {
+            wallet_activity.owner = ctx.accounts.owner.key();
+            wallet_activity.mint = ctx.accounts.mint.key();
+            wallet_activity.bump = ctx.bumps.wallet_activity;
+        }
+        wallet_activity.last_claim_at = Clock::get()?.unix_timestamp;
+        wallet_activity.last_claim_amount = amount;
+        msg!(
+            "Reward claim of {} recorded for {} at {}",
+            amount,
+            wallet_activity.owner,
+            wallet_activity.last_claim_at
+        );
+        Ok(())
+    }
+
+    #[interface(spl_transfer_hook_interface::execute)]
+    pub fn execute(ctx: Context<Execute>, amount: u64) -> Result<()> {
+        // Only Token-2022 mid-transfer may invoke the hook.
+        {
+            let source_info = ctx.accounts.source_token.to_account_info();
+            let source_data = source_info.try_borrow_data()?;
+            let source_state = StateWithExtensions::<TokenAccountState>::unpack(&source_data)?;
+            let hook_account = source_state.get_extension::<TransferHookAccount>()?;
+            require!(
+                bool::from(hook_account.transferring),
+                HookError::NotTransferring
+            );
+        }
+
+        let clock = Clock::get()?;
+        let rules = ctx.accounts.hook_config.rules;
+
+        update_wallet_activity(&ctx.accounts.source_activity, |activity| {
+            activity.record_send(amount, &rules, clock.unix_timestamp, clock.slot)
+        })?;
+        // Written after the source, so a self-transfer sees both updates.
+        update_wallet_activity(&ctx.accounts.destination_activity, |activity| {
+            activity.record_receive(amount)
+        })?;
+        Ok(())
+    }
+}
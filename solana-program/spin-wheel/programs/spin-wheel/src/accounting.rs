@@ -0,0 +1,193 @@
+//! Shared bookkeeping for `UserPlatformEscrow`.
+//!
+//! The escrow's `balance` field and the account's lamports move together. Every helper here
+//! updates both and then checks that `lamports >= balance + rent`, so an accounting bug fails
+//! the transaction instead of leaving a user's balance unbacked.
+
+use crate::{ErrorCode, UserPlatformEscrow};
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::rent::Rent;
+
+/// Lamports the escrow account must keep to stay rent exempt.
+pub fn escrow_rent_minimum(escrow: &Account<UserPlatformEscrow>) -> Result<u64> {
+    Ok(Rent::get()?.minimum_balance(escrow.to_account_info().data_len()))
+}
+
+/// Fails if the escrow's lamports no longer cover its recorded balance plus rent.
+pub fn check_escrow_invariant(escrow: &Account<UserPlatformEscrow>) -> Result<()> {
+    let lamports = escrow.to_account_info().lamports();
+    let required = escrow
+        .balance
+        .checked_add(escrow_rent_minimum(escrow)?)
+        .ok_or(ErrorCode::CalculationError)?;
+    if lamports < required {
+        msg!(
+            "Escrow {} accounting mismatch: lamports {}, balance {}, required {}",
+            escrow.key(),
+            lamports,
+            escrow.balance,
+            required
+        );
+        return err!(ErrorCode::EscrowAccountingMismatch);
+    }
+    Ok(())
+}
+
+/// Moves `amount` lamports out of the escrow and debits its balance.
+pub fn transfer_from_escrow<'info>(
+    escrow: &mut Account<'info, UserPlatformEscrow>,
+    destination: &AccountInfo<'info>,
+    amount: u64,
+) -> Result<()> {
+    require!(escrow.balance >= amount, ErrorCode::InsufficientPlatformBalance);
+    **escrow.to_account_info().try_borrow_mut_lamports()? -= amount;
+    **destination.try_borrow_mut_lamports()? += amount;
+    record_escrow_debit(escrow, amount)
+}
+
+/// Moves `amount` lamports from a program-owned `source` into the escrow and credits its balance.
+pub fn transfer_to_escrow<'info>(
+    source: &AccountInfo<'info>,
+    escrow: &mut Account<'info, UserPlatformEscrow>,
+    amount: u64,
+) -> Result<()> {
+    **source.try_borrow_mut_lamports()? -= amount;
+    **escrow.to_account_info().try_borrow_mut_lamports()? += amount;
+    record_escrow_credit(escrow, amount)
+}
+
+/// Debits the balance for lamports already moved out by another helper, e.g. the fee split.
+pub fn record_escrow_debit(escrow: &mut Account<UserPlatformEscrow>, amount: u64) -> Result<()> {
+    escrow.balance = escrow
+        .balance
+        .checked_sub(amount)
+        .ok_or(ErrorCode::InsufficientPlatformBalance)?;
+    check_escrow_invariant(escrow)
+}
+
+/// Credits the balance for lamports already moved in, e.g. by a system program transfer.
+pub fn record_escrow_credit(escrow: &mut Account<UserPlatformEscrow>, amount: u64) -> Result<()> {
+    escrow.balance = escrow
+        .balance
+        .checked_add(amount)
+        .ok_or(ErrorCode::CalculationError)?;
+    check_escrow_invariant(escrow)
+}
+
+/// Difference between what the escrow holds and what it should hold.
+pub struct EscrowDrift {
+    pub rent_minimum: u64,
+    /// Lamports above `balance + rent`, e.g. SOL sent straight to the PDA.
+    pub surplus: u64,
+    /// Lamports missing to cover `balance + rent`.
+    pub deficit: u64,
+}
+
+pub fn escrow_drift(escrow: &Account<UserPlatformEscrow>) -> Result<EscrowDrift> {
+    let lamports = escrow.to_account_info().lamports();
+    let rent_minimum = escrow_rent_minimum(escrow)?;
+    let required = escrow
+        .balance
+        .checked_add(rent_minimum)
+        .ok_or(ErrorCode::CalculationError)?;
+    Ok(EscrowDrift {
+        rent_minimum,
+        surplus: lamports.saturating_sub(required),
+        deficit: required.saturating_sub(lamports),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::solana_program::program_stubs::{set_syscall_stubs, SyscallStubs};
+    use std::sync::Once;
+
+    /// Serves `Rent::get()` off chain with the default rent.
+    struct RentStubs;
+
+    impl SyscallStubs for RentStubs {
+        fn sol_get_rent_sysvar(&self, var_addr: *mut u8) -> u64 {
+            unsafe { *(var_addr as *mut Rent) = Rent::default() };
+            0
+        }
+    }
+
+    fn stub_rent() {
+        static STUBS: Once = Once::new();
+        STUBS.call_once(|| {
+            set_syscall_stubs(Box::new(RentStubs));
+        });
+    }
+
+    fn lamport_account(lamports: u64, data: Vec<u8>, owner: Pubkey) -> &'static AccountInfo<'static> {
+        Box::leak(Box::new(AccountInfo::new(
+            Box::leak(Box::new(Pubkey::new_unique())),
+            false,
+            true,
+            Box::leak(Box::new(lamports)),
+            Box::leak(data.into_boxed_slice()),
+            Box::leak(Box::new(owner)),
+            false,
+            0,
+        )))
+    }
+
+    /// An escrow recording `balance` and holding `lamports_over_rent` lamports on top of its rent.
+    fn escrow(balance: u64, lamports_over_rent: u64) -> Account<'static, UserPlatformEscrow> {
+        stub_rent();
+        let mut data = Vec::new();
+        UserPlatformEscrow { balance, ..Default::default() }
+            .try_serialize(&mut data)
+            .unwrap();
+        let rent = Rent::default().minimum_balance(data.len());
+        Account::try_from(lamport_account(rent + lamports_over_rent, data, crate::ID)).unwrap()
+    }
+
+    fn lamports_over_rent(escrow: &Account<UserPlatformEscrow>) -> u64 {
+        escrow.to_account_info().lamports() - escrow_rent_minimum(escrow).unwrap()
+    }
+
+    #[test]
+    fn transfers_move_lamports_and_balance_together() {
+        let mut escrow = escrow(500, 500);
+        let destination = lamport_account(0, Vec::new(), Pubkey::default());
+        transfer_from_escrow(&mut escrow, destination, 200).unwrap();
+        assert_eq!((escrow.balance, lamports_over_rent(&escrow)), (300, 300));
+        assert_eq!(destination.lamports(), 200);
+
+        let source = lamport_account(1_000, Vec::new(), crate::ID);
+        transfer_to_escrow(source, &mut escrow, 100).unwrap();
+        assert_eq!((escrow.balance, lamports_over_rent(&escrow)), (400, 400));
+        assert_eq!(source.lamports(), 900);
+    }
+
+    #[test]
+    fn withdrawals_cannot_exceed_the_recorded_balance() {
+        let mut escrow = escrow(500, 800);
+        let destination = lamport_account(0, Vec::new(), Pubkey::default());
+        assert_eq!(
+            transfer_from_escrow(&mut escrow, destination, 501).unwrap_err(),
+            error!(ErrorCode::InsufficientPlatformBalance)
+        );
+    }
+
+    #[test]
+    fn a_credit_without_lamports_behind_it_breaks_the_invariant() {
+        let mut escrow = escrow(500, 500);
+        assert_eq!(
+            record_escrow_credit(&mut escrow, 1).unwrap_err(),
+            error!(ErrorCode::EscrowAccountingMismatch)
+        );
+    }
+
+    #[test]
+    fn drift_reports_surplus_and_deficit_against_balance_plus_rent() {
+        let drift = escrow_drift(&escrow(500, 600)).unwrap();
+        assert_eq!((drift.surplus, drift.deficit), (100, 0));
+
+        let drift = escrow_drift(&escrow(700, 600)).unwrap();
+        assert_eq!((drift.surplus, drift.deficit), (0, 100));
+        check_escrow_invariant(&escrow(600, 600)).unwrap();
+    }
+}
@@ -88,4 +88,162 @@ pub enum ErrorCode {
     SolWinningsAlreadyClaimed,
     #[msg("Round is not in the AwaitingSolClaim state.")]
     RoundNotInAwaitingSolClaimState,
+    #[msg("Invalid table ID provided for PDA seed.")]
+    InvalidTableId,
+    #[msg("Invalid bet limits for table.")]
+    InvalidBetLimits,
+    #[msg("Table has been retired.")]
+    TableRetired,
+    #[msg("Instruction does not match the table's bet denomination.")]
+    InvalidTableDenomination,
+    #[msg("Token escrow does not belong to this mint.")]
+    InvalidEscrowMint,
+    #[msg("Invalid revenue share basis points.")]
+    InvalidRevenueShare,
+    #[msg("Invalid unstake cooldown.")]
+    InvalidUnstakeCooldown,
+    #[msg("Invalid stake amount. Must be greater than zero.")]
+    InvalidStakeAmount,
+    #[msg("Insufficient staked balance.")]
+    InsufficientStakedBalance,
+    #[msg("The staking pool has no staked balance.")]
+    NoStakedBalance,
+    #[msg("No staking yield available to claim.")]
+    NoStakingYield,
+    #[msg("No pending unstake to complete.")]
+    NoPendingUnstake,
+    #[msg("Unstake cooldown has not elapsed yet.")]
+    UnstakeCooldownActive,
+    #[msg("Too many fee recipients.")]
+    TooManyFeeRecipients,
+    #[msg("Fee recipient shares must sum to 10,000 basis points.")]
+    InvalidFeeSplit,
+    #[msg("Fee dust recipient index is out of range.")]
+    InvalidFeeDustRecipient,
+    #[msg("Fee recipient account does not match the configured fee split.")]
+    InvalidFeeRecipient,
+    #[msg("Invalid referral share basis points.")]
+    InvalidReferralShare,
+    #[msg("A user cannot refer themselves.")]
+    SelfReferral,
+    #[msg("Referral account does not match the expected PDA or round player.")]
+    InvalidReferralAccount,
+    #[msg("No referral earnings available to claim.")]
+    NoReferralEarnings,
+    #[msg("Invalid jackpot configuration.")]
+    InvalidJackpotConfig,
+    #[msg("The caller is not the jackpot winner for this round.")]
+    NotTheJackpotWinner,
+    #[msg("Jackpot for this round has already been claimed.")]
+    JackpotAlreadyClaimed,
+    #[msg("Invalid payout tiers for the payout mode.")]
+    InvalidPayoutTiers,
+    #[msg("Instruction does not match the table or round game mode.")]
+    InvalidGameMode,
+    #[msg("Invalid wheel segment configuration or selection.")]
+    InvalidWheelSegment,
+    #[msg("Wheel segment expected value exceeds the stake.")]
+    WheelSegmentExpectedValueTooHigh,
+    #[msg("House bankroll cannot cover the worst-case payout of this round.")]
+    InsufficientBankrollLiquidity,
+    #[msg("The seed for this round has already been revealed.")]
+    SeedAlreadyRevealed,
+    #[msg("The slot hash for this round's spin is not available yet.")]
+    EntropyNotAvailable,
+    #[msg("LP withdrawal delay is outside the allowed range.")]
+    InvalidLpWithdrawalDelay,
+    #[msg("LP share amount must be greater than zero.")]
+    InvalidLpShareAmount,
+    #[msg("No LP withdrawal is pending.")]
+    NoPendingLpWithdrawal,
+    #[msg("LP withdrawal delay has not elapsed yet.")]
+    LpWithdrawalDelayActive,
+    #[msg("The bankroll is empty while LP shares are outstanding.")]
+    BankrollDepleted,
+    #[msg("LP deposit delay has not elapsed yet.")]
+    LpDepositDelayActive,
+    #[msg("Deposit exceeds your daily or weekly deposit limit.")]
+    DepositLimitExceeded,
+    #[msg("Bet exceeds your per-round or daily wager limit.")]
+    WagerLimitExceeded,
+    #[msg("Bet exceeds your daily loss limit.")]
+    LossLimitExceeded,
+    #[msg("Betting is disabled during your self-exclusion period.")]
+    SelfExcluded,
+    #[msg("Self-exclusion period must be greater than zero.")]
+    InvalidSelfExclusionPeriod,
+    #[msg("Withdrawals above the review threshold must go through request_sol_withdrawal.")]
+    WithdrawalRequiresReview,
+    #[msg("Withdrawal is below the review threshold and can be made instantly.")]
+    WithdrawalBelowReviewThreshold,
+    #[msg("Withdrawal review delay has not elapsed yet.")]
+    WithdrawalTimelockActive,
+    #[msg("Withdrawal review delay has already elapsed.")]
+    WithdrawalReviewWindowClosed,
+    #[msg("Withdrawal review delay exceeds the maximum allowed.")]
+    InvalidWithdrawalReviewConfig,
+    #[msg("Invalid withdrawal fee schedule.")]
+    InvalidWithdrawalFeeSchedule,
+    #[msg("Escrow lamports do not cover its recorded balance plus rent.")]
+    EscrowAccountingMismatch,
+    #[msg("Account is already on the current layout version.")]
+    AccountAlreadyMigrated,
+    #[msg("Account data does not match any known layout version.")]
+    UnsupportedAccountVersion,
+    #[msg("Invalid player stats account provided.")]
+    InvalidPlayerStatsAccount,
+    #[msg("Invalid leaderboard configuration.")]
+    InvalidLeaderboardConfig,
+    #[msg("Leaderboard is not accepting scores or funding at this time.")]
+    LeaderboardEpochNotActive,
+    #[msg("Leaderboard epoch has not ended yet.")]
+    LeaderboardEpochNotEnded,
+    #[msg("Player has no prize on this leaderboard.")]
+    NoLeaderboardPrize,
+    #[msg("Leaderboard prize already claimed.")]
+    LeaderboardPrizeAlreadyClaimed,
+    #[msg("Invalid rakeback schedule.")]
+    InvalidRakebackConfig,
+    #[msg("Invalid or missing escrow / player stats account for rakeback.")]
+    InvalidRakebackAccount,
+    #[msg("Rakeback for this round has already been distributed.")]
+    RakebackAlreadyDistributed,
+    #[msg("Invalid emission schedule.")]
+    InvalidEmissionSchedule,
+    #[msg("Minting would exceed the maximum token supply.")]
+    MaxSupplyExceeded,
+    #[msg("Invalid token metadata.")]
+    InvalidTokenMetadata,
+    #[msg("Invalid mint extension configuration.")]
+    InvalidMintConfig,
+    #[msg("Transfer hook accounts are required for this mint.")]
+    MissingTransferHookAccounts,
+    #[msg("No platform fee treasury has been set.")]
+    FeeTreasuryNotSet,
+    #[msg("Token account is not the platform fee treasury.")]
+    InvalidFeeTreasury,
+    #[msg("Invalid burn configuration.")]
+    InvalidBurnConfig,
+    #[msg("The fee burn vault is required while fee burning is enabled.")]
+    MissingFeeBurnVault,
+    #[msg("Burning unclaimed rewards is disabled for this table.")]
+    UnclaimedRewardBurnDisabled,
+    #[msg("The reward claim window for this round is still open.")]
+    RewardClaimWindowOpen,
+    #[msg("Invalid vesting schedule.")]
+    InvalidVestingSchedule,
+    #[msg("No vested tokens available to claim.")]
+    NothingVested,
+    #[msg("This vesting escrow is not revocable.")]
+    VestingNotRevocable,
+    #[msg("This vesting escrow has already been revoked.")]
+    VestingAlreadyRevoked,
+    #[msg("A funding token account is required to fund vesting by transfer.")]
+    MissingVestingFundingAccount,
+    #[msg("Token account is not the vesting escrow's treasury.")]
+    InvalidVestingTreasury,
+    #[msg("The table's leaderboard account is required.")]
+    MissingLeaderboard,
+    #[msg("Withdrawals from this escrow are frozen after a blocked withdrawal.")]
+    EscrowWithdrawalsFrozen,
 }
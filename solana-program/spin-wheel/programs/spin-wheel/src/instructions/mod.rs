@@ -2,4 +2,10 @@ pub mod spin_game;
 pub use spin_game::*;
 
 pub mod token;
-pub use token::*;
\ No newline at end of file
+pub use token::*;
+
+pub mod staking;
+pub use staking::*;
+
+pub mod vesting;
+pub use vesting::*;
@@ -0,0 +1,63 @@
+use crate::instructions::cancel_sol_withdrawal::return_pending_withdrawal_to_escrow;
+use crate::{ErrorCode, PendingWithdrawal, PlatformConfig, UserPlatformEscrow};
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::clock::Clock;
+
+#[derive(Accounts)]
+pub struct BlockSolWithdrawal<'info> {
+    pub risk_admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"platform_config"],
+        bump,
+        constraint = platform_config.risk_admin == risk_admin.key() @ ErrorCode::UnauthorizedAccess
+    )]
+    pub platform_config: Box<Account<'info, PlatformConfig>>,
+
+    /// CHECK: Owner of the pending withdrawal; receives the request's rent when it is closed.
+    #[account(mut, address = pending_withdrawal.user @ ErrorCode::UnauthorizedAccess)]
+    pub user: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"user_escrow", user.key().as_ref()],
+        bump = user_platform_escrow.bump
+    )]
+    pub user_platform_escrow: Account<'info, UserPlatformEscrow>,
+
+    #[account(
+        mut,
+        close = user,
+        seeds = [b"pending_withdrawal", pending_withdrawal.user.as_ref()],
+        bump = pending_withdrawal.bump
+    )]
+    pub pending_withdrawal: Box<Account<'info, PendingWithdrawal>>,
+}
+
+pub fn process_block_sol_withdrawal(ctx: Context<BlockSolWithdrawal>) -> Result<()> {
+    msg!("--- Instruction: BlockSolWithdrawal ---");
+    msg!("Risk admin: {}", ctx.accounts.risk_admin.key());
+    msg!("User: {}", ctx.accounts.user.key());
+
+    let clock = Clock::get()?;
+    require!(
+        clock.unix_timestamp < ctx.accounts.pending_withdrawal.executable_at,
+        ErrorCode::WithdrawalReviewWindowClosed
+    );
+
+    // A blocked withdrawal never leaves the platform; the funds go back to the user's escrow,
+    // which stays frozen until the authority clears it.
+    let refund = return_pending_withdrawal_to_escrow(
+        &ctx.accounts.pending_withdrawal,
+        &mut ctx.accounts.user_platform_escrow,
+    )?;
+    ctx.accounts.user_platform_escrow.withdrawals_frozen = true;
+
+    msg!(
+        "Withdrawal blocked and escrow frozen. {} lamports returned to escrow, data balance now: {}",
+        refund,
+        ctx.accounts.user_platform_escrow.balance
+    );
+    msg!("--- BlockSolWithdrawal finished ---");
+    Ok(())
+}
@@ -0,0 +1,132 @@
+use crate::{
+    ErrorCode, GameState, RoundCashinoRewardsPot, RoundState, RoundStatus, UnclaimedRewardsBurned,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{burn, spl_token_2022, Burn, Mint, Token2022, TokenAccount};
+
+#[derive(Accounts)]
+#[instruction(table_id: u64, round_id_for_pdas: u64)]
+pub struct BurnUnclaimedRewards<'info> {
+    #[account(
+        mut,
+        seeds = [b"game_state".as_ref(), &table_id.to_le_bytes()],
+        bump,
+        constraint = game_state.burn.burn_unclaimed_rewards @ ErrorCode::UnclaimedRewardBurnDisabled
+    )]
+    pub game_state: Box<Account<'info, GameState>>,
+
+    #[account(
+        mut,
+        seeds = [b"round_state".as_ref(), &table_id.to_le_bytes(), &round_id_for_pdas.to_le_bytes()],
+        bump,
+        constraint = round_state.load()?.status_discriminant == RoundStatus::RewardsProcessed as u8 @ ErrorCode::RoundNotInCorrectStateForRewardDistribution,
+    )]
+    pub round_state: AccountLoader<'info, RoundState>,
+
+    #[account(
+        seeds = [b"cashino_round_pot".as_ref(), &table_id.to_le_bytes(), &round_id_for_pdas.to_le_bytes()],
+        bump,
+        constraint = round_cashino_rewards_pot_account.round_id == round_id_for_pdas @ ErrorCode::InvalidRoundIdForSeed
+    )]
+    pub round_cashino_rewards_pot_account: Account<'info, RoundCashinoRewardsPot>,
+
+    #[account(
+        mut,
+        associated_token::mint = cashino_token_mint,
+        associated_token::authority = round_cashino_rewards_pot_account,
+        associated_token::token_program = token_program
+    )]
+    pub round_cashino_rewards_pot_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        address = game_state.cashino_mint @ ErrorCode::InvalidMintAccount
+    )]
+    pub cashino_token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(address = spl_token_2022::ID @ ErrorCode::InvalidTokenProgram)]
+    pub token_program: Program<'info, Token2022>,
+}
+
+/// Permissionless: once the claim deadline fixed when the round's rewards were processed has
+/// passed, burns whatever is left in its reward pot. Rewards still unclaimed are forfeited and
+/// marked claimed.
+pub fn process_burn_unclaimed_rewards(
+    ctx: Context<BurnUnclaimedRewards>,
+    table_id: u64,
+    round_id_for_pdas: u64,
+) -> Result<()> {
+    msg!("--- Instruction: BurnUnclaimedRewards ---");
+    msg!("Table ID: {}", table_id);
+    msg!("Round ID (used for PDAs): {}", round_id_for_pdas);
+    msg!(
+        "RoundCashinoRewardsPot ATA: {}",
+        ctx.accounts.round_cashino_rewards_pot_ata.key()
+    );
+
+    let clock = Clock::get()?;
+    let round_state = &mut ctx.accounts.round_state.load_mut()?;
+    let claim_deadline = round_state.reward_claim_deadline;
+    require!(
+        claim_deadline > 0 && clock.unix_timestamp >= claim_deadline,
+        ErrorCode::RewardClaimWindowOpen
+    );
+
+    for i in 0..(round_state.player_count as usize) {
+        if round_state.player_cashino_rewards[i].claimed_val == 0 {
+            msg!(
+                "  - Forfeiting {} $CASHINO unclaimed by {}",
+                round_state.player_cashino_rewards[i].cashino_reward_amount,
+                round_state.player_cashino_rewards[i].player
+            );
+            round_state.player_cashino_rewards[i].claimed_val = 1;
+        }
+    }
+
+    let amount = ctx.accounts.round_cashino_rewards_pot_ata.amount;
+    if amount > 0 {
+        let round_cashino_pot_bump = ctx.bumps.round_cashino_rewards_pot_account;
+        let pot_signer_seeds: &[&[u8]] = &[
+            b"cashino_round_pot".as_ref(),
+            &table_id.to_le_bytes(),
+            &round_id_for_pdas.to_le_bytes(),
+            &[round_cashino_pot_bump],
+        ];
+
+        burn(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Burn {
+                    mint: ctx.accounts.cashino_token_mint.to_account_info(),
+                    from: ctx.accounts.round_cashino_rewards_pot_ata.to_account_info(),
+                    authority: ctx
+                        .accounts
+                        .round_cashino_rewards_pot_account
+                        .to_account_info(),
+                },
+                &[pot_signer_seeds],
+            ),
+            amount,
+        )?;
+    }
+
+    let burn_config = &mut ctx.accounts.game_state.burn;
+    burn_config.rewards_burned = burn_config
+        .rewards_burned
+        .checked_add(amount)
+        .ok_or(ErrorCode::CalculationError)?;
+
+    emit!(UnclaimedRewardsBurned {
+        table_id,
+        round_id: round_id_for_pdas,
+        amount,
+        total_burned: burn_config.rewards_burned,
+    });
+    msg!(
+        "Burned {} unclaimed $CASHINO; {} burned by this table in total.",
+        amount,
+        burn_config.rewards_burned
+    );
+    msg!("--- BurnUnclaimedRewards finished ---");
+    Ok(())
+}
@@ -5,14 +5,14 @@ use crate::{
 use anchor_lang::prelude::*;
 
 #[derive(Accounts)]
-#[instruction(round_id_for_pdas: u64)]
+#[instruction(table_id: u64, round_id_for_pdas: u64)]
 pub struct CalculateRewardEntitlements<'info> {
-    #[account(seeds = [b"game_state"], bump)]
+    #[account(seeds = [b"game_state".as_ref(), &table_id.to_le_bytes()], bump)]
     pub game_state: Box<Account<'info, GameState>>,
 
     #[account(
         mut,
-        seeds = [b"round_state".as_ref(), &round_id_for_pdas.to_le_bytes()],
+        seeds = [b"round_state".as_ref(), &table_id.to_le_bytes(), &round_id_for_pdas.to_le_bytes()],
         bump,
         constraint = round_state.load()?.status_discriminant == RoundStatus::TokensMintedForRewards as u8 @ ErrorCode::RoundNotInCorrectState,
     )]
@@ -21,9 +21,11 @@ pub struct CalculateRewardEntitlements<'info> {
 
 pub fn process_calculate_reward_entitlements(
     ctx: Context<CalculateRewardEntitlements>,
+    table_id: u64,
     round_id_for_pdas: u64,
 ) -> Result<()> {
     msg!("--- Instruction: CalculateRewardEntitlements ---");
+    msg!("Target Table ID: {}", table_id);
     msg!("Target Round ID (for PDAs): {}", round_id_for_pdas);
 
     let round_state = &mut ctx.accounts.round_state.load_mut()?;
@@ -88,7 +90,13 @@ pub fn process_calculate_reward_entitlements(
         }
     }
 
+    round_state.reward_claim_deadline = ctx
+        .accounts
+        .game_state
+        .burn
+        .reward_claim_deadline(Clock::get()?.unix_timestamp)?;
     round_state.status_discriminant = RoundStatus::RewardsProcessed as u8;
+    msg!("Rewards claimable until {}.", round_state.reward_claim_deadline);
     msg!(
         "Round {} (ID: {}) status updated to RewardsProcessed (Discriminant: {}). Round is now fully finalized.",
         round_id_for_pdas,
@@ -0,0 +1,62 @@
+use crate::accounting;
+use crate::{ErrorCode, PendingWithdrawal, UserPlatformEscrow};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct CancelSolWithdrawal<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"user_escrow", user.key().as_ref()],
+        bump = user_platform_escrow.bump,
+        constraint = user_platform_escrow.user_authority == user.key() @ ErrorCode::UnauthorizedEscrowAccess
+    )]
+    pub user_platform_escrow: Account<'info, UserPlatformEscrow>,
+
+    #[account(
+        mut,
+        close = user,
+        seeds = [b"pending_withdrawal", user.key().as_ref()],
+        bump = pending_withdrawal.bump,
+        constraint = pending_withdrawal.user == user.key() @ ErrorCode::UnauthorizedAccess
+    )]
+    pub pending_withdrawal: Box<Account<'info, PendingWithdrawal>>,
+}
+
+pub fn process_cancel_sol_withdrawal(ctx: Context<CancelSolWithdrawal>) -> Result<()> {
+    msg!("--- Instruction: CancelSolWithdrawal ---");
+    msg!("User: {}", ctx.accounts.user.key());
+
+    let refund = return_pending_withdrawal_to_escrow(
+        &ctx.accounts.pending_withdrawal,
+        &mut ctx.accounts.user_platform_escrow,
+    )?;
+
+    msg!(
+        "Withdrawal cancelled. {} lamports returned to escrow, data balance now: {}",
+        refund,
+        ctx.accounts.user_platform_escrow.balance
+    );
+    msg!("--- CancelSolWithdrawal finished ---");
+    Ok(())
+}
+
+/// Moves the held amount and fee back into the user's escrow. Returns the lamports moved.
+pub(crate) fn return_pending_withdrawal_to_escrow<'info>(
+    pending_withdrawal: &Account<'info, PendingWithdrawal>,
+    user_platform_escrow: &mut Account<'info, UserPlatformEscrow>,
+) -> Result<u64> {
+    let refund = pending_withdrawal
+        .amount
+        .checked_add(pending_withdrawal.fee)
+        .ok_or(ErrorCode::CalculationError)?;
+
+    accounting::transfer_to_escrow(
+        &pending_withdrawal.to_account_info(),
+        user_platform_escrow,
+        refund,
+    )?;
+    Ok(refund)
+}
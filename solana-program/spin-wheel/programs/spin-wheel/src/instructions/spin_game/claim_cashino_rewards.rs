@@ -1,32 +1,37 @@
-use crate::{ErrorCode, GameState, RoundCashinoRewardsPot, RoundState, RoundStatus};
+use crate::token_transfer::{transfer_checked_with_hook, transfer_hook_program_id};
+use crate::{
+    ErrorCode, GameState, PlayerStats, RoundCashinoRewardsPot, RoundState, RoundStatus,
+    MINT_AUTHORITY_SEED,
+};
 use anchor_lang::prelude::*;
 use anchor_spl::{
     associated_token::AssociatedToken,
-    token_interface::{self, spl_token_2022, Mint, Token2022, TokenAccount, TransferChecked},
+    token_interface::{spl_token_2022, Mint, Token2022, TokenAccount, TransferChecked},
 };
+use cashino_transfer_hook::{cpi::accounts::RecordRewardClaim, program::CashinoTransferHook};
 
 #[derive(Accounts)]
-#[instruction(round_id_for_pdas: u64)]
+#[instruction(table_id: u64, round_id_for_pdas: u64)]
 pub struct ClaimCashinoRewards<'info> {
     #[account(mut)]
     pub player: Signer<'info>,
 
     #[account(
-        seeds = [b"game_state"],
+        seeds = [b"game_state".as_ref(), &table_id.to_le_bytes()],
         bump
     )]
     pub game_state: Box<Account<'info, GameState>>,
 
     #[account(
         mut,
-        seeds = [b"round_state".as_ref(), &round_id_for_pdas.to_le_bytes().as_ref()],
+        seeds = [b"round_state".as_ref(), &table_id.to_le_bytes(), &round_id_for_pdas.to_le_bytes().as_ref()],
         bump,
         constraint = round_state.load()?.status_discriminant == RoundStatus::RewardsProcessed as u8 @ ErrorCode::RoundNotInCorrectStateForRewardDistribution,
     )]
     pub round_state: AccountLoader<'info, RoundState>,
 
     #[account(
-        seeds = [b"cashino_round_pot".as_ref(), &round_id_for_pdas.to_le_bytes()],
+        seeds = [b"cashino_round_pot".as_ref(), &table_id.to_le_bytes(), &round_id_for_pdas.to_le_bytes()],
         bump,
         constraint = round_cashino_rewards_pot_account.round_id == round_id_for_pdas @ ErrorCode::InvalidRoundIdForSeed
     )]
@@ -54,18 +59,47 @@ pub struct ClaimCashinoRewards<'info> {
     )]
     pub player_cashino_ata: Box<InterfaceAccount<'info, TokenAccount>>,
 
+    #[account(
+        init_if_needed,
+        payer = player,
+        space = 8 + std::mem::size_of::<PlayerStats>(),
+        seeds = [b"player_stats", player.key().as_ref()],
+        bump
+    )]
+    pub player_stats: Box<Account<'info, PlayerStats>>,
+
+    // The accounts below are required only when the mint's transfer hook is the CASHINO hook,
+    // which then records the claim to start the wallet's post-claim rules.
+    /// CHECK: Hook authority, signs the claim record.
+    #[account(
+        seeds = [MINT_AUTHORITY_SEED],
+        bump
+    )]
+    pub mint_authority_pda: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Validated by the transfer hook program.
+    pub hook_config: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Player's WalletActivity PDA, created or validated by the transfer hook program.
+    #[account(mut)]
+    pub wallet_activity: Option<UncheckedAccount<'info>>,
+
+    pub transfer_hook_program: Option<Program<'info, CashinoTransferHook>>,
+
     #[account(address = spl_token_2022::ID @ ErrorCode::InvalidTokenProgram)]
     pub token_program: Program<'info, Token2022>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
 }
 
-pub fn process_claim_cashino_rewards(
-    ctx: Context<ClaimCashinoRewards>,
+pub fn process_claim_cashino_rewards<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ClaimCashinoRewards<'info>>,
+    table_id: u64,
     round_id_for_pdas: u64,
 ) -> Result<()> {
     msg!("--- Instruction: ClaimCashinoRewards ---");
     msg!("Player claiming rewards: {}", ctx.accounts.player.key());
+    msg!("For Table ID: {}", table_id);
     msg!("For Round ID (used for PDAs): {}", round_id_for_pdas);
     msg!("RoundState PDA: {}", ctx.accounts.round_state.key());
     msg!(
@@ -81,6 +115,11 @@ pub fn process_claim_cashino_rewards(
         ctx.accounts.player_cashino_ata.key()
     );
 
+    if ctx.accounts.player_stats.player == Pubkey::default() {
+        ctx.accounts.player_stats.player = ctx.accounts.player.key();
+        ctx.accounts.player_stats.bump = ctx.bumps.player_stats;
+    }
+
     let round_state = &mut ctx.accounts.round_state.load_mut()?;
 
     let mut amount_to_claim: u64 = 0;
@@ -140,12 +179,13 @@ pub fn process_claim_cashino_rewards(
 
     let pot_signer_seeds: &[&[u8]] = &[
         b"cashino_round_pot".as_ref(),
+        &table_id.to_le_bytes(),
         &round_id_for_pdas.to_le_bytes(),
         &[round_cashino_pot_bump],
     ];
     let all_pot_signer_seeds = &[pot_signer_seeds][..];
 
-    token_interface::transfer_checked(
+    transfer_checked_with_hook(
         CpiContext::new_with_signer(
             ctx.accounts.token_program.to_account_info(),
             TransferChecked {
@@ -158,7 +198,8 @@ pub fn process_claim_cashino_rewards(
                 mint: ctx.accounts.cashino_token_mint.to_account_info(),
             },
             all_pot_signer_seeds,
-        ),
+        )
+        .with_remaining_accounts(ctx.remaining_accounts.to_vec()),
         amount_to_claim,
         ctx.accounts.cashino_token_mint.decimals,
     )?;
@@ -169,6 +210,50 @@ pub fn process_claim_cashino_rewards(
     );
 
     round_state.player_cashino_rewards[player_reward_idx].claimed_val = 1;
+
+    let hook_program_id =
+        transfer_hook_program_id(&ctx.accounts.cashino_token_mint.to_account_info())?;
+    if hook_program_id == Some(cashino_transfer_hook::ID) {
+        let (
+            Some(mint_authority_pda),
+            Some(hook_config),
+            Some(wallet_activity),
+            Some(transfer_hook_program),
+        ) = (
+            ctx.accounts.mint_authority_pda.as_ref(),
+            ctx.accounts.hook_config.as_ref(),
+            ctx.accounts.wallet_activity.as_ref(),
+            ctx.accounts.transfer_hook_program.as_ref(),
+        )
+        else {
+            return err!(ErrorCode::MissingTransferHookAccounts);
+        };
+
+        let bump = ctx.bumps.mint_authority_pda.ok_or(ErrorCode::MissingTransferHookAccounts)?;
+        let pda_signer_seeds: &[&[u8]] = &[MINT_AUTHORITY_SEED, &[bump]];
+        cashino_transfer_hook::cpi::record_reward_claim(
+            CpiContext::new_with_signer(
+                transfer_hook_program.to_account_info(),
+                RecordRewardClaim {
+                    payer: ctx.accounts.player.to_account_info(),
+                    authority: mint_authority_pda.to_account_info(),
+                    hook_config: hook_config.to_account_info(),
+                    mint: ctx.accounts.cashino_token_mint.to_account_info(),
+                    owner: ctx.accounts.player.to_account_info(),
+                    wallet_activity: wallet_activity.to_account_info(),
+                    system_program: ctx.accounts.system_program.to_account_info(),
+                },
+                &[pda_signer_seeds],
+            ),
+            amount_to_claim,
+        )?;
+        msg!("Reward claim recorded with the transfer hook.");
+    }
+
+    let player_stats = &mut ctx.accounts.player_stats;
+    player_stats.record_cashino_earned(amount_to_claim)?;
+    msg!("Player stats updated. CASHINO earned: {}", player_stats.cashino_earned);
+
     msg!(
         "Marked $CASHINO reward as claimed for player {}.",
         ctx.accounts.player.key()
@@ -0,0 +1,81 @@
+use crate::accounting;
+use crate::{ErrorCode, GamePotSol, JackpotClaimed, RoundState, UserPlatformEscrow};
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::rent::Rent;
+
+#[derive(Accounts)]
+#[instruction(table_id: u64, round_id_for_pdas: u64)]
+pub struct ClaimJackpot<'info> {
+    #[account(mut)]
+    pub winner_signer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"user_escrow", winner_signer.key().as_ref()],
+        bump = user_platform_escrow.bump,
+        constraint = user_platform_escrow.user_authority == winner_signer.key() @ ErrorCode::UnauthorizedEscrowAccess
+    )]
+    pub user_platform_escrow: Account<'info, UserPlatformEscrow>,
+
+    #[account(
+        mut,
+        seeds = [b"round_state".as_ref(), &table_id.to_le_bytes(), &round_id_for_pdas.to_le_bytes()],
+        bump,
+        constraint = round_state.load()?.jackpot_winner == winner_signer.key() @ ErrorCode::NotTheJackpotWinner,
+        constraint = round_state.load()?.jackpot_claimed == 0 @ ErrorCode::JackpotAlreadyClaimed
+    )]
+    pub round_state: AccountLoader<'info, RoundState>,
+
+    #[account(
+        mut,
+        seeds = [b"sol_pot".as_ref(), &table_id.to_le_bytes(), &round_id_for_pdas.to_le_bytes()],
+        bump
+    )]
+    pub game_pot_sol: Account<'info, GamePotSol>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn process_claim_jackpot(
+    ctx: Context<ClaimJackpot>,
+    table_id: u64,
+    round_id_for_pdas: u64,
+) -> Result<()> {
+    msg!("--- Instruction: ClaimJackpot ---");
+    msg!("Winner Signer: {}", ctx.accounts.winner_signer.key());
+    msg!("Table ID: {}", table_id);
+    msg!("Round ID for PDAs: {}", round_id_for_pdas);
+
+    let mut round_state_data = ctx.accounts.round_state.load_mut()?;
+    let jackpot_amount = round_state_data.jackpot_amount;
+    msg!("Attempting to claim jackpot of {} lamports.", jackpot_amount);
+
+    // The jackpot was moved into the round pot at finalize, alongside the regular winnings.
+    let game_pot_account_info = ctx.accounts.game_pot_sol.to_account_info();
+
+    let rent_for_pot = Rent::get()?.minimum_balance(game_pot_account_info.data_len());
+    require!(
+        game_pot_account_info.lamports().saturating_sub(jackpot_amount) >= rent_for_pot,
+        ErrorCode::InsufficientFunds
+    );
+
+    let user_platform_escrow_data = &mut ctx.accounts.user_platform_escrow;
+    accounting::transfer_to_escrow(&game_pot_account_info, user_platform_escrow_data, jackpot_amount)?;
+    user_platform_escrow_data.record_winnings(jackpot_amount, Clock::get()?.unix_timestamp)?;
+    round_state_data.jackpot_claimed = 1;
+
+    emit!(JackpotClaimed {
+        table_id,
+        round_id: round_id_for_pdas,
+        winner: ctx.accounts.winner_signer.key(),
+        amount: jackpot_amount,
+    });
+    msg!(
+        "Jackpot of {} claimed by {}. Escrow data balance now: {}",
+        jackpot_amount,
+        ctx.accounts.winner_signer.key(),
+        user_platform_escrow_data.balance
+    );
+    msg!("--- ClaimJackpot finished ---");
+    Ok(())
+}
@@ -0,0 +1,117 @@
+use crate::{ErrorCode, Leaderboard, LeaderboardPrizeClaimed, PlatformConfig};
+use crate::token_transfer::transfer_checked_with_hook;
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{spl_token_2022, Mint, Token2022, TokenAccount, TransferChecked},
+};
+
+#[derive(Accounts)]
+#[instruction(leaderboard_id: u64)]
+pub struct ClaimLeaderboardPrize<'info> {
+    #[account(mut)]
+    pub player: Signer<'info>,
+
+    #[account(seeds = [b"platform_config"], bump)]
+    pub platform_config: Box<Account<'info, PlatformConfig>>,
+
+    #[account(
+        mut,
+        seeds = [b"leaderboard".as_ref(), &leaderboard_id.to_le_bytes()],
+        bump = leaderboard.bump
+    )]
+    pub leaderboard: Box<Account<'info, Leaderboard>>,
+
+    #[account(address = platform_config.cashino_mint @ ErrorCode::InvalidMintAccount)]
+    pub cashino_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = cashino_mint,
+        associated_token::authority = leaderboard,
+        associated_token::token_program = token_program
+    )]
+    pub prize_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = player,
+        associated_token::mint = cashino_mint,
+        associated_token::authority = player,
+        associated_token::token_program = token_program
+    )]
+    pub player_cashino_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(address = spl_token_2022::ID @ ErrorCode::InvalidTokenProgram)]
+    pub token_program: Program<'info, Token2022>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn process_claim_leaderboard_prize<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ClaimLeaderboardPrize<'info>>,
+    leaderboard_id: u64,
+) -> Result<()> {
+    msg!("--- Instruction: ClaimLeaderboardPrize ---");
+    msg!("Player: {}", ctx.accounts.player.key());
+    msg!("Leaderboard ID: {}", leaderboard_id);
+
+    let clock = Clock::get()?;
+    let player_key = ctx.accounts.player.key();
+    let leaderboard = &mut ctx.accounts.leaderboard;
+    require!(
+        clock.unix_timestamp >= leaderboard.end_time,
+        ErrorCode::LeaderboardEpochNotEnded
+    );
+
+    let rank = leaderboard.entries[..leaderboard.entry_count as usize]
+        .iter()
+        .position(|e| e.player == player_key)
+        .ok_or(ErrorCode::NoLeaderboardPrize)?;
+    require!(
+        !leaderboard.entries[rank].claimed,
+        ErrorCode::LeaderboardPrizeAlreadyClaimed
+    );
+    let amount = leaderboard.prize_for(rank)?;
+    require!(amount > 0, ErrorCode::NoLeaderboardPrize);
+    leaderboard.entries[rank].claimed = true;
+    msg!(
+        "Rank {} with score {}. Prize: {} of pool {}",
+        rank + 1,
+        leaderboard.entries[rank].score,
+        amount,
+        leaderboard.prize_pool
+    );
+
+    let leaderboard_id_bytes = leaderboard_id.to_le_bytes();
+    let signer_seeds: &[&[u8]] = &[
+        b"leaderboard".as_ref(),
+        &leaderboard_id_bytes,
+        &[leaderboard.bump],
+    ];
+    transfer_checked_with_hook(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.prize_vault.to_account_info(),
+                mint: ctx.accounts.cashino_mint.to_account_info(),
+                to: ctx.accounts.player_cashino_ata.to_account_info(),
+                authority: ctx.accounts.leaderboard.to_account_info(),
+            },
+            &[signer_seeds],
+        )
+        .with_remaining_accounts(ctx.remaining_accounts.to_vec()),
+        amount,
+        ctx.accounts.cashino_mint.decimals,
+    )?;
+
+    emit!(LeaderboardPrizeClaimed {
+        leaderboard_id,
+        player: player_key,
+        rank: rank as u8,
+        amount,
+    });
+    msg!("Transferred {} CASHINO to {}.", amount, ctx.accounts.player_cashino_ata.key());
+    msg!("--- ClaimLeaderboardPrize finished ---");
+    Ok(())
+}
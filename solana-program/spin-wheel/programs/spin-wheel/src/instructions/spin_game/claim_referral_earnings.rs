@@ -0,0 +1,54 @@
+use crate::accounting;
+use crate::{ErrorCode, ReferrerStats, UserPlatformEscrow};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct ClaimReferralEarnings<'info> {
+    #[account(mut)]
+    pub referrer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"referrer_stats", referrer.key().as_ref()],
+        bump = referrer_stats.bump,
+        constraint = referrer_stats.referrer == referrer.key() @ ErrorCode::UnauthorizedAccess
+    )]
+    pub referrer_stats: Box<Account<'info, ReferrerStats>>,
+
+    #[account(
+        mut,
+        seeds = [b"user_escrow", referrer.key().as_ref()],
+        bump = user_platform_escrow.bump,
+        constraint = user_platform_escrow.user_authority == referrer.key() @ ErrorCode::UnauthorizedEscrowAccess
+    )]
+    pub user_platform_escrow: Account<'info, UserPlatformEscrow>,
+}
+
+pub fn process_claim_referral_earnings(ctx: Context<ClaimReferralEarnings>) -> Result<()> {
+    msg!("--- Instruction: ClaimReferralEarnings ---");
+    msg!("Referrer: {}", ctx.accounts.referrer.key());
+    msg!("ReferrerStats PDA: {}", ctx.accounts.referrer_stats.key());
+    msg!("Referrer escrow: {}", ctx.accounts.user_platform_escrow.key());
+
+    let earnings = ctx.accounts.referrer_stats.accrued_lamports;
+    msg!("Accrued referral earnings: {}", earnings);
+    require!(earnings > 0, ErrorCode::NoReferralEarnings);
+
+    // Earnings are held as extra lamports on the stats PDA, above its rent.
+    accounting::transfer_to_escrow(
+        &ctx.accounts.referrer_stats.to_account_info(),
+        &mut ctx.accounts.user_platform_escrow,
+        earnings,
+    )?;
+
+    ctx.accounts.referrer_stats.accrued_lamports = 0;
+    let escrow_account_data = &ctx.accounts.user_platform_escrow;
+
+    msg!(
+        "Moved {} lamports of referral earnings into escrow. Escrow data balance now: {}",
+        earnings,
+        escrow_account_data.balance
+    );
+    msg!("--- ClaimReferralEarnings finished ---");
+    Ok(())
+}
@@ -1,9 +1,10 @@
-use crate::{ErrorCode, GamePotSol, RoundState, RoundStatus, UserPlatformEscrow};
+use crate::accounting;
+use crate::{ErrorCode, GamePotSol, GameState, RoundState, RoundStatus, UserPlatformEscrow};
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::{rent::Rent, system_program};
 
 #[derive(Accounts)]
-#[instruction(round_id_for_pdas: u64)]
+#[instruction(table_id: u64, round_id_for_pdas: u64)]
 pub struct ClaimSolWinnings<'info> {
     #[account(mut)]
     pub winner_signer: Signer<'info>,
@@ -16,19 +17,24 @@ pub struct ClaimSolWinnings<'info> {
     )]
     pub user_platform_escrow: Account<'info, UserPlatformEscrow>,
 
+    #[account(
+        seeds = [b"game_state".as_ref(), &table_id.to_le_bytes()],
+        bump,
+        constraint = !game_state.is_token_table() @ ErrorCode::InvalidTableDenomination
+    )]
+    pub game_state: Box<Account<'info, GameState>>,
+
     #[account(
         mut,
-        seeds = [b"round_state".as_ref(), &round_id_for_pdas.to_le_bytes()],
+        seeds = [b"round_state".as_ref(), &table_id.to_le_bytes(), &round_id_for_pdas.to_le_bytes()],
         bump,
-        constraint = round_state.load()?.status_discriminant == RoundStatus::AwaitingSolClaim as u8 @ ErrorCode::RoundNotInAwaitingSolClaimState,
-        constraint = round_state.load()?.winner_sol_pubkey == winner_signer.key() @ ErrorCode::NotTheSolWinner,
-        constraint = round_state.load()?.winner_sol_claimed == 0 @ ErrorCode::SolWinningsAlreadyClaimed // 0 for false
+        constraint = round_state.load()?.status_discriminant == RoundStatus::AwaitingSolClaim as u8 @ ErrorCode::RoundNotInAwaitingSolClaimState
     )]
     pub round_state: AccountLoader<'info, RoundState>,
 
     #[account(
         mut,
-        seeds = [b"sol_pot".as_ref(), &round_id_for_pdas.to_le_bytes()],
+        seeds = [b"sol_pot".as_ref(), &table_id.to_le_bytes(), &round_id_for_pdas.to_le_bytes()],
         bump
     )]
     pub game_pot_sol: Account<'info, GamePotSol>,
@@ -38,6 +44,7 @@ pub struct ClaimSolWinnings<'info> {
 
 pub fn process_claim_sol_winnings(
     ctx: Context<ClaimSolWinnings>,
+    table_id: u64,
     round_id_for_pdas: u64,
 ) -> Result<()> {
     msg!("--- Instruction: ClaimSolWinnings ---");
@@ -48,16 +55,20 @@ pub fn process_claim_sol_winnings(
     );
     msg!("RoundState PDA: {}", ctx.accounts.round_state.key());
     msg!("GamePotSol PDA: {}", ctx.accounts.game_pot_sol.key());
+    msg!("Table ID: {}", table_id);
     msg!("Round ID for PDAs: {}", round_id_for_pdas);
 
-    let mut round_state_data = ctx.accounts.round_state.load_mut()?;
-    let winnings_amount = round_state_data.winner_sol_amount;
+    // Finds the signer among the round's winners and marks their payout claimed
+    let winnings_amount = ctx
+        .accounts
+        .round_state
+        .load_mut()?
+        .claim_winner_payout(ctx.accounts.winner_signer.key())?;
 
     msg!("Attempting to claim {} SOL winnings.", winnings_amount);
 
     if winnings_amount > 0 {
         let game_pot_account_info = ctx.accounts.game_pot_sol.to_account_info();
-        let user_escrow_account_info = ctx.accounts.user_platform_escrow.to_account_info();
 
         let rent_for_pot = Rent::get()?.minimum_balance(game_pot_account_info.data_len());
         require!(
@@ -69,20 +80,15 @@ pub fn process_claim_sol_winnings(
             ErrorCode::InsufficientFunds // Pot would become non-rent-exempt or have not enough funds
         );
 
-        // Transfer SOL from GamePotSol PDA to UserPlatformEscrow PDA (actual lamports)
-        **game_pot_account_info.try_borrow_mut_lamports()? -= winnings_amount;
-        **user_escrow_account_info.try_borrow_mut_lamports()? += winnings_amount;
+        // Transfer SOL from GamePotSol PDA to UserPlatformEscrow PDA and credit its balance field
+        let user_platform_escrow_data = &mut ctx.accounts.user_platform_escrow;
+        accounting::transfer_to_escrow(&game_pot_account_info, user_platform_escrow_data, winnings_amount)?;
         msg!(
-            "Transferred {} lamports from GamePotSol to UserPlatformEscrow (PDA lamports).",
+            "Transferred {} lamports from GamePotSol to UserPlatformEscrow.",
             winnings_amount
         );
-
-        // Update the balance field in UserPlatformEscrow struct
-        let user_platform_escrow_data = &mut ctx.accounts.user_platform_escrow;
-        user_platform_escrow_data.balance = user_platform_escrow_data
-            .balance
-            .checked_add(winnings_amount)
-            .ok_or(ErrorCode::CalculationError)?;
+        // Winnings offset the day's wagers when checking the loss limit
+        user_platform_escrow_data.record_winnings(winnings_amount, Clock::get()?.unix_timestamp)?;
         msg!(
             "UserPlatformEscrow struct 'balance' field updated to: {}",
             user_platform_escrow_data.balance
@@ -91,13 +97,8 @@ pub fn process_claim_sol_winnings(
         msg!("No SOL winnings to claim (amount is zero).");
     }
 
-    // Mark SOL winnings as claimed in RoundState
-    round_state_data.winner_sol_claimed = 1; // 1 for true
-                                             // Optionally, update status to SolClaimed if this is a distinct step before RewardPotAccountsCreated
-    round_state_data.set_status(RoundStatus::SolClaimed);
-
     msg!(
-        "SOL winnings of {} claimed successfully by {}. Round status updated to SolClaimed.",
+        "SOL winnings of {} claimed successfully by {}.",
         winnings_amount,
         ctx.accounts.winner_signer.key()
     );
@@ -0,0 +1,148 @@
+use crate::token_transfer::transfer_checked_with_hook;
+use crate::{ErrorCode, GamePotSol, GameState, RoundState, RoundStatus, UserTokenEscrow};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface, TransferChecked};
+
+#[derive(Accounts)]
+#[instruction(table_id: u64, round_id_for_pdas: u64)]
+pub struct ClaimTokenWinnings<'info> {
+    #[account(mut)]
+    pub winner_signer: Signer<'info>,
+
+    #[account(
+        seeds = [b"game_state".as_ref(), &table_id.to_le_bytes()],
+        bump,
+        constraint = game_state.is_token_table() @ ErrorCode::InvalidTableDenomination
+    )]
+    pub game_state: Box<Account<'info, GameState>>,
+
+    #[account(address = game_state.bet_mint @ ErrorCode::InvalidMintAccount)]
+    pub bet_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        seeds = [b"user_token_escrow", bet_mint.key().as_ref(), winner_signer.key().as_ref()],
+        bump = user_token_escrow.bump,
+        constraint = user_token_escrow.user_authority == winner_signer.key() @ ErrorCode::UnauthorizedEscrowAccess
+    )]
+    pub user_token_escrow: Box<Account<'info, UserTokenEscrow>>,
+
+    #[account(
+        mut,
+        associated_token::mint = bet_mint,
+        associated_token::authority = user_token_escrow,
+        associated_token::token_program = token_program
+    )]
+    pub escrow_token_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [b"round_state".as_ref(), &table_id.to_le_bytes(), &round_id_for_pdas.to_le_bytes()],
+        bump,
+        constraint = round_state.load()?.status_discriminant == RoundStatus::AwaitingSolClaim as u8 @ ErrorCode::RoundNotInAwaitingSolClaimState
+    )]
+    pub round_state: AccountLoader<'info, RoundState>,
+
+    #[account(
+        seeds = [b"sol_pot".as_ref(), &table_id.to_le_bytes(), &round_id_for_pdas.to_le_bytes()],
+        bump
+    )]
+    pub game_pot: Box<Account<'info, GamePotSol>>,
+
+    #[account(
+        mut,
+        associated_token::mint = bet_mint,
+        associated_token::authority = game_pot,
+        associated_token::token_program = token_program
+    )]
+    pub round_token_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn process_claim_token_winnings<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ClaimTokenWinnings<'info>>,
+    table_id: u64,
+    round_id_for_pdas: u64,
+) -> Result<()> {
+    msg!("--- Instruction: ClaimTokenWinnings ---");
+    msg!("Winner Signer: {}", ctx.accounts.winner_signer.key());
+    msg!("User Token Escrow: {}", ctx.accounts.user_token_escrow.key());
+    msg!("RoundState PDA: {}", ctx.accounts.round_state.key());
+    msg!("Round Token Vault: {}", ctx.accounts.round_token_vault.key());
+    msg!("Table ID: {}", table_id);
+    msg!("Round ID for PDAs: {}", round_id_for_pdas);
+
+    let winnings_amount = ctx
+        .accounts
+        .round_state
+        .load_mut()?
+        .claim_winner_payout(ctx.accounts.winner_signer.key())?;
+    msg!("Attempting to claim {} token winnings.", winnings_amount);
+
+    if winnings_amount > 0 {
+        require!(
+            ctx.accounts.round_token_vault.amount >= winnings_amount,
+            ErrorCode::InsufficientFunds
+        );
+
+        let game_pot_bump = ctx.bumps.game_pot;
+        let pot_signer_seeds: &[&[u8]] = &[
+            b"sol_pot".as_ref(),
+            &table_id.to_le_bytes(),
+            &round_id_for_pdas.to_le_bytes(),
+            &[game_pot_bump],
+        ];
+        let all_pot_signer_seeds = &[pot_signer_seeds][..];
+
+        let vault_balance_before = ctx.accounts.escrow_token_vault.amount;
+        transfer_checked_with_hook(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.round_token_vault.to_account_info(),
+                    to: ctx.accounts.escrow_token_vault.to_account_info(),
+                    authority: ctx.accounts.game_pot.to_account_info(),
+                    mint: ctx.accounts.bet_mint.to_account_info(),
+                },
+                all_pot_signer_seeds,
+            )
+            .with_remaining_accounts(ctx.remaining_accounts.to_vec()),
+            winnings_amount,
+            ctx.accounts.bet_mint.decimals,
+        )?;
+
+        ctx.accounts.escrow_token_vault.reload()?;
+        let amount_received = ctx
+            .accounts
+            .escrow_token_vault
+            .amount
+            .checked_sub(vault_balance_before)
+            .ok_or(ErrorCode::CalculationError)?;
+        msg!(
+            "Transferred {} token winnings to escrow vault; {} received after transfer fees.",
+            winnings_amount,
+            amount_received
+        );
+
+        let user_token_escrow_data = &mut ctx.accounts.user_token_escrow;
+        user_token_escrow_data.balance = user_token_escrow_data
+            .balance
+            .checked_add(amount_received)
+            .ok_or(ErrorCode::CalculationError)?;
+        msg!(
+            "UserTokenEscrow struct 'balance' field updated to: {}",
+            user_token_escrow_data.balance
+        );
+    } else {
+        msg!("No token winnings to claim (amount is zero).");
+    }
+
+    msg!(
+        "Token winnings of {} claimed successfully by {}.",
+        winnings_amount,
+        ctx.accounts.winner_signer.key()
+    );
+    msg!("--- ClaimTokenWinnings finished ---");
+    Ok(())
+}
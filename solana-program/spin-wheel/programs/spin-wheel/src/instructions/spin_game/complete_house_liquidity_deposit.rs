@@ -0,0 +1,119 @@
+use crate::instructions::mint_tokens::internal_perform_mint;
+use crate::{ErrorCode, HouseBankroll, LpDepositRequest, MINT_AUTHORITY_SEED};
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::clock::Clock;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{spl_token_2022, Mint, Token2022, TokenAccount},
+};
+
+#[derive(Accounts)]
+pub struct CompleteHouseLiquidityDeposit<'info> {
+    #[account(mut)]
+    pub provider: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"house_bankroll"],
+        bump = house_bankroll.bump
+    )]
+    pub house_bankroll: Box<Account<'info, HouseBankroll>>,
+
+    #[account(
+        mut,
+        address = house_bankroll.lp_mint @ ErrorCode::InvalidMintAccount
+    )]
+    pub lp_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        init_if_needed,
+        payer = provider,
+        associated_token::mint = lp_mint,
+        associated_token::authority = provider,
+        associated_token::token_program = token_program
+    )]
+    pub provider_lp_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        close = provider,
+        seeds = [b"lp_deposit", provider.key().as_ref()],
+        bump = lp_deposit_request.bump,
+        constraint = lp_deposit_request.provider == provider.key() @ ErrorCode::UnauthorizedAccess
+    )]
+    pub lp_deposit_request: Box<Account<'info, LpDepositRequest>>,
+
+    /// CHECK: The PDA that signs the LP mint. Verified again inside internal_perform_mint.
+    #[account(seeds = [MINT_AUTHORITY_SEED], bump)]
+    pub mint_authority_pda: AccountInfo<'info>,
+
+    #[account(address = spl_token_2022::ID @ ErrorCode::InvalidTokenProgram)]
+    pub token_program: Program<'info, Token2022>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn process_complete_house_liquidity_deposit(
+    ctx: Context<CompleteHouseLiquidityDeposit>,
+) -> Result<()> {
+    msg!("--- Instruction: CompleteHouseLiquidityDeposit ---");
+    msg!("Provider: {}", ctx.accounts.provider.key());
+
+    let lamports = ctx.accounts.lp_deposit_request.lamports;
+    let available_at = ctx.accounts.lp_deposit_request.available_at;
+    let clock = Clock::get()?;
+    msg!(
+        "Pending lamports: {}, available at: {}, now: {}",
+        lamports,
+        available_at,
+        clock.unix_timestamp
+    );
+
+    require!(
+        clock.unix_timestamp >= available_at,
+        ErrorCode::LpDepositDelayActive
+    );
+
+    let lp_supply = ctx.accounts.lp_mint.supply;
+    let shares = ctx
+        .accounts
+        .house_bankroll
+        .shares_for_queued_deposit(&ctx.accounts.lp_deposit_request, lp_supply)?;
+    msg!(
+        "Pool balance: {}, LP supply: {}, shares to mint: {}",
+        ctx.accounts.house_bankroll.balance,
+        lp_supply,
+        shares
+    );
+    require!(shares > 0, ErrorCode::InvalidLpShareAmount);
+
+    let house_bankroll = &mut ctx.accounts.house_bankroll;
+    house_bankroll.pending_deposits = house_bankroll
+        .pending_deposits
+        .checked_sub(lamports)
+        .ok_or(ErrorCode::CalculationError)?;
+    house_bankroll.balance = house_bankroll
+        .balance
+        .checked_add(lamports)
+        .ok_or(ErrorCode::CalculationError)?;
+
+    internal_perform_mint(
+        &ctx.accounts.mint_authority_pda,
+        &ctx.accounts.lp_mint,
+        &ctx.accounts.provider_lp_token_account,
+        &ctx.accounts.token_program,
+        ctx.bumps.mint_authority_pda,
+        shares,
+        None, // LP shares track deposits, so they have no supply cap
+        ctx.program_id,
+    )?;
+
+    msg!(
+        "Minted {} LP shares to {}. Bankroll balance: {}",
+        shares,
+        ctx.accounts.provider_lp_token_account.key(),
+        ctx.accounts.house_bankroll.balance
+    );
+    msg!("--- CompleteHouseLiquidityDeposit finished ---");
+    Ok(())
+}
@@ -0,0 +1,117 @@
+use crate::{ErrorCode, HouseBankroll, LpWithdrawalRequest};
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::clock::Clock;
+use anchor_spl::token_interface::{self, spl_token_2022, Burn, Mint, Token2022, TokenAccount};
+
+#[derive(Accounts)]
+pub struct CompleteHouseLiquidityWithdrawal<'info> {
+    #[account(mut)]
+    pub provider: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"house_bankroll"],
+        bump = house_bankroll.bump
+    )]
+    pub house_bankroll: Box<Account<'info, HouseBankroll>>,
+
+    #[account(
+        mut,
+        address = house_bankroll.lp_mint @ ErrorCode::InvalidMintAccount
+    )]
+    pub lp_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = lp_mint,
+        associated_token::authority = house_bankroll,
+        associated_token::token_program = token_program
+    )]
+    pub lp_withdrawal_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        close = provider,
+        seeds = [b"lp_withdrawal", provider.key().as_ref()],
+        bump = lp_withdrawal_request.bump,
+        constraint = lp_withdrawal_request.provider == provider.key() @ ErrorCode::UnauthorizedAccess
+    )]
+    pub lp_withdrawal_request: Box<Account<'info, LpWithdrawalRequest>>,
+
+    #[account(address = spl_token_2022::ID @ ErrorCode::InvalidTokenProgram)]
+    pub token_program: Program<'info, Token2022>,
+}
+
+pub fn process_complete_house_liquidity_withdrawal(
+    ctx: Context<CompleteHouseLiquidityWithdrawal>,
+) -> Result<()> {
+    msg!("--- Instruction: CompleteHouseLiquidityWithdrawal ---");
+    msg!("Provider: {}", ctx.accounts.provider.key());
+
+    let shares = ctx.accounts.lp_withdrawal_request.shares;
+    let available_at = ctx.accounts.lp_withdrawal_request.available_at;
+    let clock = Clock::get()?;
+    msg!(
+        "Pending shares: {}, available at: {}, now: {}",
+        shares,
+        available_at,
+        clock.unix_timestamp
+    );
+
+    require!(shares > 0, ErrorCode::NoPendingLpWithdrawal);
+    require!(
+        clock.unix_timestamp >= available_at,
+        ErrorCode::LpWithdrawalDelayActive
+    );
+
+    let lp_supply = ctx.accounts.lp_mint.supply;
+    let lamports_out = ctx.accounts.house_bankroll.lamports_for_shares(shares, lp_supply)?;
+    msg!(
+        "Pool balance: {}, LP supply: {}, lamports out: {}",
+        ctx.accounts.house_bankroll.balance,
+        lp_supply,
+        lamports_out
+    );
+    // Liquidity reserved for open fixed-odds rounds stays in the pool until they settle.
+    require!(
+        lamports_out <= ctx.accounts.house_bankroll.available_liquidity(),
+        ErrorCode::InsufficientBankrollLiquidity
+    );
+
+    let bankroll_signer_seeds: &[&[u8]] = &[b"house_bankroll", &[ctx.accounts.house_bankroll.bump]];
+    let all_bankroll_signer_seeds = &[bankroll_signer_seeds][..];
+
+    token_interface::burn(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Burn {
+                mint: ctx.accounts.lp_mint.to_account_info(),
+                from: ctx.accounts.lp_withdrawal_vault.to_account_info(),
+                authority: ctx.accounts.house_bankroll.to_account_info(),
+            },
+            all_bankroll_signer_seeds,
+        ),
+        shares,
+    )?;
+    msg!("Burned {} LP shares.", shares);
+
+    let bankroll_account_info = ctx.accounts.house_bankroll.to_account_info();
+    let provider_account_info = ctx.accounts.provider.to_account_info();
+    **bankroll_account_info.try_borrow_mut_lamports()? -= lamports_out;
+    **provider_account_info.try_borrow_mut_lamports()? += lamports_out;
+
+    let house_bankroll = &mut ctx.accounts.house_bankroll;
+    house_bankroll.balance = house_bankroll
+        .balance
+        .checked_sub(lamports_out)
+        .ok_or(ErrorCode::CalculationError)?;
+
+    msg!(
+        "Transferred {} lamports to provider. Bankroll balance: {}, reserved: {}",
+        lamports_out,
+        house_bankroll.balance,
+        house_bankroll.reserved_exposure
+    );
+    msg!("--- CompleteHouseLiquidityWithdrawal finished ---");
+    Ok(())
+}
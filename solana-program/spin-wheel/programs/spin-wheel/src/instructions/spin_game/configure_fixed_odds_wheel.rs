@@ -0,0 +1,86 @@
+use crate::{ErrorCode, GameMode, GameState, PlatformConfig, WheelSegment, MAX_WHEEL_SEGMENTS};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(table_id: u64)]
+pub struct ConfigureFixedOddsWheel<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"platform_config"],
+        bump,
+        constraint = platform_config.authority == authority.key() @ ErrorCode::UnauthorizedAccess,
+        constraint = platform_config.is_initialized @ ErrorCode::UnauthorizedAccess
+    )]
+    pub platform_config: Box<Account<'info, PlatformConfig>>,
+
+    #[account(
+        mut,
+        seeds = [b"game_state".as_ref(), &table_id.to_le_bytes()],
+        bump,
+        constraint = !game_state.is_retired @ ErrorCode::TableRetired,
+        constraint = !game_state.is_token_table() @ ErrorCode::InvalidTableDenomination
+    )]
+    pub game_state: Box<Account<'info, GameState>>,
+}
+
+/// Passing no segments switches the table back to the pari-mutuel wheel.
+pub fn process_configure_fixed_odds_wheel(
+    ctx: Context<ConfigureFixedOddsWheel>,
+    table_id: u64,
+    segments: Vec<WheelSegment>,
+) -> Result<()> {
+    msg!("--- Instruction: ConfigureFixedOddsWheel ---");
+    msg!("Authority: {}", ctx.accounts.authority.key());
+    msg!("Table ID: {}", table_id);
+    msg!("Segment count: {}", segments.len());
+
+    require!(segments.len() <= MAX_WHEEL_SEGMENTS, ErrorCode::InvalidWheelSegment);
+
+    if !segments.is_empty() {
+        require!(segments.len() >= 2, ErrorCode::InvalidWheelSegment);
+        let total_weight: u64 = segments.iter().map(|segment| segment.weight as u64).sum();
+        require!(total_weight > 0, ErrorCode::InvalidWheelSegment);
+
+        // A bet on any segment must not return more than its stake on average:
+        // multiplier * weight / total_weight <= 1x.
+        for (i, segment) in segments.iter().enumerate() {
+            require!(
+                segment.weight > 0 && segment.multiplier_basis_points > 0,
+                ErrorCode::InvalidWheelSegment
+            );
+            let expected_return = (segment.multiplier_basis_points as u64) * (segment.weight as u64);
+            require!(
+                expected_return <= 10_000 * total_weight,
+                ErrorCode::WheelSegmentExpectedValueTooHigh
+            );
+            msg!(
+                "  Segment {}: {} bp multiplier, weight {}/{}",
+                i,
+                segment.multiplier_basis_points,
+                segment.weight,
+                total_weight
+            );
+        }
+    }
+
+    // Rounds snapshot the wheel when they start, so open rounds keep their odds.
+    let game_state = &mut ctx.accounts.game_state;
+    game_state.wheel_segments = [WheelSegment::default(); MAX_WHEEL_SEGMENTS];
+    game_state.wheel_segments[..segments.len()].copy_from_slice(&segments);
+    game_state.wheel_segment_count = segments.len() as u8;
+    game_state.game_mode = if segments.is_empty() {
+        GameMode::Parimutuel
+    } else {
+        GameMode::FixedOdds
+    };
+
+    msg!(
+        "Table {} game mode set to {:?} with {} segment(s).",
+        game_state.table_id,
+        game_state.game_mode,
+        game_state.wheel_segment_count
+    );
+    msg!("--- ConfigureFixedOddsWheel finished ---");
+    Ok(())
+}
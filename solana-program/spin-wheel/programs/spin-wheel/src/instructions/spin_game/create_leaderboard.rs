@@ -0,0 +1,88 @@
+use crate::{ErrorCode, Leaderboard, LeaderboardMetric, PlatformConfig, LEADERBOARD_SIZE};
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{spl_token_2022, Mint, Token2022, TokenAccount},
+};
+
+#[derive(Accounts)]
+#[instruction(leaderboard_id: u64)]
+pub struct CreateLeaderboard<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"platform_config"],
+        bump,
+        constraint = platform_config.authority == authority.key() @ ErrorCode::UnauthorizedAccess,
+        constraint = platform_config.is_initialized @ ErrorCode::UnauthorizedAccess
+    )]
+    pub platform_config: Box<Account<'info, PlatformConfig>>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + std::mem::size_of::<Leaderboard>(),
+        seeds = [b"leaderboard".as_ref(), &leaderboard_id.to_le_bytes()],
+        bump
+    )]
+    pub leaderboard: Box<Account<'info, Leaderboard>>,
+
+    #[account(address = platform_config.cashino_mint @ ErrorCode::InvalidMintAccount)]
+    pub cashino_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Holds the epoch's CASHINO prizes. Anyone can create an ATA ahead of time, so an existing
+    /// one is taken over rather than failing the creation.
+    #[account(
+        init_if_needed,
+        payer = authority,
+        associated_token::mint = cashino_mint,
+        associated_token::authority = leaderboard,
+        associated_token::token_program = token_program
+    )]
+    pub prize_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(address = spl_token_2022::ID @ ErrorCode::InvalidTokenProgram)]
+    pub token_program: Program<'info, Token2022>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn process_create_leaderboard(
+    ctx: Context<CreateLeaderboard>,
+    leaderboard_id: u64,
+    metric: LeaderboardMetric,
+    start_time: i64,
+    end_time: i64,
+    prize_shares_basis_points: Vec<u16>,
+) -> Result<()> {
+    msg!("--- Instruction: CreateLeaderboard ---");
+    msg!("Authority: {}", ctx.accounts.authority.key());
+    msg!("Leaderboard ID: {}, metric: {:?}", leaderboard_id, metric);
+    msg!("Epoch: {} -> {}", start_time, end_time);
+    msg!("Prize shares (bp): {:?}", prize_shares_basis_points);
+
+    require!(end_time > start_time, ErrorCode::InvalidLeaderboardConfig);
+    require!(
+        prize_shares_basis_points.len() <= LEADERBOARD_SIZE,
+        ErrorCode::InvalidLeaderboardConfig
+    );
+    let total_share: u32 = prize_shares_basis_points.iter().map(|s| *s as u32).sum();
+    require!(total_share <= 10_000, ErrorCode::InvalidLeaderboardConfig);
+
+    let leaderboard = &mut ctx.accounts.leaderboard;
+    leaderboard.leaderboard_id = leaderboard_id;
+    leaderboard.metric = metric;
+    leaderboard.start_time = start_time;
+    leaderboard.end_time = end_time;
+    leaderboard.entry_count = 0;
+    leaderboard.prize_shares_basis_points = [0; LEADERBOARD_SIZE];
+    leaderboard.prize_shares_basis_points[..prize_shares_basis_points.len()]
+        .copy_from_slice(&prize_shares_basis_points);
+    leaderboard.prize_pool = 0;
+    leaderboard.bump = ctx.bumps.leaderboard;
+
+    msg!("Leaderboard created. Prize vault: {}", ctx.accounts.prize_vault.key());
+    msg!("--- CreateLeaderboard finished ---");
+    Ok(())
+}
@@ -8,13 +8,13 @@ use anchor_spl::{
 };
 
 #[derive(Accounts)]
-#[instruction(round_id_for_pdas: u64)]
+#[instruction(table_id: u64, round_id_for_pdas: u64)]
 pub struct CreateRewardPotAccounts<'info> {
     #[account(mut)] 
     pub authority: Signer<'info>,
 
     #[account(
-        seeds = [b"game_state"],
+        seeds = [b"game_state".as_ref(), &table_id.to_le_bytes()],
         bump,
         constraint = game_state.authority == authority.key() @ ErrorCode::UnauthorizedAccess,
     )]
@@ -22,7 +22,7 @@ pub struct CreateRewardPotAccounts<'info> {
 
     #[account(
         mut, 
-        seeds = [b"round_state".as_ref(), &round_id_for_pdas.to_le_bytes()],
+        seeds = [b"round_state".as_ref(), &table_id.to_le_bytes(), &round_id_for_pdas.to_le_bytes()],
         bump,
         constraint = round_state.load()?.status_discriminant == RoundStatus::SolClaimed as u8 @ ErrorCode::RoundNotInCorrectState,
     )]
@@ -37,7 +37,7 @@ pub struct CreateRewardPotAccounts<'info> {
         init,
         payer = authority,
         space = 8 + std::mem::size_of::<RoundCashinoRewardsPot>(),
-        seeds = [b"cashino_round_pot".as_ref(), &round_id_for_pdas.to_le_bytes()],
+        seeds = [b"cashino_round_pot".as_ref(), &table_id.to_le_bytes(), &round_id_for_pdas.to_le_bytes()],
         bump
     )]
     pub round_cashino_rewards_pot_account: Box<Account<'info, RoundCashinoRewardsPot>>, 
@@ -61,10 +61,12 @@ pub struct CreateRewardPotAccounts<'info> {
 #[inline(never)]
 pub fn process_create_reward_pot_accounts(
     ctx: Context<CreateRewardPotAccounts>,
+    table_id: u64,
     round_id_for_pdas: u64,
 ) -> Result<()> {
     msg!("--- Instruction: CreateRewardPotAccounts ---");
     msg!("Authority: {}", ctx.accounts.authority.key());
+    msg!("Target Table ID: {}", table_id);
     msg!("Target Round ID (for PDAs): {}", round_id_for_pdas);
     
     let current_status = ctx.accounts.round_state.load()?.get_status()?;
@@ -0,0 +1,69 @@
+use crate::{ErrorCode, GamePotSol, GameState, RoundState, RoundStatus};
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+#[derive(Accounts)]
+#[instruction(table_id: u64, round_id_for_pdas: u64)]
+pub struct CreateRoundTokenVault<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"game_state".as_ref(), &table_id.to_le_bytes()],
+        bump,
+        constraint = game_state.authority == authority.key() @ ErrorCode::UnauthorizedAccess,
+        constraint = game_state.is_token_table() @ ErrorCode::InvalidTableDenomination
+    )]
+    pub game_state: Box<Account<'info, GameState>>,
+
+    #[account(
+        seeds = [b"round_state".as_ref(), &table_id.to_le_bytes(), &round_id_for_pdas.to_le_bytes()],
+        bump,
+        constraint = round_state.load()?.status_discriminant == RoundStatus::Active as u8 @ ErrorCode::RoundNotActive,
+    )]
+    pub round_state: AccountLoader<'info, RoundState>,
+
+    #[account(
+        seeds = [b"sol_pot".as_ref(), &table_id.to_le_bytes(), &round_id_for_pdas.to_le_bytes()],
+        bump
+    )]
+    pub game_pot: Box<Account<'info, GamePotSol>>,
+
+    #[account(address = game_state.bet_mint @ ErrorCode::InvalidMintAccount)]
+    pub bet_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        init,
+        payer = authority,
+        associated_token::mint = bet_mint,
+        associated_token::authority = game_pot,
+        associated_token::token_program = token_program
+    )]
+    pub round_token_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn process_create_round_token_vault(
+    ctx: Context<CreateRoundTokenVault>,
+    table_id: u64,
+    round_id_for_pdas: u64,
+) -> Result<()> {
+    msg!("--- Instruction: CreateRoundTokenVault ---");
+    msg!("Authority: {}", ctx.accounts.authority.key());
+    msg!("Target Table ID: {}", table_id);
+    msg!("Target Round ID (for PDAs): {}", round_id_for_pdas);
+    msg!(
+        "Initialized round token vault {} for mint {} owned by GamePotSol PDA {}.",
+        ctx.accounts.round_token_vault.key(),
+        ctx.accounts.bet_mint.key(),
+        ctx.accounts.game_pot.key()
+    );
+    msg!("--- CreateRoundTokenVault finished ---");
+    Ok(())
+}
@@ -0,0 +1,152 @@
+use crate::{
+    EmissionSchedule, ErrorCode, GameState, PayoutMode, PlatformConfig, GAME_STATE_VERSION,
+    MAX_BET_AMOUNT, MAX_GAME_HOUSE_FEE_BASIS_POINTS, MAX_ROUND_DURATION, MIN_BET_AMOUNT,
+    MIN_ROUND_DURATION,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+#[derive(Accounts)]
+#[instruction(table_id: u64)]
+pub struct CreateTable<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"platform_config"],
+        bump,
+        constraint = platform_config.authority == authority.key() @ ErrorCode::UnauthorizedAccess,
+        constraint = platform_config.is_initialized @ ErrorCode::UnauthorizedAccess,
+        constraint = platform_config.table_count == table_id @ ErrorCode::InvalidTableId
+    )]
+    pub platform_config: Box<Account<'info, PlatformConfig>>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + std::mem::size_of::<GameState>(),
+        seeds = [b"game_state".as_ref(), &table_id.to_le_bytes()],
+        bump
+    )]
+    pub game_state: Box<Account<'info, GameState>>,
+
+    /// Leave empty for a SOL table; pass an SPL or Token-2022 mint to denominate the table in it.
+    pub bet_mint: Option<Box<InterfaceAccount<'info, Mint>>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn process_create_table(
+    ctx: Context<CreateTable>,
+    table_id: u64,
+    house_fee_basis_points: u16,
+    min_bet_amount: u64,
+    max_bet_amount: u64,
+    round_duration: i64,
+) -> Result<()> {
+    msg!("--- Instruction: CreateTable ---");
+    msg!("Authority: {}", ctx.accounts.authority.key());
+    msg!("Table ID (from client, current platform_config.table_count): {}", table_id);
+    msg!(
+        "New GameState PDA to be initialized at: {}",
+        ctx.accounts.game_state.key()
+    );
+    msg!(
+        "Initial House Fee Basis Points to set: {}",
+        house_fee_basis_points
+    );
+    msg!("Bet limits to set: min {}, max {}", min_bet_amount, max_bet_amount);
+    msg!("Round duration to set: {} seconds", round_duration);
+    let bet_mint = ctx
+        .accounts
+        .bet_mint
+        .as_ref()
+        .map(|mint| mint.key())
+        .unwrap_or_default();
+    msg!("Bet mint to set (default = native SOL): {}", bet_mint);
+
+    require!(
+        house_fee_basis_points <= MAX_GAME_HOUSE_FEE_BASIS_POINTS,
+        ErrorCode::InvalidHouseFeeConfig
+    );
+    msg!(
+        "House fee basis points {} validated against max {}.",
+        house_fee_basis_points,
+        MAX_GAME_HOUSE_FEE_BASIS_POINTS
+    );
+
+    require!(
+        min_bet_amount > 0 && min_bet_amount <= max_bet_amount,
+        ErrorCode::InvalidBetLimits
+    );
+    if bet_mint == Pubkey::default() {
+        // Platform bounds are expressed in lamports, so they only apply to SOL tables.
+        require!(
+            min_bet_amount >= MIN_BET_AMOUNT && max_bet_amount <= MAX_BET_AMOUNT,
+            ErrorCode::InvalidBetLimits
+        );
+        msg!(
+            "Bet limits validated against platform bounds {} - {}.",
+            MIN_BET_AMOUNT,
+            MAX_BET_AMOUNT
+        );
+    }
+
+    require!(
+        (MIN_ROUND_DURATION..=MAX_ROUND_DURATION).contains(&round_duration),
+        ErrorCode::InvalidTimeParameters
+    );
+    msg!("Round duration validated ({} seconds).", round_duration);
+
+    let platform_config = &mut ctx.accounts.platform_config;
+    let game_state = &mut ctx.accounts.game_state;
+
+    game_state.version = GAME_STATE_VERSION;
+    game_state.authority = platform_config.authority;
+    game_state.house_wallet = platform_config.house_wallet;
+    game_state.house_fee_basis_points = house_fee_basis_points;
+    game_state.round_counter = 0; // Start round counter at 0
+    game_state.cashino_mint = platform_config.cashino_mint;
+    game_state.table_id = table_id;
+    game_state.min_bet_amount = min_bet_amount;
+    game_state.max_bet_amount = max_bet_amount;
+    game_state.round_duration = round_duration;
+    game_state.is_retired = false;
+    game_state.bet_mint = bet_mint;
+    game_state.payout_mode = PayoutMode::SingleWinner;
+    game_state.payout_tier_count = 0;
+    game_state.emission = EmissionSchedule::default(); // Flat CASHINO_REWARD_PER_ROUND_UNITS until set_emission_schedule is called
+    game_state.is_initialized = true;
+
+    platform_config.table_count = platform_config
+        .table_count
+        .checked_add(1)
+        .ok_or(ErrorCode::CalculationError)?;
+
+    msg!("Table {} created successfully in GameState PDA.", game_state.table_id);
+    msg!("  Authority set to: {}", game_state.authority);
+    msg!("  House Wallet set to: {}", game_state.house_wallet);
+    msg!(
+        "  House Fee Basis Points set to: {}",
+        game_state.house_fee_basis_points
+    );
+    msg!("  $CASHINO Mint set to: {}", game_state.cashino_mint);
+    msg!(
+        "  Bet limits set to: min {}, max {}",
+        game_state.min_bet_amount,
+        game_state.max_bet_amount
+    );
+    msg!("  Round Duration set to: {}", game_state.round_duration);
+    msg!("  Bet Mint set to: {}", game_state.bet_mint);
+    msg!(
+        "  Round Counter initialized to: {}",
+        game_state.round_counter
+    );
+    msg!(
+        "Platform table count incremented to: {}",
+        platform_config.table_count
+    );
+    msg!("--- CreateTable finished ---");
+    Ok(())
+}
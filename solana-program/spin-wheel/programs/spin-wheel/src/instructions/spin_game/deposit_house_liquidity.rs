@@ -0,0 +1,92 @@
+use crate::{ErrorCode, HouseBankroll, LpDepositRequest};
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::clock::Clock;
+use anchor_lang::system_program;
+use anchor_spl::token_interface::Mint;
+
+#[derive(Accounts)]
+#[instruction(amount: u64)]
+pub struct DepositHouseLiquidity<'info> {
+    #[account(mut)]
+    pub provider: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"house_bankroll"],
+        bump = house_bankroll.bump,
+        constraint = house_bankroll.is_initialized @ ErrorCode::UnauthorizedAccess
+    )]
+    pub house_bankroll: Box<Account<'info, HouseBankroll>>,
+
+    #[account(address = house_bankroll.lp_mint @ ErrorCode::InvalidMintAccount)]
+    pub lp_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    // `init` rather than `init_if_needed`: one deposit waits at a time per provider.
+    #[account(
+        init,
+        payer = provider,
+        space = 8 + std::mem::size_of::<LpDepositRequest>(),
+        seeds = [b"lp_deposit", provider.key().as_ref()],
+        bump
+    )]
+    pub lp_deposit_request: Box<Account<'info, LpDepositRequest>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn process_deposit_house_liquidity(ctx: Context<DepositHouseLiquidity>, amount: u64) -> Result<()> {
+    msg!("--- Instruction: DepositHouseLiquidity ---");
+    msg!("Provider {} depositing {} lamports.", ctx.accounts.provider.key(), amount);
+
+    require!(amount > 0, ErrorCode::InvalidDepositAmount);
+
+    // Snapshot the share price now. Shares are minted once the delay has passed, at no better
+    // than this price, so a deposit placed just before a known house win cannot skim it.
+    let lp_supply = ctx.accounts.lp_mint.supply;
+    let balance = ctx.accounts.house_bankroll.balance;
+    let shares = ctx.accounts.house_bankroll.shares_for_deposit(amount, lp_supply)?;
+    msg!(
+        "Pool balance: {}, LP supply: {}, shares at the current price: {}",
+        balance,
+        lp_supply,
+        shares
+    );
+    require!(shares > 0, ErrorCode::InvalidLpShareAmount);
+
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.provider.to_account_info(),
+                to: ctx.accounts.house_bankroll.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+
+    let house_bankroll = &mut ctx.accounts.house_bankroll;
+    house_bankroll.pending_deposits = house_bankroll
+        .pending_deposits
+        .checked_add(amount)
+        .ok_or(ErrorCode::CalculationError)?;
+
+    let clock = Clock::get()?;
+    let request = &mut ctx.accounts.lp_deposit_request;
+    request.provider = ctx.accounts.provider.key();
+    request.lamports = amount;
+    request.balance_at_request = balance;
+    request.lp_supply_at_request = lp_supply;
+    request.available_at = clock
+        .unix_timestamp
+        .checked_add(house_bankroll.withdrawal_delay)
+        .ok_or(ErrorCode::CalculationError)?;
+    request.bump = ctx.bumps.lp_deposit_request;
+
+    msg!(
+        "LP deposit queued. Pending deposits: {}, available at: {}",
+        house_bankroll.pending_deposits,
+        request.available_at
+    );
+    msg!("--- DepositHouseLiquidity finished ---");
+    Ok(())
+}
@@ -1,5 +1,7 @@
-use crate::{ErrorCode, UserPlatformEscrow};
+use crate::accounting;
+use crate::{ErrorCode, UserPlatformEscrow, USER_PLATFORM_ESCROW_VERSION};
 use anchor_lang::{prelude::*, system_program};
+use anchor_lang::solana_program::clock::Clock;
 
 #[derive(Accounts)]
 #[instruction(amount: u64)]
@@ -40,6 +42,7 @@ pub fn process_deposit_sol(ctx: Context<DepositSol>, amount: u64) -> Result<()>
             ctx.accounts.user.key()
         );
         let escrow_struct_data = &mut ctx.accounts.user_platform_escrow;
+        escrow_struct_data.version = USER_PLATFORM_ESCROW_VERSION;
         escrow_struct_data.user_authority = ctx.accounts.user.key();
         escrow_struct_data.balance = 0;
         escrow_struct_data.bump = ctx.bumps.user_platform_escrow;
@@ -59,6 +62,16 @@ pub fn process_deposit_sol(ctx: Context<DepositSol>, amount: u64) -> Result<()>
         return err!(ErrorCode::UnauthorizedAccess);
     }
 
+    let clock = Clock::get()?;
+    ctx.accounts
+        .user_platform_escrow
+        .record_deposit(amount, clock.unix_timestamp)?;
+    msg!(
+        "Deposit within limits. Deposited today: {}, this week: {}",
+        ctx.accounts.user_platform_escrow.daily_deposited,
+        ctx.accounts.user_platform_escrow.weekly_deposited
+    );
+
     let cpi_context = CpiContext::new(
         ctx.accounts.system_program.to_account_info(),
         system_program::Transfer {
@@ -73,10 +86,7 @@ pub fn process_deposit_sol(ctx: Context<DepositSol>, amount: u64) -> Result<()>
     );
 
     let escrow_struct_final_update = &mut ctx.accounts.user_platform_escrow;
-    escrow_struct_final_update.balance = escrow_struct_final_update
-        .balance
-        .checked_add(amount)
-        .ok_or(ErrorCode::CalculationError)?;
+    accounting::record_escrow_credit(escrow_struct_final_update, amount)?;
     msg!(
         "Escrow struct 'balance' field updated to: {}",
         escrow_struct_final_update.balance
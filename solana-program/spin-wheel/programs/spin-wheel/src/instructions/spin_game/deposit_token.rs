@@ -0,0 +1,139 @@
+use crate::instructions::self_exclude::require_not_self_excluded;
+use crate::token_transfer::transfer_checked_with_hook;
+use crate::{ErrorCode, UserTokenEscrow};
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::clock::Clock;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface, TransferChecked},
+};
+
+#[derive(Accounts)]
+#[instruction(amount: u64)]
+pub struct DepositToken<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    pub bet_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        token::mint = bet_mint,
+        token::authority = user,
+        token::token_program = token_program
+    )]
+    pub user_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = 8 + std::mem::size_of::<UserTokenEscrow>(),
+        seeds = [b"user_token_escrow", bet_mint.key().as_ref(), user.key().as_ref()],
+        bump
+    )]
+    pub user_token_escrow: Box<Account<'info, UserTokenEscrow>>,
+
+    #[account(
+        init_if_needed,
+        payer = user,
+        associated_token::mint = bet_mint,
+        associated_token::authority = user_token_escrow,
+        associated_token::token_program = token_program
+    )]
+    pub escrow_token_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: The player's UserPlatformEscrow PDA, read for its self-exclusion. It may not exist.
+    #[account(seeds = [b"user_escrow", user.key().as_ref()], bump)]
+    pub user_platform_escrow: UncheckedAccount<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn process_deposit_token<'info>(
+    ctx: Context<'_, '_, 'info, 'info, DepositToken<'info>>,
+    amount: u64,
+) -> Result<()> {
+    msg!("--- Instruction: DepositToken ---");
+    msg!(
+        "User {} depositing {} base units of mint {}.",
+        ctx.accounts.user.key(),
+        amount,
+        ctx.accounts.bet_mint.key()
+    );
+
+    require!(amount > 0, ErrorCode::InvalidDepositAmount);
+    require_not_self_excluded(&ctx.accounts.user_platform_escrow, Clock::get()?.unix_timestamp)?;
+
+    let is_first_init_of_struct_fields =
+        ctx.accounts.user_token_escrow.user_authority == Pubkey::default();
+
+    if is_first_init_of_struct_fields {
+        msg!(
+            "UserTokenEscrow struct fields appear uninitialized. Setting for user {}.",
+            ctx.accounts.user.key()
+        );
+        let escrow_struct_data = &mut ctx.accounts.user_token_escrow;
+        escrow_struct_data.user_authority = ctx.accounts.user.key();
+        escrow_struct_data.mint = ctx.accounts.bet_mint.key();
+        escrow_struct_data.balance = 0;
+        escrow_struct_data.bump = ctx.bumps.user_token_escrow;
+    }
+
+    if ctx.accounts.user_token_escrow.user_authority != ctx.accounts.user.key() {
+        msg!(
+            "Unauthorized: Signer {} is not the authority {} of this token escrow account.",
+            ctx.accounts.user.key(),
+            ctx.accounts.user_token_escrow.user_authority
+        );
+        return err!(ErrorCode::UnauthorizedAccess);
+    }
+
+    let vault_balance_before = ctx.accounts.escrow_token_vault.amount;
+
+    transfer_checked_with_hook(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.user_token_account.to_account_info(),
+                to: ctx.accounts.escrow_token_vault.to_account_info(),
+                authority: ctx.accounts.user.to_account_info(),
+                mint: ctx.accounts.bet_mint.to_account_info(),
+            },
+        )
+        .with_remaining_accounts(ctx.remaining_accounts.to_vec()),
+        amount,
+        ctx.accounts.bet_mint.decimals,
+    )?;
+
+    // Token-2022 transfer fees are withheld on the destination, so only credit what actually arrived.
+    ctx.accounts.escrow_token_vault.reload()?;
+    let amount_received = ctx
+        .accounts
+        .escrow_token_vault
+        .amount
+        .checked_sub(vault_balance_before)
+        .ok_or(ErrorCode::CalculationError)?;
+    msg!(
+        "Transferred {} base units to escrow vault; {} received after transfer fees.",
+        amount,
+        amount_received
+    );
+
+    let escrow_struct_final_update = &mut ctx.accounts.user_token_escrow;
+    escrow_struct_final_update.balance = escrow_struct_final_update
+        .balance
+        .checked_add(amount_received)
+        .ok_or(ErrorCode::CalculationError)?;
+    msg!(
+        "Token escrow 'balance' field updated to: {}",
+        escrow_struct_final_update.balance
+    );
+
+    msg!(
+        "--- DepositToken finished for user {} ---",
+        ctx.accounts.user.key()
+    );
+    Ok(())
+}
@@ -0,0 +1,106 @@
+use crate::accounting;
+use crate::{
+    ErrorCode, GamePotSol, RakebackDistributed, RoundState, RoundStatus, UserPlatformEscrow,
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(table_id: u64, round_id_for_pdas: u64)]
+pub struct DistributeRakeback<'info> {
+    /// Anyone can crank the payout; the amounts are fixed by the round and the schedule.
+    pub cranker: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"round_state".as_ref(), &table_id.to_le_bytes(), &round_id_for_pdas.to_le_bytes()],
+        bump,
+        constraint = round_state.load()?.status_discriminant != RoundStatus::Active as u8 @ ErrorCode::RoundNotInCorrectState
+    )]
+    pub round_state: AccountLoader<'info, RoundState>,
+
+    #[account(
+        mut,
+        seeds = [b"sol_pot".as_ref(), &table_id.to_le_bytes(), &round_id_for_pdas.to_le_bytes()],
+        bump
+    )]
+    pub game_pot_sol: Account<'info, GamePotSol>,
+}
+
+pub fn process_distribute_rakeback<'info>(
+    ctx: Context<'_, '_, 'info, 'info, DistributeRakeback<'info>>,
+    table_id: u64,
+    round_id_for_pdas: u64,
+) -> Result<()> {
+    msg!("--- Instruction: DistributeRakeback ---");
+    msg!("Table ID: {}, Round ID: {}", table_id, round_id_for_pdas);
+
+    let mut round_rw = ctx.accounts.round_state.load_mut()?;
+    require!(
+        round_rw.rakeback_distributed == 0,
+        ErrorCode::RakebackAlreadyDistributed
+    );
+    let reserve = round_rw.rakeback_reserved;
+    let player_count = round_rw.player_count as usize;
+    msg!("Rakeback reserve: {}", reserve);
+    if reserve == 0 {
+        // Nothing was held back, e.g. rakeback was off when the round was finalized
+        round_rw.rakeback_distributed = 1;
+        msg!("No rakeback reserved for this round.");
+        msg!("--- DistributeRakeback finished ---");
+        return Ok(());
+    }
+
+    // Remaining accounts: the UserPlatformEscrow of every player in round order. Rates were
+    // fixed when the round was finalized, and the reserve holds exactly what they pay out.
+    let escrow_accounts = ctx.remaining_accounts;
+    require!(
+        escrow_accounts.len() == player_count,
+        ErrorCode::InvalidRakebackAccount
+    );
+
+    let game_pot_account_info = ctx.accounts.game_pot_sol.to_account_info();
+    let mut total_rakeback: u64 = 0;
+    for (i, escrow_account_info) in escrow_accounts.iter().enumerate() {
+        let player = round_rw.players[i];
+        let mut escrow = Account::<UserPlatformEscrow>::try_from(escrow_account_info)?;
+        require_keys_eq!(escrow.user_authority, player.pubkey, ErrorCode::InvalidRakebackAccount);
+        let expected_escrow_key = Pubkey::create_program_address(
+            &[b"user_escrow", escrow.user_authority.as_ref(), &[escrow.bump]],
+            &crate::ID,
+        )
+        .map_err(|_| ErrorCode::InvalidRakebackAccount)?;
+        require_keys_eq!(escrow_account_info.key(), expected_escrow_key, ErrorCode::InvalidRakebackAccount);
+
+        let rate = round_rw.player_rakeback_basis_points[i];
+        let rakeback = round_rw.rakeback_owed(i)?;
+        if rakeback == 0 {
+            continue;
+        }
+
+        accounting::transfer_to_escrow(&game_pot_account_info, &mut escrow, rakeback)?;
+        escrow.exit(&crate::ID)?;
+        total_rakeback = total_rakeback
+            .checked_add(rakeback)
+            .ok_or(ErrorCode::CalculationError)?;
+        msg!(
+            "  Rakeback {} lamports to {} at {} bp (lifetime wagered {})",
+            rakeback,
+            player.pubkey,
+            rate,
+            round_rw.player_total_wagered[i]
+        );
+    }
+
+    require!(total_rakeback == reserve, ErrorCode::CalculationError);
+
+    round_rw.rakeback_reserved = 0;
+    round_rw.rakeback_distributed = 1;
+    emit!(RakebackDistributed {
+        table_id,
+        round_id: round_rw.id,
+        total_rakeback,
+    });
+    msg!("Rakeback distributed: {}", total_rakeback);
+    msg!("--- DistributeRakeback finished ---");
+    Ok(())
+}
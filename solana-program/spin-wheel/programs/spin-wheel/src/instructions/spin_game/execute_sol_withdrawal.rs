@@ -0,0 +1,75 @@
+use crate::instructions::set_fee_split::distribute_house_fee;
+use crate::{ErrorCode, PendingWithdrawal, PlatformConfig};
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::clock::Clock;
+
+#[derive(Accounts)]
+pub struct ExecuteSolWithdrawal<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        mut,
+        close = user,
+        seeds = [b"pending_withdrawal", user.key().as_ref()],
+        bump = pending_withdrawal.bump,
+        constraint = pending_withdrawal.user == user.key() @ ErrorCode::UnauthorizedAccess
+    )]
+    pub pending_withdrawal: Box<Account<'info, PendingWithdrawal>>,
+
+    #[account(seeds = [b"platform_config"], bump)]
+    pub platform_config: Box<Account<'info, PlatformConfig>>,
+
+    /// CHECK: This is the house_wallet Pubkey stored in platform_config.
+    #[account(
+        mut,
+        address = platform_config.house_wallet @ ErrorCode::InvalidHouseWalletAddress
+    )]
+    pub house_wallet: AccountInfo<'info>,
+}
+
+pub fn process_execute_sol_withdrawal<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ExecuteSolWithdrawal<'info>>,
+) -> Result<()> {
+    msg!("--- Instruction: ExecuteSolWithdrawal ---");
+    msg!("User: {}", ctx.accounts.user.key());
+
+    let amount = ctx.accounts.pending_withdrawal.amount;
+    let fee = ctx.accounts.pending_withdrawal.fee;
+    let executable_at = ctx.accounts.pending_withdrawal.executable_at;
+    let clock = Clock::get()?;
+    msg!(
+        "Pending amount: {}, fee: {}, executable at: {}, now: {}",
+        amount,
+        fee,
+        executable_at,
+        clock.unix_timestamp
+    );
+
+    require!(
+        clock.unix_timestamp >= executable_at,
+        ErrorCode::WithdrawalTimelockActive
+    );
+
+    let pending_account_info = ctx.accounts.pending_withdrawal.to_account_info();
+    let user_account_info = ctx.accounts.user.to_account_info();
+    let house_wallet_account_info = ctx.accounts.house_wallet.to_account_info();
+
+    if fee > 0 {
+        distribute_house_fee(
+            &ctx.accounts.platform_config,
+            &pending_account_info,
+            &house_wallet_account_info,
+            ctx.remaining_accounts,
+            fee,
+        )?;
+        msg!("Withdrawal fee of {} distributed.", fee);
+    }
+
+    **pending_account_info.try_borrow_mut_lamports()? -= amount;
+    **user_account_info.try_borrow_mut_lamports()? += amount;
+
+    msg!("Transferred withdrawal amount of {} to user {}.", amount, user_account_info.key());
+    msg!("--- ExecuteSolWithdrawal finished ---");
+    Ok(())
+}
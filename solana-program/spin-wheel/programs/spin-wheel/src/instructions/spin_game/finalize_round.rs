@@ -1,15 +1,25 @@
+use crate::instructions::register_referrer::accrue_referral_shares;
+use crate::instructions::set_fee_split::distribute_house_fee;
 use crate::{
-    ErrorCode, GamePotSol, GameState, RoundState, RoundStatus, SeedArray, SEED_BYTES_LENGTH,
+    ErrorCode, GamePotSol, GameState, JackpotContributed, JackpotHit, JackpotPool, Leaderboard,
+    LeaderboardMetric, PlatformConfig, PlayerData, PlayerStats, RoundHistory, RoundHistoryEntry,
+    RoundState, RoundStatus, RoundWinner, SeedArray, StakingPool, MAX_PLAYERS, SEED_BYTES_LENGTH,
 };
 use anchor_lang::prelude::*;
-use anchor_lang::solana_program::{clock::Clock, hash::hash, pubkey::Pubkey, rent::Rent};
+use anchor_lang::Discriminator;
+use anchor_lang::solana_program::{
+    clock::Clock,
+    hash::{hash, hashv},
+    pubkey::Pubkey,
+    rent::Rent,
+};
 
-fn determine_winner(round_state: &RoundState, current_timestamp: i64) -> Result<u8> {
-    msg!("--- DetermineWinner ---");
+/// Mixes the revealed seed with round data. Every draw made for the round is derived from these bytes.
+pub(crate) fn round_entropy(round_state: &RoundState, current_timestamp: i64) -> Result<SeedArray> {
     let revealed_seed = round_state
         .get_revealed_seed()
         .ok_or(ErrorCode::InvalidRevealedSeed)?;
-    msg!("DetermineWinner: Revealed seed: {:?}", revealed_seed);
+    msg!("RoundEntropy: Revealed seed: {:?}", revealed_seed);
 
     let mut combined_entropy = [0u8; SEED_BYTES_LENGTH as usize];
     for (i, byte) in revealed_seed.iter().enumerate() {
@@ -32,32 +42,39 @@ fn determine_winner(round_state: &RoundState, current_timestamp: i64) -> Result<
     for (i, byte) in id_bytes.iter().enumerate() {
         combined_entropy[i % SEED_BYTES_LENGTH] ^= byte;
     }
-    msg!("DetermineWinner: Entropy combined for hashing.");
-
-    let entropy_hash = hash(&combined_entropy).to_bytes();
-    msg!("DetermineWinner: Entropy hashed.");
+    msg!("RoundEntropy: Entropy combined for hashing.");
+    Ok(combined_entropy)
+}
 
-    let random_value = u64::from_le_bytes(
-        entropy_hash[0..8]
+/// Random value for a side draw (e.g. the jackpot). The domain tag keeps it independent of the winner draw.
+pub(crate) fn draw_random_value(entropy: &SeedArray, domain: &[u8]) -> Result<u64> {
+    let draw_hash = hashv(&[domain, entropy]).to_bytes();
+    Ok(u64::from_le_bytes(
+        draw_hash[0..8]
             .try_into()
             .map_err(|_| ErrorCode::GameCalculationError)?,
-    );
-    msg!("DetermineWinner: Random value generated: {}", random_value);
+    ))
+}
 
-    if round_state.total_sol_pot == 0 {
-        msg!("DetermineWinner Error: Total SOL pot is zero.");
+/// Picks a player index with probability proportional to their bet.
+pub(crate) fn select_weighted_player(
+    players: &[PlayerData],
+    total_weight: u64,
+    random_value: u64,
+) -> Result<u8> {
+    if total_weight == 0 {
+        msg!("SelectWeightedPlayer Error: Total weight is zero.");
         return err!(ErrorCode::NoPlayers);
     }
 
-    let scaled_random = random_value % round_state.total_sol_pot;
+    let scaled_random = random_value % total_weight;
     msg!(
-        "DetermineWinner: Scaled random value (0 to total_sol_pot-1): {}",
+        "SelectWeightedPlayer: Scaled random value (0 to total_weight-1): {}",
         scaled_random
     );
 
     let mut cumulative_bet_amount: u64 = 0;
-    for i in 0..(round_state.player_count as usize) {
-        let player_data = &round_state.players[i];
+    for (i, player_data) in players.iter().enumerate() {
         if player_data.amount == 0 {
             continue;
         }
@@ -65,47 +82,359 @@ fn determine_winner(round_state: &RoundState, current_timestamp: i64) -> Result<
             .checked_add(player_data.amount)
             .ok_or(ErrorCode::GameCalculationError)?;
         msg!(
-            "DetermineWinner: Player index {}: Pubkey {}, Bet SOL {}, Cumulative SOL {}",
+            "SelectWeightedPlayer: Player index {}: Pubkey {}, Bet SOL {}, Cumulative SOL {}",
             i,
             player_data.pubkey,
             player_data.amount,
             cumulative_bet_amount
         );
         if scaled_random < cumulative_bet_amount {
-            msg!("DetermineWinner: Winner determined at index: {}", i);
+            msg!("SelectWeightedPlayer: Player selected at index: {}", i);
             return Ok(i as u8);
         }
     }
 
-    msg!("DetermineWinner Error: Logic failed to select a winner. This should not happen if total_sol_pot > 0 and players exist.");
+    msg!("SelectWeightedPlayer Error: Logic failed to select a player. This should not happen if total_weight > 0 and players exist.");
     err!(ErrorCode::GameCalculationError)
 }
 
+fn determine_winner(round_state: &RoundState, current_timestamp: i64) -> Result<u8> {
+    msg!("--- DetermineWinner ---");
+    let combined_entropy = round_entropy(round_state, current_timestamp)?;
+
+    let entropy_hash = hash(&combined_entropy).to_bytes();
+    msg!("DetermineWinner: Entropy hashed.");
+
+    let random_value = u64::from_le_bytes(
+        entropy_hash[0..8]
+            .try_into()
+            .map_err(|_| ErrorCode::GameCalculationError)?,
+    );
+    msg!("DetermineWinner: Random value generated: {}", random_value);
+
+    select_weighted_player(
+        &round_state.players[..round_state.player_count as usize],
+        round_state.total_sol_pot,
+        random_value,
+    )
+}
+
+/// Draws up to `max_winners` distinct players without replacement, weighted by bet.
+/// First place uses the original winner draw; later places use domain-separated draws.
+fn determine_winners(
+    round_state: &RoundState,
+    max_winners: usize,
+    current_timestamp: i64,
+) -> Result<Vec<u8>> {
+    let first_place = determine_winner(round_state, current_timestamp)?;
+    let mut selected = vec![first_place];
+    if max_winners <= 1 {
+        return Ok(selected);
+    }
+
+    let combined_entropy = round_entropy(round_state, current_timestamp)?;
+    let player_count = round_state.player_count as usize;
+    let mut remaining_players = round_state.players;
+    let mut remaining_weight = round_state
+        .total_sol_pot
+        .checked_sub(remaining_players[first_place as usize].amount)
+        .ok_or(ErrorCode::GameCalculationError)?;
+    remaining_players[first_place as usize].amount = 0;
+
+    while selected.len() < max_winners && remaining_weight > 0 {
+        let place = selected.len() as u8;
+        let domain = [b"tier_winner".as_ref(), &[place]].concat();
+        let random_value = draw_random_value(&combined_entropy, &domain)?;
+        let index = select_weighted_player(
+            &remaining_players[..player_count],
+            remaining_weight,
+            random_value,
+        )?;
+        msg!("DetermineWinners: Place {} drawn at player index {}", place + 1, index);
+
+        remaining_weight = remaining_weight
+            .checked_sub(remaining_players[index as usize].amount)
+            .ok_or(ErrorCode::GameCalculationError)?;
+        remaining_players[index as usize].amount = 0;
+        selected.push(index);
+    }
+    Ok(selected)
+}
+
+/// Checks the round can be settled and that `revealed_seed_arg` hashes to the round's commitment.
+pub(crate) fn validate_round_ready_for_finalize(
+    round_ro: &RoundState,
+    revealed_seed_arg: &SeedArray,
+    current_timestamp: i64,
+) -> Result<()> {
+    msg!("Stored seed_commitment: {:?}", round_ro.seed_commitment);
+    msg!("Round Start Time: {}", round_ro.start_time);
+    msg!("Round End Time: {}", round_ro.end_time);
+    msg!(
+        "Round Current Status Discriminant: {}",
+        round_ro.status_discriminant
+    );
+
+    require!(
+        round_ro.status_discriminant == RoundStatus::Active as u8,
+        ErrorCode::RoundNotActive
+    );
+    require!(
+        current_timestamp >= round_ro.end_time,
+        ErrorCode::RoundNotEnded
+    );
+    require!(round_ro.player_count > 0, ErrorCode::NoPlayers);
+    require!(
+        hash(revealed_seed_arg).to_bytes() == round_ro.seed_commitment,
+        ErrorCode::InvalidRevealedSeed
+    );
+    // Ensure this round hasn't already been finalized past this stage
+    require!(
+        round_ro.winner_sol_pubkey == Pubkey::default(),
+        ErrorCode::RoundAlreadyActive
+    );
+    Ok(())
+}
+
+/// Amounts taken out of the pot when a round is settled, in the table's bet units.
+pub(crate) struct RoundSettlement {
+    pub house_fee: u64,
+    pub jackpot_contribution: u64,
+}
+
+/// Reveals the seed, picks the winners and records the payout split on the round.
+/// `payout_tiers` gives each place's share of the net pot; places that cannot be filled
+/// because there are fewer players, and any rounding dust, go to first place.
+/// Moving funds for the house fee and jackpot contribution is left to the caller.
+pub(crate) fn settle_round_winner(
+    round_rw: &mut RoundState,
+    revealed_seed_arg: SeedArray,
+    house_fee_basis_points: u16,
+    jackpot_contribution_basis_points: u16,
+    payout_tiers: &[u16],
+    current_timestamp: i64,
+) -> Result<RoundSettlement> {
+    round_rw.set_revealed_seed(Some(revealed_seed_arg));
+    msg!("Revealed seed set in RoundState.");
+
+    let winner_indices = determine_winners(round_rw, payout_tiers.len(), current_timestamp)?;
+    msg!("Winner indices determined: {:?}", winner_indices);
+
+    let total_pot_value = round_rw.total_sol_pot;
+
+    let house_fee = total_pot_value
+        .checked_mul(house_fee_basis_points as u64)
+        .and_then(|v| v.checked_div(10_000))
+        .ok_or(ErrorCode::GameCalculationError)?;
+    msg!("House fee calculated: {}", house_fee);
+
+    let jackpot_contribution = total_pot_value
+        .checked_mul(jackpot_contribution_basis_points as u64)
+        .and_then(|v| v.checked_div(10_000))
+        .ok_or(ErrorCode::GameCalculationError)?;
+    msg!("Jackpot contribution calculated: {}", jackpot_contribution);
+
+    let net_winnings = total_pot_value
+        .checked_sub(house_fee)
+        .and_then(|v| v.checked_sub(jackpot_contribution))
+        .ok_or(ErrorCode::GameCalculationError)?;
+    msg!("Net winnings calculated: {}", net_winnings);
+
+    round_rw.winners = [RoundWinner::default(); MAX_PLAYERS];
+    let mut distributed: u64 = 0;
+    for (place, player_index) in winner_indices.iter().enumerate() {
+        let amount = net_winnings
+            .checked_mul(payout_tiers[place] as u64)
+            .and_then(|v| v.checked_div(10_000))
+            .ok_or(ErrorCode::GameCalculationError)?;
+        round_rw.winners[place] = RoundWinner {
+            pubkey: round_rw.players[*player_index as usize].pubkey,
+            amount,
+            claimed: 0,
+            _padding_rw: [0; 7],
+        };
+        distributed = distributed
+            .checked_add(amount)
+            .ok_or(ErrorCode::GameCalculationError)?;
+    }
+    let remainder = net_winnings
+        .checked_sub(distributed)
+        .ok_or(ErrorCode::GameCalculationError)?;
+    round_rw.winners[0].amount = round_rw.winners[0]
+        .amount
+        .checked_add(remainder)
+        .ok_or(ErrorCode::GameCalculationError)?;
+    round_rw.winner_count = winner_indices.len() as u8;
+
+    for (place, winner) in round_rw.winners[..winner_indices.len()].iter().enumerate() {
+        msg!("Place {}: {} wins {}", place + 1, winner.pubkey, winner.amount);
+    }
+
+    round_rw.set_winner_index(Some(winner_indices[0]));
+    round_rw.house_sol_fee = house_fee;
+    round_rw.jackpot_contribution = jackpot_contribution;
+    round_rw.winner_sol_pubkey = round_rw.winners[0].pubkey;
+    round_rw.winner_sol_amount = round_rw.winners[0].amount;
+    round_rw.winner_sol_claimed = 0;
+
+    round_rw.set_status(RoundStatus::AwaitingSolClaim);
+    msg!(
+        "RoundState updated: Winner Pk: {}, Winner Sol Amount: {}, SOL Claimed: {}, Status: AwaitingSolClaim",
+        round_rw.winner_sol_pubkey, round_rw.winner_sol_amount, round_rw.winner_sol_claimed
+    );
+    Ok(RoundSettlement {
+        house_fee,
+        jackpot_contribution,
+    })
+}
+
+/// Runs the round's jackpot draw. On a hit, returns the index of the bettor who takes the jackpot.
+pub(crate) fn draw_jackpot(
+    round_state: &RoundState,
+    jackpot_odds: u64,
+    current_timestamp: i64,
+) -> Result<Option<u8>> {
+    msg!("--- DrawJackpot ---");
+    let combined_entropy = round_entropy(round_state, current_timestamp)?;
+
+    let hit_value = draw_random_value(&combined_entropy, b"jackpot_hit")?;
+    msg!("DrawJackpot: Hit value {} against odds 1 in {}", hit_value, jackpot_odds);
+    if jackpot_odds == 0 || hit_value % jackpot_odds != 0 {
+        return Ok(None);
+    }
+
+    let selection_value = draw_random_value(&combined_entropy, b"jackpot_winner")?;
+    let index = select_weighted_player(
+        &round_state.players[..round_state.player_count as usize],
+        round_state.total_sol_pot,
+        selection_value,
+    )?;
+    Ok(Some(index))
+}
+
+/// Loads a program-owned singleton PDA that may not have been created yet. The account's seeds
+/// constraint fixes its address, so the finalizer can neither leave it out nor swap in another.
+pub(crate) fn load_singleton<T: AccountDeserialize>(account_info: &AccountInfo) -> Result<Option<T>> {
+    if account_info.owner != &crate::ID || account_info.data_is_empty() {
+        return Ok(None);
+    }
+    let data = account_info.try_borrow_data()?;
+    Ok(Some(T::try_deserialize(&mut &data[..])?))
+}
+
+pub(crate) fn store_singleton<T: AccountSerialize>(account_info: &AccountInfo, value: &T) -> Result<()> {
+    let mut data = account_info.try_borrow_mut_data()?;
+    value.try_serialize(&mut &mut data[..])
+}
+
+/// Appends the round to the table's `RoundHistory` and returns the number of entries, or
+/// `None` while the history has not been initialized. The account is required either way, so
+/// a caller can't keep a round out of an existing history by leaving it off.
+pub(crate) fn record_round_history(
+    account_info: &AccountInfo,
+    entry: RoundHistoryEntry,
+) -> Result<Option<u32>> {
+    if account_info.owner != &crate::ID || account_info.data_is_empty() {
+        return Ok(None);
+    }
+    let mut data = account_info.try_borrow_mut_data()?;
+    let end = 8 + std::mem::size_of::<RoundHistory>();
+    require!(
+        data.len() >= end && data[..8] == RoundHistory::DISCRIMINATOR,
+        anchor_lang::error::ErrorCode::AccountDiscriminatorMismatch
+    );
+    let history: &mut RoundHistory = bytemuck::from_bytes_mut(&mut data[8..end]);
+    history.push(entry);
+    Ok(Some(history.len))
+}
+
+/// Splits finalize_round's remaining accounts: the fee split recipients first, then the
+/// PlayerStats of every player in round order, then a [ReferralAccount, ReferrerStats] pair per
+/// player, also in round order. Missing recipients or referral pairs are caught by their consumers.
+pub(crate) fn split_remaining_accounts<T>(
+    accounts: &[T],
+    fee_recipient_count: usize,
+    player_count: usize,
+) -> Result<(&[T], &[T], &[T])> {
+    let (fee_recipients, player_accounts) = accounts.split_at(fee_recipient_count.min(accounts.len()));
+    require!(
+        player_accounts.len() >= player_count,
+        ErrorCode::InvalidPlayerStatsAccount
+    );
+    let (player_stats, referrals) = player_accounts.split_at(player_count);
+    Ok((fee_recipients, player_stats, referrals))
+}
+
+/// Credits the winners' `PlayerStats`. The stats of every bettor are passed in round order,
+/// so the caller doesn't need to know the winners in advance. Each bettor's account was
+/// created by `place_sol_bet`, so a missing one fails the finalization.
+pub(crate) fn record_winner_stats<'info>(
+    players: &[PlayerData],
+    winners: &[RoundWinner],
+    stats_accounts: &'info [AccountInfo<'info>],
+) -> Result<()> {
+    require!(
+        stats_accounts.len() == players.len(),
+        ErrorCode::InvalidPlayerStatsAccount
+    );
+    for (player, stats_account_info) in players.iter().zip(stats_accounts) {
+        let mut stats = Account::<PlayerStats>::try_from(stats_account_info)?;
+        require_keys_eq!(stats.player, player.pubkey, ErrorCode::InvalidPlayerStatsAccount);
+        let expected_stats_key = Pubkey::create_program_address(
+            &[b"player_stats", stats.player.as_ref(), &[stats.bump]],
+            &crate::ID,
+        )
+        .map_err(|_| ErrorCode::InvalidPlayerStatsAccount)?;
+        require_keys_eq!(
+            stats_account_info.key(),
+            expected_stats_key,
+            ErrorCode::InvalidPlayerStatsAccount
+        );
+
+        let Some(winner) = winners.iter().find(|w| w.pubkey == player.pubkey) else {
+            continue;
+        };
+        stats.record_win(winner.amount)?;
+        stats.exit(&crate::ID)?;
+        msg!(
+            "  Player stats for {}: rounds won {}, largest win {}",
+            stats.player,
+            stats.rounds_won,
+            stats.largest_win
+        );
+    }
+    Ok(())
+}
+
 #[derive(Accounts)]
-#[instruction(revealed_seed_arg: SeedArray, round_id_for_pdas: u64)]
+#[instruction(table_id: u64, revealed_seed_arg: SeedArray, round_id_for_pdas: u64)]
 pub struct FinalizeRound<'info> {
     #[account(mut)]
     pub authority: Signer<'info>,
 
     #[account(
         mut,
-        seeds = [b"game_state"],
+        seeds = [b"game_state".as_ref(), &table_id.to_le_bytes()],
         bump,
         constraint = game_state.authority == authority.key() @ ErrorCode::UnauthorizedAccess,
-        constraint = game_state.is_initialized @ ErrorCode::UnauthorizedAccess
+        constraint = game_state.is_initialized @ ErrorCode::UnauthorizedAccess,
+        constraint = !game_state.is_token_table() @ ErrorCode::InvalidTableDenomination
     )]
     pub game_state: Box<Account<'info, GameState>>,
 
+    #[account(seeds = [b"platform_config"], bump)]
+    pub platform_config: Box<Account<'info, PlatformConfig>>,
+
     #[account(
         mut,
-        seeds = [b"round_state", &round_id_for_pdas.to_le_bytes()],
+        seeds = [b"round_state", &table_id.to_le_bytes(), &round_id_for_pdas.to_le_bytes()],
         bump
     )]
     pub round_state: AccountLoader<'info, RoundState>,
 
     #[account(
         mut,
-        seeds = [b"sol_pot", &round_id_for_pdas.to_le_bytes()],
+        seeds = [b"sol_pot", &table_id.to_le_bytes(), &round_id_for_pdas.to_le_bytes()],
         bump
     )]
     pub game_pot_sol: Account<'info, GamePotSol>,
@@ -114,15 +443,54 @@ pub struct FinalizeRound<'info> {
     #[account(mut, address = game_state.house_wallet @ ErrorCode::InvalidHouseWalletAddress)]
     pub house_wallet: AccountInfo<'info>,
 
+    /// CHECK: The StakingPool PDA. Once the pool has been initialized it receives the stakers'
+    /// share of the house fee; before that it is an empty account.
+    #[account(
+        mut,
+        seeds = [b"staking_pool"],
+        bump
+    )]
+    pub staking_pool: UncheckedAccount<'info>,
+
+    /// CHECK: The JackpotPool PDA. Once the jackpot has been initialized it takes this round's
+    /// contribution and runs the jackpot draw; before that it is an empty account.
+    #[account(
+        mut,
+        seeds = [b"jackpot"],
+        bump
+    )]
+    pub jackpot_pool: UncheckedAccount<'info>,
+
+    /// CHECK: The table's RoundHistory PDA. Once it has been initialized every settled round is
+    /// recorded in it; before that it is an empty account.
+    #[account(
+        mut,
+        seeds = [b"round_history", &table_id.to_le_bytes()],
+        bump
+    )]
+    pub round_history: UncheckedAccount<'info>,
+
+    /// The table's winnings leaderboard, derived from `game_state.leaderboards`. Required while
+    /// one is bound.
+    #[account(
+        mut,
+        seeds = [b"leaderboard".as_ref(), &game_state.leaderboards.won_leaderboard_id.to_le_bytes()],
+        bump = leaderboard.bump,
+        constraint = leaderboard.metric == LeaderboardMetric::Won @ ErrorCode::InvalidLeaderboardConfig
+    )]
+    pub leaderboard: Option<Box<Account<'info, Leaderboard>>>,
+
     pub system_program: Program<'info, System>,
 }
 
-pub fn process_finalize_round(
-    ctx: Context<FinalizeRound>,
+pub fn process_finalize_round<'info>(
+    ctx: Context<'_, '_, 'info, 'info, FinalizeRound<'info>>,
+    table_id: u64,
     revealed_seed_arg: SeedArray,
     round_id_for_pdas: u64,
 ) -> Result<()> {
     msg!("--- Instruction: process_finalize_round ---");
+    msg!("Table ID: {}", table_id);
     msg!("Round ID for PDAs: {}", round_id_for_pdas);
     msg!("Revealed seed (arg): {:?}", revealed_seed_arg);
 
@@ -133,93 +501,206 @@ pub fn process_finalize_round(
     // Scope for initial round_state read-only load for checks
     {
         let round_ro = ctx.accounts.round_state.load()?;
-        msg!("Stored seed_commitment: {:?}", round_ro.seed_commitment);
-        msg!("Round Start Time: {}", round_ro.start_time);
-        msg!("Round End Time: {}", round_ro.end_time);
-        msg!(
-            "Round Current Status Discriminant: {}",
-            round_ro.status_discriminant
-        );
-
-        require!(
-            round_ro.status_discriminant == RoundStatus::Active as u8,
-            ErrorCode::RoundNotActive
-        );
-        require!(
-            current_timestamp >= round_ro.end_time,
-            ErrorCode::RoundNotEnded
-        );
-        require!(round_ro.player_count > 0, ErrorCode::NoPlayers);
-        require!(
-            revealed_seed_arg == round_ro.seed_commitment,
-            ErrorCode::InvalidRevealedSeed
-        );
-        // Ensure this round hasn't already been finalized past this stage
-        require!(
-            round_ro.winner_sol_pubkey == Pubkey::default(),
-            ErrorCode::RoundAlreadyActive
-        );
+        validate_round_ready_for_finalize(&round_ro, &revealed_seed_arg, current_timestamp)?;
+        require!(!round_ro.is_fixed_odds(), ErrorCode::InvalidGameMode);
     }
 
     // Load round_state mutably
     let mut round_rw = ctx.accounts.round_state.load_mut()?;
-
-    round_rw.set_revealed_seed(Some(revealed_seed_arg));
-    msg!("Revealed seed set in RoundState.");
-
-    let winner_index = determine_winner(&round_rw, current_timestamp)?;
-    msg!("Winner index determined: {}", winner_index);
-
-    let winner_pubkey = round_rw.players[winner_index as usize].pubkey;
-    let total_pot_value = round_rw.total_sol_pot;
-
-    let house_fee = total_pot_value
-        .checked_mul(ctx.accounts.game_state.house_fee_basis_points as u64)
-        .and_then(|v| v.checked_div(10_000))
-        .ok_or(ErrorCode::GameCalculationError)?;
-    msg!("House fee calculated: {}", house_fee);
-
-    let net_winnings_for_winner = total_pot_value
-        .checked_sub(house_fee)
+    let jackpot_account_info = ctx.accounts.jackpot_pool.to_account_info();
+    let mut jackpot_pool = load_singleton::<JackpotPool>(&jackpot_account_info)?
+        .filter(|jackpot_pool| jackpot_pool.is_initialized);
+    let jackpot_contribution_basis_points = jackpot_pool
+        .as_ref()
+        .map_or(0, |jackpot_pool| jackpot_pool.contribution_basis_points);
+    let settlement = settle_round_winner(
+        &mut round_rw,
+        revealed_seed_arg,
+        ctx.accounts.game_state.house_fee_basis_points,
+        jackpot_contribution_basis_points,
+        ctx.accounts.game_state.active_payout_tiers(),
+        current_timestamp,
+    )?;
+    let house_fee = settlement.house_fee;
+
+    // Rakeback owed at each player's rate stays in the pot until distribute_rakeback pays it out,
+    // so the rest of the fee goes through the referral, staker and fee splits below
+    round_rw.snapshot_rakeback_rates(&ctx.accounts.platform_config.rakeback);
+    let rakeback_reserve = round_rw.total_rakeback_owed()?;
+    round_rw.rakeback_reserved = rakeback_reserve;
+    let distributable_fee = house_fee
+        .checked_sub(rakeback_reserve)
         .ok_or(ErrorCode::GameCalculationError)?;
-    msg!(
-        "Net winnings for winner calculated: {}",
-        net_winnings_for_winner
+    msg!("Rakeback reserved: {}, fee to distribute now: {}", rakeback_reserve, distributable_fee);
+
+    let game_pot_account_info = ctx.accounts.game_pot_sol.to_account_info();
+    let rent_for_pot = Rent::get()?.minimum_balance(game_pot_account_info.data_len());
+    require!(
+        game_pot_account_info
+            .lamports()
+            .saturating_sub(house_fee)
+            .saturating_sub(settlement.jackpot_contribution)
+            >= rent_for_pot,
+        ErrorCode::InsufficientFunds
     );
 
-    round_rw.set_winner_index(Some(winner_index));
-    round_rw.house_sol_fee = house_fee;
-    round_rw.winner_sol_pubkey = winner_pubkey;
-    round_rw.winner_sol_amount = net_winnings_for_winner;
-    round_rw.winner_sol_claimed = 0;
+    // Jackpot: divert this round's contribution, then run the independent draw
+    if let Some(jackpot_pool) = jackpot_pool.as_mut() {
+        let contribution = settlement.jackpot_contribution;
+        if contribution > 0 {
+            **game_pot_account_info.try_borrow_mut_lamports()? -= contribution;
+            **jackpot_account_info.try_borrow_mut_lamports()? += contribution;
+            jackpot_pool.balance = jackpot_pool
+                .balance
+                .checked_add(contribution)
+                .ok_or(ErrorCode::GameCalculationError)?;
+            jackpot_pool.total_contributed = jackpot_pool
+                .total_contributed
+                .checked_add(contribution)
+                .ok_or(ErrorCode::GameCalculationError)?;
+            msg!(
+                "Transferred {} SOL jackpot contribution. Jackpot balance now: {}",
+                contribution,
+                jackpot_pool.balance
+            );
+            emit!(JackpotContributed {
+                table_id,
+                round_id: round_rw.id,
+                amount: contribution,
+                jackpot_balance: jackpot_pool.balance,
+            });
+        }
 
-    round_rw.set_status(RoundStatus::AwaitingSolClaim);
-    msg!(
-        "RoundState updated: Winner Pk: {}, Winner Sol Amount: {}, SOL Claimed: {}, Status: AwaitingSolClaim",
-        round_rw.winner_sol_pubkey, round_rw.winner_sol_amount, round_rw.winner_sol_claimed
-    );
+        if jackpot_pool.balance > 0 {
+            match draw_jackpot(&round_rw, jackpot_pool.odds, current_timestamp)? {
+                Some(jackpot_winner_index) => {
+                    let jackpot_amount = jackpot_pool.balance;
+                    let jackpot_winner = round_rw.players[jackpot_winner_index as usize].pubkey;
+
+                    // The jackpot joins the round pot and is claimed from there with claim_jackpot
+                    **jackpot_account_info.try_borrow_mut_lamports()? -= jackpot_amount;
+                    **game_pot_account_info.try_borrow_mut_lamports()? += jackpot_amount;
+                    jackpot_pool.balance = 0;
+                    jackpot_pool.total_paid_out = jackpot_pool
+                        .total_paid_out
+                        .checked_add(jackpot_amount)
+                        .ok_or(ErrorCode::GameCalculationError)?;
+                    jackpot_pool.last_hit_table_id = table_id;
+                    jackpot_pool.last_hit_round_id = round_rw.id;
+                    jackpot_pool.last_winner = jackpot_winner;
+
+                    round_rw.jackpot_winner = jackpot_winner;
+                    round_rw.jackpot_amount = jackpot_amount;
+                    round_rw.jackpot_claimed = 0;
+                    msg!("Jackpot hit! {} lamports to {}.", jackpot_amount, jackpot_winner);
+                    emit!(JackpotHit {
+                        table_id,
+                        round_id: round_rw.id,
+                        winner: jackpot_winner,
+                        amount: jackpot_amount,
+                    });
+                }
+                None => msg!("Jackpot not hit this round. Balance: {}", jackpot_pool.balance),
+            }
+        }
+        store_singleton(&jackpot_account_info, jackpot_pool)?;
+    }
 
-    // Transfer house fee to house_wallet
-    if house_fee > 0 {
-        let game_pot_account_info = ctx.accounts.game_pot_sol.to_account_info();
+    let history_entry = RoundHistoryEntry {
+        round_id: round_rw.id,
+        winner: round_rw.winner_sol_pubkey,
+        total_pot: round_rw.total_sol_pot,
+        house_fee,
+        revealed_seed_hash: hash(&revealed_seed_arg).to_bytes(),
+        finalized_at: current_timestamp,
+        player_count: round_rw.player_count,
+        _padding_rhe: [0; 7],
+    };
+    if let Some(len) = record_round_history(&ctx.accounts.round_history, history_entry)? {
+        msg!("Round {} recorded in history ({} entries).", round_rw.id, len);
+    }
+
+    let players = round_rw.players;
+    let player_count = round_rw.player_count as usize;
+    let total_pot = round_rw.total_sol_pot;
+    let winners = round_rw.winners;
+    let winner_count = round_rw.winner_count as usize;
+    drop(round_rw);
+
+    let (fee_recipient_accounts, player_stats_accounts, referral_accounts) = split_remaining_accounts(
+        ctx.remaining_accounts,
+        ctx.accounts.platform_config.fee_recipient_count as usize,
+        player_count,
+    )?;
+
+    record_winner_stats(
+        &players[..player_count],
+        &winners[..winner_count],
+        player_stats_accounts,
+    )?;
+
+    if ctx.accounts.game_state.leaderboards.has_won_leaderboard {
+        let leaderboard = ctx
+            .accounts
+            .leaderboard
+            .as_mut()
+            .ok_or(ErrorCode::MissingLeaderboard)?;
+        if leaderboard.is_active(current_timestamp) {
+            for winner in &winners[..winner_count] {
+                leaderboard.record_score(winner.pubkey, winner.amount)?;
+            }
+            msg!("Leaderboard {} updated with {} winner(s).", leaderboard.leaderboard_id, winner_count);
+        }
+    }
+
+    // Transfer house fee out of the pot: referrers first, then the stakers' share, then the fee split
+    if distributable_fee > 0 {
         let house_wallet_account_info = ctx.accounts.house_wallet.to_account_info();
 
-        let rent_for_pot = Rent::get()?.minimum_balance(game_pot_account_info.data_len());
-        require!(
-            game_pot_account_info
-                .lamports()
-                .checked_sub(house_fee)
-                .unwrap_or(0)
-                >= rent_for_pot,
-            ErrorCode::InsufficientFunds
-        );
+        let referral_total = accrue_referral_shares(
+            &players[..player_count],
+            total_pot,
+            distributable_fee,
+            ctx.accounts.platform_config.referral_share_basis_points,
+            &game_pot_account_info,
+            referral_accounts,
+        )?;
+        let fee_after_referrals = distributable_fee
+            .checked_sub(referral_total)
+            .ok_or(ErrorCode::GameCalculationError)?;
 
-        **game_pot_account_info.try_borrow_mut_lamports()? -= house_fee;
-        **house_wallet_account_info.try_borrow_mut_lamports()? += house_fee;
-        msg!(
-            "Transferred {} SOL fee from GamePotSol to HouseWallet.",
-            house_fee
-        );
+        let mut staker_share: u64 = 0;
+        let staking_pool_account_info = ctx.accounts.staking_pool.to_account_info();
+        let staking_pool = load_singleton::<StakingPool>(&staking_pool_account_info)?
+            .filter(|staking_pool| staking_pool.is_initialized);
+        if let Some(mut staking_pool) = staking_pool {
+            staker_share = staking_pool.revenue_share_of(fee_after_referrals)?;
+            if staker_share > 0 {
+                staking_pool.distribute_sol(staker_share)?;
+                **game_pot_account_info.try_borrow_mut_lamports()? -= staker_share;
+                **staking_pool_account_info.try_borrow_mut_lamports()? += staker_share;
+                store_singleton(&staking_pool_account_info, &staking_pool)?;
+                msg!(
+                    "Transferred {} SOL fee share from GamePotSol to StakingPool. acc_sol_per_share now: {}",
+                    staker_share,
+                    staking_pool.acc_sol_per_share
+                );
+            } else {
+                msg!("Staking pool present but nothing is staked or share is zero. No staker share taken.");
+            }
+        }
+
+        // Remaining fee is split across the platform fee recipients (passed as remaining accounts)
+        let house_wallet_share = fee_after_referrals
+            .checked_sub(staker_share)
+            .ok_or(ErrorCode::GameCalculationError)?;
+        distribute_house_fee(
+            &ctx.accounts.platform_config,
+            &game_pot_account_info,
+            &house_wallet_account_info,
+            fee_recipient_accounts,
+            house_wallet_share,
+        )?;
     } else {
         msg!("No house fee to transfer (fee is zero).");
     }
@@ -227,3 +708,199 @@ pub fn process_finalize_round(
     msg!("--- process_finalize_round finished ---");
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bytemuck::Zeroable;
+
+    const SEED: SeedArray = [9; 32];
+
+    fn ended_round(seed_commitment: SeedArray) -> RoundState {
+        let mut round = RoundState::zeroed();
+        round.end_time = 1_000;
+        round.player_count = 1;
+        round.seed_commitment = seed_commitment;
+        round
+    }
+
+    fn history_account(data: Vec<u8>, owner: Pubkey) -> AccountInfo<'static> {
+        AccountInfo::new(
+            Box::leak(Box::new(Pubkey::new_unique())),
+            false,
+            true,
+            Box::leak(Box::new(1)),
+            Box::leak(data.into_boxed_slice()),
+            Box::leak(Box::new(owner)),
+            false,
+            0,
+        )
+    }
+
+    fn initialized_history() -> Vec<u8> {
+        // u64 words keep the zero-copy view aligned, as account data is on chain.
+        let words = (8 + std::mem::size_of::<RoundHistory>()) / 8;
+        let mut data = bytemuck::cast_slice::<u64, u8>(&vec![0u64; words]).to_vec();
+        data[..8].copy_from_slice(&RoundHistory::DISCRIMINATOR);
+        data
+    }
+
+    #[test]
+    fn settled_rounds_are_appended_to_an_initialized_history() {
+        let account = history_account(initialized_history(), crate::ID);
+        let entry = |round_id| RoundHistoryEntry { round_id, ..Default::default() };
+        assert_eq!(record_round_history(&account, entry(1)).unwrap(), Some(1));
+        assert_eq!(record_round_history(&account, entry(2)).unwrap(), Some(2));
+
+        let data = account.try_borrow_data().unwrap();
+        let history: &RoundHistory = bytemuck::from_bytes(&data[8..]);
+        assert_eq!((history.head, history.entries[1].round_id), (2, 2));
+    }
+
+    #[test]
+    fn nothing_is_recorded_before_the_history_exists() {
+        let account = history_account(Vec::new(), Pubkey::default());
+        assert_eq!(record_round_history(&account, RoundHistoryEntry::default()).unwrap(), None);
+    }
+
+    #[test]
+    fn other_program_accounts_are_not_taken_for_the_history() {
+        let mut data = initialized_history();
+        data[0] ^= 1;
+        let account = history_account(data, crate::ID);
+        assert_eq!(
+            record_round_history(&account, RoundHistoryEntry::default()).unwrap_err(),
+            error!(anchor_lang::error::ErrorCode::AccountDiscriminatorMismatch)
+        );
+    }
+
+    fn players(amounts: &[u64]) -> Vec<PlayerData> {
+        amounts
+            .iter()
+            .map(|&amount| PlayerData {
+                pubkey: Pubkey::new_unique(),
+                amount,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn weighted_draw_maps_values_onto_cumulative_bets() {
+        let players = players(&[100, 0, 300]);
+        assert_eq!(select_weighted_player(&players, 400, 0).unwrap(), 0);
+        assert_eq!(select_weighted_player(&players, 400, 99).unwrap(), 0);
+        // Players with nothing left in the draw are skipped.
+        assert_eq!(select_weighted_player(&players, 400, 100).unwrap(), 2);
+        assert_eq!(select_weighted_player(&players, 400, 399).unwrap(), 2);
+        assert_eq!(select_weighted_player(&players, 400, 400).unwrap(), 0);
+    }
+
+    #[test]
+    fn weighted_draw_rejects_an_empty_pot() {
+        assert_eq!(
+            select_weighted_player(&players(&[0, 0]), 0, 42).unwrap_err(),
+            error!(ErrorCode::NoPlayers)
+        );
+    }
+
+    /// A round with one bettor per amount, ready to be settled.
+    fn round_with_bets(amounts: &[u64]) -> RoundState {
+        let mut round = ended_round(hash(&SEED).to_bytes());
+        for (i, &amount) in amounts.iter().enumerate() {
+            round.players[i] = PlayerData { pubkey: Pubkey::new_unique(), amount };
+        }
+        round.player_count = amounts.len() as u8;
+        round.total_sol_pot = amounts.iter().sum();
+        round
+    }
+
+    fn paid_to_winners(round: &RoundState) -> u64 {
+        round.winners[..round.winner_count as usize].iter().map(|w| w.amount).sum()
+    }
+
+    #[test]
+    fn settlement_accounts_for_every_lamport_of_the_pot() {
+        let mut round = round_with_bets(&[333_333, 333_333, 333_334]);
+        let settlement = settle_round_winner(&mut round, SEED, 250, 100, &[5_000, 3_000, 2_000], 1_000).unwrap();
+
+        assert_eq!(settlement.house_fee, 25_000);
+        assert_eq!(settlement.jackpot_contribution, 10_000);
+        assert_eq!(paid_to_winners(&round), 1_000_000 - 25_000 - 10_000);
+        assert_eq!(round.winner_count, 3);
+        assert_eq!(round.winner_sol_amount, round.winners[0].amount);
+        assert_eq!(round.get_status().unwrap(), RoundStatus::AwaitingSolClaim);
+
+        // Each player places at most once.
+        let mut winners: Vec<Pubkey> = round.winners[..3].iter().map(|w| w.pubkey).collect();
+        winners.sort();
+        winners.dedup();
+        assert_eq!(winners.len(), 3);
+    }
+
+    #[test]
+    fn unfilled_places_and_dust_go_to_first_place() {
+        let mut round = round_with_bets(&[500, 501]);
+        settle_round_winner(&mut round, SEED, 0, 0, &[7_000, 2_000, 1_000], 1_000).unwrap();
+
+        assert_eq!(round.winner_count, 2);
+        let second_place = round.winners[1].amount;
+        assert_eq!(second_place, 1_001 * 2_000 / 10_000);
+        assert_eq!(round.winners[0].amount, 1_001 - second_place);
+    }
+
+    #[test]
+    fn jackpot_odds_of_one_always_hit_and_zero_never_do() {
+        let mut round = round_with_bets(&[100, 200, 300]);
+        round.set_revealed_seed(Some(SEED));
+        let index = draw_jackpot(&round, 1, 1_000).unwrap().unwrap();
+        assert!(index < 3);
+        assert_eq!(draw_jackpot(&round, 0, 1_000).unwrap(), None);
+    }
+
+    #[test]
+    fn remaining_accounts_are_split_into_recipients_stats_and_referrals() {
+        // Two fee recipients, three players and their referral pairs.
+        let accounts: Vec<u8> = (0..11).collect();
+        let (recipients, stats, referrals) = split_remaining_accounts(&accounts, 2, 3).unwrap();
+        assert_eq!(recipients, &[0, 1]);
+        assert_eq!(stats, &[2, 3, 4]);
+        assert_eq!(referrals, &[5, 6, 7, 8, 9, 10]);
+    }
+
+    #[test]
+    fn every_player_needs_a_stats_account() {
+        let accounts: Vec<u8> = (0..4).collect();
+        assert_eq!(
+            split_remaining_accounts(&accounts, 2, 3).unwrap_err(),
+            error!(ErrorCode::InvalidPlayerStatsAccount)
+        );
+    }
+
+    #[test]
+    fn reveal_must_hash_to_the_commitment() {
+        let round = ended_round(hash(&SEED).to_bytes());
+        validate_round_ready_for_finalize(&round, &SEED, 1_000).unwrap();
+        assert_eq!(
+            validate_round_ready_for_finalize(&round, &[8; 32], 1_000).unwrap_err(),
+            error!(ErrorCode::InvalidRevealedSeed)
+        );
+    }
+
+    #[test]
+    fn plaintext_seed_commitments_are_rejected() {
+        let round = ended_round(SEED);
+        assert_eq!(
+            validate_round_ready_for_finalize(&round, &SEED, 1_000).unwrap_err(),
+            error!(ErrorCode::InvalidRevealedSeed)
+        );
+    }
+
+    #[test]
+    fn rounds_cannot_be_settled_before_they_end() {
+        let round = ended_round(hash(&SEED).to_bytes());
+        assert_eq!(
+            validate_round_ready_for_finalize(&round, &SEED, 999).unwrap_err(),
+            error!(ErrorCode::RoundNotEnded)
+        );
+    }
+}
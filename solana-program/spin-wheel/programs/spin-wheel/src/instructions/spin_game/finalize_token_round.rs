@@ -0,0 +1,145 @@
+use crate::instructions::finalize_round::{settle_round_winner, validate_round_ready_for_finalize};
+use crate::token_transfer::transfer_checked_with_hook;
+use crate::{ErrorCode, GamePotSol, GameState, RoundState, SeedArray};
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::clock::Clock;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface, TransferChecked},
+};
+
+#[derive(Accounts)]
+#[instruction(table_id: u64, revealed_seed_arg: SeedArray, round_id_for_pdas: u64)]
+pub struct FinalizeTokenRound<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"game_state".as_ref(), &table_id.to_le_bytes()],
+        bump,
+        constraint = game_state.authority == authority.key() @ ErrorCode::UnauthorizedAccess,
+        constraint = game_state.is_initialized @ ErrorCode::UnauthorizedAccess,
+        constraint = game_state.is_token_table() @ ErrorCode::InvalidTableDenomination
+    )]
+    pub game_state: Box<Account<'info, GameState>>,
+
+    #[account(
+        mut,
+        seeds = [b"round_state".as_ref(), &table_id.to_le_bytes(), &round_id_for_pdas.to_le_bytes()],
+        bump
+    )]
+    pub round_state: AccountLoader<'info, RoundState>,
+
+    #[account(
+        seeds = [b"sol_pot".as_ref(), &table_id.to_le_bytes(), &round_id_for_pdas.to_le_bytes()],
+        bump
+    )]
+    pub game_pot: Box<Account<'info, GamePotSol>>,
+
+    #[account(address = game_state.bet_mint @ ErrorCode::InvalidMintAccount)]
+    pub bet_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = bet_mint,
+        associated_token::authority = game_pot,
+        associated_token::token_program = token_program
+    )]
+    pub round_token_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: Owner of the fee token account. Its address is validated against game_state.house_wallet.
+    #[account(address = game_state.house_wallet @ ErrorCode::InvalidHouseWalletAddress)]
+    pub house_wallet: AccountInfo<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        associated_token::mint = bet_mint,
+        associated_token::authority = house_wallet,
+        associated_token::token_program = token_program
+    )]
+    pub house_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn process_finalize_token_round<'info>(
+    ctx: Context<'_, '_, 'info, 'info, FinalizeTokenRound<'info>>,
+    table_id: u64,
+    revealed_seed_arg: SeedArray,
+    round_id_for_pdas: u64,
+) -> Result<()> {
+    msg!("--- Instruction: process_finalize_token_round ---");
+    msg!("Table ID: {}", table_id);
+    msg!("Round ID for PDAs: {}", round_id_for_pdas);
+    msg!("Revealed seed (arg): {:?}", revealed_seed_arg);
+    msg!("Bet Mint: {}", ctx.accounts.bet_mint.key());
+
+    let clock = Clock::get()?;
+    let current_timestamp = clock.unix_timestamp;
+    msg!("Current on-chain time: {}", current_timestamp);
+
+    {
+        let round_ro = ctx.accounts.round_state.load()?;
+        validate_round_ready_for_finalize(&round_ro, &revealed_seed_arg, current_timestamp)?;
+    }
+
+    // The jackpot is SOL-only, so token rounds contribute nothing to it.
+    let house_fee = {
+        let mut round_rw = ctx.accounts.round_state.load_mut()?;
+        settle_round_winner(
+            &mut round_rw,
+            revealed_seed_arg,
+            ctx.accounts.game_state.house_fee_basis_points,
+            0,
+            ctx.accounts.game_state.active_payout_tiers(),
+            current_timestamp,
+        )?
+        .house_fee
+    };
+
+    // House fee is paid in the bet token, from the round vault to the house wallet's token account.
+    if house_fee > 0 {
+        require!(
+            ctx.accounts.round_token_vault.amount >= house_fee,
+            ErrorCode::InsufficientFunds
+        );
+
+        let game_pot_bump = ctx.bumps.game_pot;
+        let pot_signer_seeds: &[&[u8]] = &[
+            b"sol_pot".as_ref(),
+            &table_id.to_le_bytes(),
+            &round_id_for_pdas.to_le_bytes(),
+            &[game_pot_bump],
+        ];
+        let all_pot_signer_seeds = &[pot_signer_seeds][..];
+
+        transfer_checked_with_hook(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.round_token_vault.to_account_info(),
+                    to: ctx.accounts.house_token_account.to_account_info(),
+                    authority: ctx.accounts.game_pot.to_account_info(),
+                    mint: ctx.accounts.bet_mint.to_account_info(),
+                },
+                all_pot_signer_seeds,
+            )
+            .with_remaining_accounts(ctx.remaining_accounts.to_vec()),
+            house_fee,
+            ctx.accounts.bet_mint.decimals,
+        )?;
+        msg!(
+            "Transferred {} token fee from round vault to house token account {}.",
+            house_fee,
+            ctx.accounts.house_token_account.key()
+        );
+    } else {
+        msg!("No house fee to transfer (fee is zero).");
+    }
+
+    msg!("--- process_finalize_token_round finished ---");
+    Ok(())
+}
@@ -0,0 +1,97 @@
+use crate::{ErrorCode, Leaderboard, PlatformConfig};
+use crate::token_transfer::transfer_checked_with_hook;
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{spl_token_2022, Mint, Token2022, TokenAccount, TransferChecked};
+
+#[derive(Accounts)]
+#[instruction(leaderboard_id: u64)]
+pub struct FundLeaderboardPrizes<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"platform_config"],
+        bump,
+        constraint = platform_config.authority == authority.key() @ ErrorCode::UnauthorizedAccess
+    )]
+    pub platform_config: Box<Account<'info, PlatformConfig>>,
+
+    #[account(
+        mut,
+        seeds = [b"leaderboard".as_ref(), &leaderboard_id.to_le_bytes()],
+        bump = leaderboard.bump
+    )]
+    pub leaderboard: Box<Account<'info, Leaderboard>>,
+
+    #[account(address = platform_config.cashino_mint @ ErrorCode::InvalidMintAccount)]
+    pub cashino_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        token::mint = cashino_mint,
+        token::authority = authority,
+        token::token_program = token_program
+    )]
+    pub authority_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        associated_token::mint = cashino_mint,
+        associated_token::authority = leaderboard,
+        associated_token::token_program = token_program
+    )]
+    pub prize_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(address = spl_token_2022::ID @ ErrorCode::InvalidTokenProgram)]
+    pub token_program: Program<'info, Token2022>,
+}
+
+pub fn process_fund_leaderboard_prizes<'info>(
+    ctx: Context<'_, '_, 'info, 'info, FundLeaderboardPrizes<'info>>,
+    leaderboard_id: u64,
+    amount: u64,
+) -> Result<()> {
+    msg!("--- Instruction: FundLeaderboardPrizes ---");
+    msg!("Leaderboard ID: {}, amount: {}", leaderboard_id, amount);
+
+    require!(amount > 0, ErrorCode::InvalidDepositAmount);
+    // Prizes are priced off the final pool, so it cannot change once the epoch is over.
+    let clock = Clock::get()?;
+    require!(
+        clock.unix_timestamp < ctx.accounts.leaderboard.end_time,
+        ErrorCode::LeaderboardEpochNotActive
+    );
+
+    let vault_before = ctx.accounts.prize_vault.amount;
+    transfer_checked_with_hook(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.authority_token_account.to_account_info(),
+                mint: ctx.accounts.cashino_mint.to_account_info(),
+                to: ctx.accounts.prize_vault.to_account_info(),
+                authority: ctx.accounts.authority.to_account_info(),
+            },
+        )
+        .with_remaining_accounts(ctx.remaining_accounts.to_vec()),
+        amount,
+        ctx.accounts.cashino_mint.decimals,
+    )?;
+
+    // Count what actually arrived, after any transfer fee.
+    ctx.accounts.prize_vault.reload()?;
+    let received = ctx
+        .accounts
+        .prize_vault
+        .amount
+        .checked_sub(vault_before)
+        .ok_or(ErrorCode::CalculationError)?;
+    let leaderboard = &mut ctx.accounts.leaderboard;
+    leaderboard.prize_pool = leaderboard
+        .prize_pool
+        .checked_add(received)
+        .ok_or(ErrorCode::CalculationError)?;
+
+    msg!("Prize vault received {}. Prize pool now: {}", received, leaderboard.prize_pool);
+    msg!("--- FundLeaderboardPrizes finished ---");
+    Ok(())
+}
@@ -0,0 +1,80 @@
+use crate::{
+    ErrorCode, HouseBankroll, PlatformConfig, HOUSE_LP_MINT_DECIMALS, MAX_LP_WITHDRAWAL_DELAY,
+    MINT_AUTHORITY_SEED, MIN_LP_WITHDRAWAL_DELAY,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{spl_token_2022, Mint, Token2022};
+
+#[derive(Accounts)]
+pub struct InitializeHouseBankroll<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"platform_config"],
+        bump,
+        constraint = platform_config.authority == authority.key() @ ErrorCode::UnauthorizedAccess,
+        constraint = platform_config.is_initialized @ ErrorCode::UnauthorizedAccess
+    )]
+    pub platform_config: Box<Account<'info, PlatformConfig>>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + std::mem::size_of::<HouseBankroll>(),
+        seeds = [b"house_bankroll"],
+        bump
+    )]
+    pub house_bankroll: Box<Account<'info, HouseBankroll>>,
+
+    /// CHECK: The PDA that mints and controls the LP share supply.
+    #[account(seeds = [MINT_AUTHORITY_SEED], bump)]
+    pub mint_authority_pda: AccountInfo<'info>,
+
+    #[account(
+        init,
+        payer = authority,
+        seeds = [b"house_lp_mint"],
+        bump,
+        mint::decimals = HOUSE_LP_MINT_DECIMALS,
+        mint::authority = mint_authority_pda,
+        mint::token_program = token_program
+    )]
+    pub lp_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(address = spl_token_2022::ID @ ErrorCode::InvalidTokenProgram)]
+    pub token_program: Program<'info, Token2022>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn process_initialize_house_bankroll(
+    ctx: Context<InitializeHouseBankroll>,
+    withdrawal_delay: i64,
+) -> Result<()> {
+    msg!("--- Instruction: InitializeHouseBankroll ---");
+    msg!("Authority: {}", ctx.accounts.authority.key());
+    msg!("HouseBankroll PDA: {}", ctx.accounts.house_bankroll.key());
+    msg!("LP Mint: {}", ctx.accounts.lp_mint.key());
+    msg!("Withdrawal delay (seconds): {}", withdrawal_delay);
+
+    require!(
+        (MIN_LP_WITHDRAWAL_DELAY..=MAX_LP_WITHDRAWAL_DELAY).contains(&withdrawal_delay),
+        ErrorCode::InvalidLpWithdrawalDelay
+    );
+
+    let house_bankroll = &mut ctx.accounts.house_bankroll;
+    house_bankroll.authority = ctx.accounts.authority.key();
+    house_bankroll.balance = 0;
+    house_bankroll.reserved_exposure = 0;
+    house_bankroll.pending_deposits = 0;
+    house_bankroll.total_wagered = 0;
+    house_bankroll.total_paid_out = 0;
+    house_bankroll.lp_mint = ctx.accounts.lp_mint.key();
+    house_bankroll.withdrawal_delay = withdrawal_delay;
+    house_bankroll.bump = ctx.bumps.house_bankroll;
+    house_bankroll.is_initialized = true;
+
+    msg!("House bankroll initialized. Liquidity providers fund it with deposit_house_liquidity.");
+    msg!("--- InitializeHouseBankroll finished ---");
+    Ok(())
+}
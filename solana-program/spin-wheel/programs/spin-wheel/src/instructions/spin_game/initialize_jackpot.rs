@@ -0,0 +1,64 @@
+use crate::{ErrorCode, JackpotPool, PlatformConfig, MAX_JACKPOT_CONTRIBUTION_BASIS_POINTS};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct InitializeJackpot<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"platform_config"],
+        bump,
+        constraint = platform_config.authority == authority.key() @ ErrorCode::UnauthorizedAccess,
+        constraint = platform_config.is_initialized @ ErrorCode::UnauthorizedAccess
+    )]
+    pub platform_config: Box<Account<'info, PlatformConfig>>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + std::mem::size_of::<JackpotPool>(),
+        seeds = [b"jackpot"],
+        bump
+    )]
+    pub jackpot_pool: Box<Account<'info, JackpotPool>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn validate_jackpot_config(contribution_basis_points: u16, odds: u64) -> Result<()> {
+    require!(
+        contribution_basis_points <= MAX_JACKPOT_CONTRIBUTION_BASIS_POINTS,
+        ErrorCode::InvalidJackpotConfig
+    );
+    require!(odds > 0, ErrorCode::InvalidJackpotConfig);
+    Ok(())
+}
+
+pub fn process_initialize_jackpot(
+    ctx: Context<InitializeJackpot>,
+    contribution_basis_points: u16,
+    odds: u64,
+) -> Result<()> {
+    msg!("--- Instruction: InitializeJackpot ---");
+    msg!("Authority: {}", ctx.accounts.authority.key());
+    msg!("JackpotPool PDA: {}", ctx.accounts.jackpot_pool.key());
+    msg!("Contribution (bp of total pot): {}", contribution_basis_points);
+    msg!("Odds: 1 in {}", odds);
+
+    validate_jackpot_config(contribution_basis_points, odds)?;
+
+    let jackpot_pool = &mut ctx.accounts.jackpot_pool;
+    jackpot_pool.authority = ctx.accounts.authority.key();
+    jackpot_pool.balance = 0;
+    jackpot_pool.contribution_basis_points = contribution_basis_points;
+    jackpot_pool.odds = odds;
+    jackpot_pool.total_contributed = 0;
+    jackpot_pool.total_paid_out = 0;
+    jackpot_pool.bump = ctx.bumps.jackpot_pool;
+    jackpot_pool.is_initialized = true;
+
+    msg!("Jackpot initialized.");
+    msg!("--- InitializeJackpot finished ---");
+    Ok(())
+}
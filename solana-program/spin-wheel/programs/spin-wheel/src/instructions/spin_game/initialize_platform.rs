@@ -0,0 +1,65 @@
+use crate::{PlatformConfig, RakebackSchedule, WithdrawalFeeSchedule};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, Token2022};
+
+#[derive(Accounts)]
+pub struct InitializePlatform<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + std::mem::size_of::<PlatformConfig>(),
+        seeds = [b"platform_config"],
+        bump
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    /// CHECK: This is the account that will receive the house fees $SOL from the games.
+    pub house_wallet: AccountInfo<'info>,
+
+    pub cashino_token_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(address = anchor_spl::token_interface::spl_token_2022::ID)]
+    pub token_2022_program: Program<'info, Token2022>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn process_initialize_platform(ctx: Context<InitializePlatform>) -> Result<()> {
+    msg!("--- Instruction: InitializePlatform ---");
+    msg!("Authority: {}", ctx.accounts.authority.key());
+    msg!(
+        "New PlatformConfig PDA to be initialized at: {}",
+        ctx.accounts.platform_config.key()
+    );
+    msg!(
+        "House Wallet to be set: {}",
+        ctx.accounts.house_wallet.key()
+    );
+    msg!("$CASHINO Mint Address to set: {}", ctx.accounts.cashino_token_mint.key());
+    msg!("Token-2022 Program ID being used for validation: {}", ctx.accounts.token_2022_program.key());
+
+    let platform_config = &mut ctx.accounts.platform_config;
+
+    platform_config.authority = ctx.accounts.authority.key();
+    platform_config.house_wallet = ctx.accounts.house_wallet.key();
+    platform_config.cashino_mint = ctx.accounts.cashino_token_mint.key();
+    platform_config.table_count = 0; // Tables are created afterwards with create_table
+    platform_config.fee_recipient_count = 0; // House fees go to house_wallet until set_fee_split is called
+    platform_config.referral_share_basis_points = 0; // Referrals earn nothing until set_referral_share is called
+    platform_config.risk_admin = ctx.accounts.authority.key();
+    platform_config.withdrawal_review_threshold = 0; // All withdrawals are instant until set_withdrawal_review_config is called
+    platform_config.withdrawal_review_delay = 0;
+    platform_config.withdrawal_fee = WithdrawalFeeSchedule::default_schedule(); // Flat minimum fee until set_withdrawal_fee_schedule is called
+    platform_config.rakeback = RakebackSchedule::default(); // No rakeback until set_rakeback_schedule is called
+    platform_config.is_initialized = true;
+
+    msg!("Platform initialized successfully in PlatformConfig PDA.");
+    msg!("  Authority set to: {}", platform_config.authority);
+    msg!("  House Wallet set to: {}", platform_config.house_wallet);
+    msg!("  $CASHINO Mint set to: {}", platform_config.cashino_mint);
+    msg!("  Table Count initialized to: {}", platform_config.table_count);
+    msg!("--- InitializePlatform finished ---");
+    Ok(())
+}
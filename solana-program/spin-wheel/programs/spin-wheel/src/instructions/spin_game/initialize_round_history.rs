@@ -0,0 +1,47 @@
+use crate::{ErrorCode, GameState, RoundHistory};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(table_id: u64)]
+pub struct InitializeRoundHistory<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"game_state".as_ref(), &table_id.to_le_bytes()],
+        bump,
+        constraint = game_state.authority == authority.key() @ ErrorCode::UnauthorizedAccess,
+        constraint = game_state.is_initialized @ ErrorCode::UnauthorizedAccess
+    )]
+    pub game_state: Box<Account<'info, GameState>>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + std::mem::size_of::<RoundHistory>(),
+        seeds = [b"round_history".as_ref(), &table_id.to_le_bytes()],
+        bump
+    )]
+    pub round_history: AccountLoader<'info, RoundHistory>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn process_initialize_round_history(
+    ctx: Context<InitializeRoundHistory>,
+    table_id: u64,
+) -> Result<()> {
+    msg!("--- Instruction: InitializeRoundHistory ---");
+    msg!("Authority: {}", ctx.accounts.authority.key());
+    msg!("Table ID: {}", table_id);
+    msg!("RoundHistory PDA: {}", ctx.accounts.round_history.key());
+
+    let round_history = &mut ctx.accounts.round_history.load_init()?;
+    round_history.table_id = table_id;
+    round_history.head = 0;
+    round_history.len = 0;
+
+    msg!("Round history initialized for table {}.", table_id);
+    msg!("--- InitializeRoundHistory finished ---");
+    Ok(())
+}
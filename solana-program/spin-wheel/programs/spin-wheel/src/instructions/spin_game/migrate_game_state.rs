@@ -0,0 +1,51 @@
+use crate::migrations::{rewrite_account, upgrade_game_state, GAME_STATE_SPACE};
+use crate::{ErrorCode, PlatformConfig};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(table_id: u64)]
+pub struct MigrateGameState<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"platform_config"],
+        bump,
+        constraint = platform_config.authority == authority.key() @ ErrorCode::UnauthorizedAccess,
+        constraint = platform_config.is_initialized @ ErrorCode::UnauthorizedAccess
+    )]
+    pub platform_config: Box<Account<'info, PlatformConfig>>,
+
+    /// CHECK: Loaded by hand because older layouts do not deserialize as the current GameState.
+    #[account(
+        mut,
+        seeds = [b"game_state".as_ref(), &table_id.to_le_bytes()],
+        bump,
+        owner = crate::ID @ ErrorCode::InvalidGameState
+    )]
+    pub game_state: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn process_migrate_game_state(ctx: Context<MigrateGameState>, table_id: u64) -> Result<()> {
+    msg!("--- Instruction: MigrateGameState ---");
+    msg!("Authority: {}", ctx.accounts.authority.key());
+    msg!("Table ID: {}", table_id);
+    msg!("GameState PDA: {}, current size: {}", ctx.accounts.game_state.key(), ctx.accounts.game_state.data_len());
+
+    let game_state_info = ctx.accounts.game_state.to_account_info();
+    let migrated = upgrade_game_state(&game_state_info.try_borrow_data()?, table_id)?;
+
+    rewrite_account(
+        &game_state_info,
+        &ctx.accounts.authority.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        GAME_STATE_SPACE,
+        &migrated,
+    )?;
+
+    msg!("GameState migrated to version {}, new size: {}", migrated.version, GAME_STATE_SPACE);
+    msg!("--- MigrateGameState finished ---");
+    Ok(())
+}
@@ -0,0 +1,47 @@
+use crate::migrations::{rewrite_account, upgrade_user_platform_escrow, USER_PLATFORM_ESCROW_SPACE};
+use crate::ErrorCode;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct MigrateUserEscrow<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    /// CHECK: Loaded by hand because older layouts do not deserialize as the current UserPlatformEscrow.
+    #[account(
+        mut,
+        seeds = [b"user_escrow", user.key().as_ref()],
+        bump,
+        owner = crate::ID @ ErrorCode::UnauthorizedEscrowAccess
+    )]
+    pub user_platform_escrow: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn process_migrate_user_escrow(ctx: Context<MigrateUserEscrow>) -> Result<()> {
+    msg!("--- Instruction: MigrateUserEscrow ---");
+    msg!("User: {}", ctx.accounts.user.key());
+    msg!("Escrow PDA: {}, current size: {}", ctx.accounts.user_platform_escrow.key(), ctx.accounts.user_platform_escrow.data_len());
+
+    let escrow_info = ctx.accounts.user_platform_escrow.to_account_info();
+    let migrated = upgrade_user_platform_escrow(&escrow_info.try_borrow_data()?)?;
+    require_keys_eq!(
+        migrated.user_authority,
+        ctx.accounts.user.key(),
+        ErrorCode::UnauthorizedEscrowAccess
+    );
+
+    // The user pays the extra rent, so the escrow's balance stays fully backed.
+    rewrite_account(
+        &escrow_info,
+        &ctx.accounts.user.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        USER_PLATFORM_ESCROW_SPACE,
+        &migrated,
+    )?;
+
+    msg!("Escrow migrated to version {}, new size: {}", migrated.version, USER_PLATFORM_ESCROW_SPACE);
+    msg!("--- MigrateUserEscrow finished ---");
+    Ok(())
+}
@@ -0,0 +1,86 @@
+use crate::instructions::mint_tokens::internal_perform_mint;
+use crate::{ErrorCode, Leaderboard, PlatformConfig, CASHINO_MAX_SUPPLY_UNITS, MINT_AUTHORITY_SEED};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{spl_token_2022, Mint, Token2022, TokenAccount};
+
+#[derive(Accounts)]
+#[instruction(leaderboard_id: u64)]
+pub struct MintLeaderboardPrizes<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"platform_config"],
+        bump,
+        constraint = platform_config.authority == authority.key() @ ErrorCode::UnauthorizedAccess
+    )]
+    pub platform_config: Box<Account<'info, PlatformConfig>>,
+
+    #[account(
+        mut,
+        seeds = [b"leaderboard".as_ref(), &leaderboard_id.to_le_bytes()],
+        bump = leaderboard.bump
+    )]
+    pub leaderboard: Box<Account<'info, Leaderboard>>,
+
+    #[account(
+        mut,
+        address = platform_config.cashino_mint @ ErrorCode::InvalidMintAccount
+    )]
+    pub cashino_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = cashino_mint,
+        associated_token::authority = leaderboard,
+        associated_token::token_program = token_program
+    )]
+    pub prize_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: The PDA that signs the CASHINO mint. Verified again inside internal_perform_mint.
+    #[account(seeds = [MINT_AUTHORITY_SEED], bump)]
+    pub mint_authority_pda: AccountInfo<'info>,
+
+    #[account(address = spl_token_2022::ID @ ErrorCode::InvalidTokenProgram)]
+    pub token_program: Program<'info, Token2022>,
+}
+
+pub fn process_mint_leaderboard_prizes(
+    ctx: Context<MintLeaderboardPrizes>,
+    leaderboard_id: u64,
+    amount: u64,
+) -> Result<()> {
+    msg!("--- Instruction: MintLeaderboardPrizes ---");
+    msg!("Leaderboard ID: {}, amount: {}", leaderboard_id, amount);
+
+    require!(amount > 0, ErrorCode::InvalidDepositAmount);
+    let clock = Clock::get()?;
+    require!(
+        clock.unix_timestamp < ctx.accounts.leaderboard.end_time,
+        ErrorCode::LeaderboardEpochNotActive
+    );
+
+    internal_perform_mint(
+        &ctx.accounts.mint_authority_pda,
+        &ctx.accounts.cashino_mint,
+        &ctx.accounts.prize_vault,
+        &ctx.accounts.token_program,
+        ctx.bumps.mint_authority_pda,
+        amount,
+        Some((
+            CASHINO_MAX_SUPPLY_UNITS,
+            &mut ctx.accounts.platform_config.cashino_minted,
+        )),
+        ctx.program_id,
+    )?;
+
+    let leaderboard = &mut ctx.accounts.leaderboard;
+    leaderboard.prize_pool = leaderboard
+        .prize_pool
+        .checked_add(amount)
+        .ok_or(ErrorCode::CalculationError)?;
+
+    msg!("Minted {} CASHINO into the prize vault. Prize pool now: {}", amount, leaderboard.prize_pool);
+    msg!("--- MintLeaderboardPrizes finished ---");
+    Ok(())
+}
@@ -1,19 +1,20 @@
 use crate::instructions::mint_tokens::internal_perform_mint;
 use crate::{
-    ErrorCode, GameState, RoundCashinoRewardsPot, RoundState, RoundStatus,
-    CASHINO_REWARD_PER_ROUND_UNITS, MINT_AUTHORITY_SEED,
+    EmissionMinted, ErrorCode, GameState, PlatformConfig, RoundCashinoRewardsPot, RoundState,
+    RoundStatus, MINT_AUTHORITY_SEED,
 };
 use anchor_lang::prelude::*;
 use anchor_spl::token_interface::{spl_token_2022, Mint, Token2022, TokenAccount};
 
 #[derive(Accounts)]
-#[instruction(round_id_for_pdas: u64)]
+#[instruction(table_id: u64, round_id_for_pdas: u64)]
 pub struct MintTokensToRewardPot<'info> {
     #[account(mut)]
     pub authority: Signer<'info>,
 
     #[account(
-        seeds = [b"game_state"],
+        mut,
+        seeds = [b"game_state".as_ref(), &table_id.to_le_bytes()],
         bump,
         constraint = game_state.authority == authority.key() @ ErrorCode::UnauthorizedAccess,
     )]
@@ -21,7 +22,14 @@ pub struct MintTokensToRewardPot<'info> {
 
     #[account(
         mut,
-        seeds = [b"round_state".as_ref(), &round_id_for_pdas.to_le_bytes()],
+        seeds = [b"platform_config"],
+        bump
+    )]
+    pub platform_config: Box<Account<'info, PlatformConfig>>,
+
+    #[account(
+        mut,
+        seeds = [b"round_state".as_ref(), &table_id.to_le_bytes(), &round_id_for_pdas.to_le_bytes()],
         bump,
         constraint = round_state.load()?.status_discriminant == RoundStatus::RewardPotAccountsCreated as u8 @ ErrorCode::RoundNotInCorrectState,
     )]
@@ -39,7 +47,7 @@ pub struct MintTokensToRewardPot<'info> {
 
     #[account(
         mut,
-        seeds = [b"cashino_round_pot".as_ref(), &round_id_for_pdas.to_le_bytes()],
+        seeds = [b"cashino_round_pot".as_ref(), &table_id.to_le_bytes(), &round_id_for_pdas.to_le_bytes()],
         bump,
     )]
     pub round_cashino_rewards_pot_account: Box<Account<'info, RoundCashinoRewardsPot>>,
@@ -62,13 +70,36 @@ pub struct MintTokensToRewardPot<'info> {
 #[inline(never)]
 pub fn process_mint_tokens_to_reward_pot(
     ctx: Context<MintTokensToRewardPot>,
+    table_id: u64,
     round_id_for_pdas: u64,
 ) -> Result<()> {
     msg!("--- Instruction: MintTokensToRewardPot ---");
     msg!("Authority: {}", ctx.accounts.authority.key());
+    msg!("Target Table ID: {}", table_id);
     msg!("Target Round ID (for PDAs): {}", round_id_for_pdas);
 
-    let total_cashino_to_mint_for_round = CASHINO_REWARD_PER_ROUND_UNITS;
+    let clock = Clock::get()?;
+    let (round_id, round_volume) = {
+        let round_state = ctx.accounts.round_state.load()?;
+        (round_state.id, round_state.total_sol_pot)
+    };
+    let emission = ctx.accounts.game_state.emission;
+    let halvings = emission.halvings_at(round_id, clock.unix_timestamp);
+    let scheduled_emission = emission.emission_for_round(round_id, round_volume, clock.unix_timestamp)?;
+
+    // Near the cap the round gets whatever supply is left, so rounds keep settling once it is reached.
+    let supply_cap = emission.supply_cap();
+    let remaining_supply = supply_cap.saturating_sub(ctx.accounts.platform_config.cashino_minted);
+    let total_cashino_to_mint_for_round = scheduled_emission.min(remaining_supply);
+    msg!(
+        "Emission for round {}: scheduled {} after {} halving(s), volume {}, supply left under cap {}: {}",
+        round_id,
+        scheduled_emission,
+        halvings,
+        round_volume,
+        supply_cap,
+        remaining_supply
+    );
 
     let round_cashino_pot_data = &mut ctx.accounts.round_cashino_rewards_pot_account;
 
@@ -79,23 +110,42 @@ pub fn process_mint_tokens_to_reward_pot(
         total_cashino_to_mint_for_round
     );
 
-    msg!(
-        "Calling internal_perform_mint to mint $CASHINO to ATA: {}",
-        ctx.accounts.round_cashino_rewards_pot_ata.key()
-    );
-    internal_perform_mint(
-        &ctx.accounts.cashino_mint_authority_pda,
-        &ctx.accounts.cashino_token_mint,
-        &ctx.accounts.round_cashino_rewards_pot_ata,
-        &ctx.accounts.token_program,
-        ctx.bumps.cashino_mint_authority_pda,
-        total_cashino_to_mint_for_round,
-        &ctx.accounts.spin_wheel_program.key(),
-    )?;
-    msg!(
-        "Successfully minted {} $CASHINO to the round's reward pot ATA.",
-        total_cashino_to_mint_for_round
-    );
+    if total_cashino_to_mint_for_round > 0 {
+        msg!(
+            "Calling internal_perform_mint to mint $CASHINO to ATA: {}",
+            ctx.accounts.round_cashino_rewards_pot_ata.key()
+        );
+        internal_perform_mint(
+            &ctx.accounts.cashino_mint_authority_pda,
+            &ctx.accounts.cashino_token_mint,
+            &ctx.accounts.round_cashino_rewards_pot_ata,
+            &ctx.accounts.token_program,
+            ctx.bumps.cashino_mint_authority_pda,
+            total_cashino_to_mint_for_round,
+            Some((supply_cap, &mut ctx.accounts.platform_config.cashino_minted)),
+            &ctx.accounts.spin_wheel_program.key(),
+        )?;
+        msg!(
+            "Successfully minted {} $CASHINO to the round's reward pot ATA.",
+            total_cashino_to_mint_for_round
+        );
+    } else {
+        msg!("Emission is zero for this round. Nothing minted.");
+    }
+
+    let game_state = &mut ctx.accounts.game_state;
+    game_state.emission.total_emitted = game_state
+        .emission
+        .total_emitted
+        .checked_add(total_cashino_to_mint_for_round)
+        .ok_or(ErrorCode::CalculationError)?;
+    emit!(EmissionMinted {
+        table_id,
+        round_id,
+        amount: total_cashino_to_mint_for_round,
+        halvings,
+        total_emitted: game_state.emission.total_emitted,
+    });
 
     let round_state = &mut ctx.accounts.round_state.load_mut()?;
     round_state.total_cashino_minted_for_round = total_cashino_to_mint_for_round;
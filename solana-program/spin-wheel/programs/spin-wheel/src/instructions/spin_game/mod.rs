@@ -1,5 +1,11 @@
-pub mod game_initialize;
-pub use game_initialize::*;
+pub mod initialize_platform;
+pub use initialize_platform::*;
+
+pub mod create_table;
+pub use create_table::*;
+
+pub mod retire_table;
+pub use retire_table::*;
 
 pub mod start_new_round;
 pub use start_new_round::*;
@@ -25,8 +31,152 @@ pub use claim_cashino_rewards::*;
 pub mod deposit_sol;
 pub use deposit_sol::*;
 
+pub mod reconcile_escrow;
+pub use reconcile_escrow::*;
+
+pub mod set_gaming_limits;
+pub use set_gaming_limits::*;
+
+pub mod self_exclude;
+pub use self_exclude::*;
+
 pub mod withdraw_sol_from_platform;
 pub use withdraw_sol_from_platform::*;
 
+pub mod set_withdrawal_review_config;
+pub use set_withdrawal_review_config::*;
+
+pub mod set_withdrawal_fee_schedule;
+pub use set_withdrawal_fee_schedule::*;
+
+pub mod request_sol_withdrawal;
+pub use request_sol_withdrawal::*;
+
+pub mod execute_sol_withdrawal;
+pub use execute_sol_withdrawal::*;
+
+pub mod cancel_sol_withdrawal;
+pub use cancel_sol_withdrawal::*;
+
+pub mod block_sol_withdrawal;
+pub use block_sol_withdrawal::*;
+
 pub mod claim_sol_winnings;
 pub use claim_sol_winnings::*;
+
+pub mod deposit_token;
+pub use deposit_token::*;
+
+pub mod withdraw_token_from_platform;
+pub use withdraw_token_from_platform::*;
+
+pub mod create_round_token_vault;
+pub use create_round_token_vault::*;
+
+pub mod place_token_bet;
+pub use place_token_bet::*;
+
+pub mod finalize_token_round;
+pub use finalize_token_round::*;
+
+pub mod claim_token_winnings;
+pub use claim_token_winnings::*;
+
+pub mod set_fee_split;
+pub use set_fee_split::*;
+
+pub mod set_referral_share;
+pub use set_referral_share::*;
+
+pub mod register_referrer;
+pub use register_referrer::*;
+
+pub mod claim_referral_earnings;
+pub use claim_referral_earnings::*;
+
+pub mod initialize_jackpot;
+pub use initialize_jackpot::*;
+
+pub mod update_jackpot_config;
+pub use update_jackpot_config::*;
+
+pub mod claim_jackpot;
+pub use claim_jackpot::*;
+
+pub mod set_table_payout_mode;
+pub use set_table_payout_mode::*;
+
+pub mod initialize_house_bankroll;
+pub use initialize_house_bankroll::*;
+
+pub mod deposit_house_liquidity;
+pub use deposit_house_liquidity::*;
+
+pub mod complete_house_liquidity_deposit;
+pub use complete_house_liquidity_deposit::*;
+
+pub mod request_house_liquidity_withdrawal;
+pub use request_house_liquidity_withdrawal::*;
+
+pub mod complete_house_liquidity_withdrawal;
+pub use complete_house_liquidity_withdrawal::*;
+
+pub mod configure_fixed_odds_wheel;
+pub use configure_fixed_odds_wheel::*;
+
+pub mod place_fixed_odds_bet;
+pub use place_fixed_odds_bet::*;
+
+pub mod reveal_fixed_odds_seed;
+pub use reveal_fixed_odds_seed::*;
+
+pub mod settle_fixed_odds_round;
+pub use settle_fixed_odds_round::*;
+
+pub mod migrate_game_state;
+pub use migrate_game_state::*;
+
+pub mod migrate_user_escrow;
+pub use migrate_user_escrow::*;
+
+pub mod initialize_round_history;
+pub use initialize_round_history::*;
+
+pub mod create_leaderboard;
+pub use create_leaderboard::*;
+
+pub mod fund_leaderboard_prizes;
+pub use fund_leaderboard_prizes::*;
+
+pub mod mint_leaderboard_prizes;
+pub use mint_leaderboard_prizes::*;
+
+pub mod claim_leaderboard_prize;
+pub use claim_leaderboard_prize::*;
+
+pub mod set_rakeback_schedule;
+pub use set_rakeback_schedule::*;
+
+pub mod distribute_rakeback;
+pub use distribute_rakeback::*;
+
+pub mod set_emission_schedule;
+pub use set_emission_schedule::*;
+
+pub mod set_fee_treasury;
+pub use set_fee_treasury::*;
+
+pub mod set_fee_burn;
+pub use set_fee_burn::*;
+
+pub mod set_burn_config;
+pub use set_burn_config::*;
+
+pub mod burn_unclaimed_rewards;
+pub use burn_unclaimed_rewards::*;
+
+pub mod set_table_leaderboards;
+pub use set_table_leaderboards::*;
+
+pub mod unfreeze_escrow_withdrawals;
+pub use unfreeze_escrow_withdrawals::*;
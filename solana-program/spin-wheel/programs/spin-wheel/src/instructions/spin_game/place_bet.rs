@@ -2,21 +2,21 @@ use anchor_lang::prelude::*;
 use anchor_lang::solana_program::{
     clock::Clock,
 };
+use crate::accounting;
 use crate::{
     GameState,
     RoundState,
     GamePotSol,
-    PlayerData,
     ErrorCode,
-    MIN_BET_AMOUNT,
-    MAX_BET_AMOUNT,
-    MAX_PLAYERS,
+    Leaderboard,
+    LeaderboardMetric,
+    PlayerStats,
     RoundStatus,
     UserPlatformEscrow
 };
 
 #[derive(Accounts)]
-#[instruction(round_id_for_pdas: u64, amount: u64)]
+#[instruction(table_id: u64, round_id_for_pdas: u64, amount: u64)]
 pub struct PlaceSolBet<'info> {
     #[account(mut)]
     pub player: Signer<'info>,
@@ -30,35 +30,61 @@ pub struct PlaceSolBet<'info> {
     )]
     pub user_platform_escrow: Account<'info, UserPlatformEscrow>,
 
-    #[account(seeds = [b"game_state"], bump)]
+    #[account(
+        seeds = [b"game_state".as_ref(), &table_id.to_le_bytes()],
+        bump,
+        constraint = !game_state.is_token_table() @ ErrorCode::InvalidTableDenomination
+    )]
     pub game_state: Box<Account<'info, GameState>>,
 
     #[account(
         mut, 
-        seeds = [b"round_state".as_ref(), &round_id_for_pdas.to_le_bytes().as_ref()],
+        seeds = [b"round_state".as_ref(), &table_id.to_le_bytes(), &round_id_for_pdas.to_le_bytes().as_ref()],
         bump,
         constraint = round_state.load()?.status_discriminant == RoundStatus::Active as u8 @ ErrorCode::RoundNotActive,
+        constraint = !round_state.load()?.is_fixed_odds() @ ErrorCode::InvalidGameMode,
     )]
     pub round_state: AccountLoader<'info, RoundState>,
 
     #[account(
         mut,
-        seeds = [b"sol_pot".as_ref(), &round_id_for_pdas.to_le_bytes().as_ref()],
+        seeds = [b"sol_pot".as_ref(), &table_id.to_le_bytes(), &round_id_for_pdas.to_le_bytes().as_ref()],
         bump
     )]
     pub game_pot: Account<'info, GamePotSol>,
 
+    #[account(
+        init_if_needed,
+        payer = player,
+        space = 8 + std::mem::size_of::<PlayerStats>(),
+        seeds = [b"player_stats", player.key().as_ref()],
+        bump
+    )]
+    pub player_stats: Box<Account<'info, PlayerStats>>,
+
+    /// The table's wager leaderboard, derived from `game_state.leaderboards`. Required while
+    /// one is bound.
+    #[account(
+        mut,
+        seeds = [b"leaderboard".as_ref(), &game_state.leaderboards.wagered_leaderboard_id.to_le_bytes()],
+        bump = leaderboard.bump,
+        constraint = leaderboard.metric == LeaderboardMetric::Wagered @ ErrorCode::InvalidLeaderboardConfig
+    )]
+    pub leaderboard: Option<Box<Account<'info, Leaderboard>>>,
+
     pub system_program: Program<'info, System>,
 }
 
 pub fn process_place_sol_bet(
     ctx: Context<PlaceSolBet>,
+    table_id: u64,
     round_id_for_pdas: u64,
     amount: u64
 ) -> Result<()> {
     msg!("--- Instruction: PlaceSolBet ---");
     msg!("Player: {}", ctx.accounts.player.key());
     msg!("User Platform Escrow Account: {}", ctx.accounts.user_platform_escrow.key());
+    msg!("Target Table ID: {}", table_id);
     msg!("Target Round ID (for PDAs): {}", round_id_for_pdas);
     msg!("Bet Amount (SOL lamports): {}", amount);
     msg!("GameState PDA: {}", ctx.accounts.game_state.key());
@@ -67,6 +93,8 @@ pub fn process_place_sol_bet(
     msg!("Current Escrow Balance: {}", ctx.accounts.user_platform_escrow.balance);
 
     let player_key = ctx.accounts.player.key();
+    let min_bet_amount = ctx.accounts.game_state.min_bet_amount;
+    let max_bet_amount = ctx.accounts.game_state.max_bet_amount;
     let round_state = &mut ctx.accounts.round_state.load_mut()?;
     let clock = Clock::get()?;
 
@@ -78,43 +106,40 @@ pub fn process_place_sol_bet(
     msg!("Bet window is open. (Current: {}, End: {}).", clock.unix_timestamp, round_state.end_time);
 
     require!(
-        amount >= MIN_BET_AMOUNT && amount <= MAX_BET_AMOUNT,
+        amount >= min_bet_amount && amount <= max_bet_amount,
         ErrorCode::InvalidBetAmount
     );
 
-    msg!("Bet amount {} validated against min {} and max {}.", amount, MIN_BET_AMOUNT, MAX_BET_AMOUNT);
+    msg!("Bet amount {} validated against table min {} and max {}.", amount, min_bet_amount, max_bet_amount);
 
-    let mut player_found_and_updated = false;
-    let mut player_index_to_update = 0; // hold the index of the player
+    let first_bet_in_round = round_state.stake_of(player_key) == 0;
+    round_state.record_bet(player_key, amount)?;
 
-    for i in 0..(round_state.player_count as usize) {
-        if round_state.players[i].pubkey == player_key {
-            player_index_to_update = i;
-            player_found_and_updated = true;
-            break;
-        }
-    }
+    let player_round_total = round_state.stake_of(player_key);
+    ctx.accounts
+        .user_platform_escrow
+        .record_wager(amount, player_round_total, clock.unix_timestamp)?;
+    msg!("Wager within limits. Wagered today: {}", ctx.accounts.user_platform_escrow.daily_wagered);
 
-    if player_found_and_updated {
-        round_state.players[player_index_to_update].amount = round_state.players[player_index_to_update]
-            .amount
-            .checked_add(amount)
-            .ok_or(ErrorCode::CalculationError)?;
-        msg!("Player {} updated existing bet. New total bet for player: {}", player_key, round_state.players[player_index_to_update].amount);
-    } else {
-        require!(
-            round_state.player_count < MAX_PLAYERS as u8,
-            ErrorCode::MaxPlayersReached
-        );
-        msg!("New player check: Player count {} is less than MAX_PLAYERS {}.", round_state.player_count, MAX_PLAYERS);
-
-        let current_player_count_as_index = round_state.player_count as usize;
-        round_state.players[current_player_count_as_index] = PlayerData {
-            pubkey: player_key,
-            amount,
-        };
-        round_state.player_count += 1;
-        msg!("New player {} added with bet amount {}. Player count now: {}", player_key, amount, round_state.player_count);
+    let player_stats = &mut ctx.accounts.player_stats;
+    if player_stats.player == Pubkey::default() {
+        player_stats.player = player_key;
+        player_stats.bump = ctx.bumps.player_stats;
+    }
+    player_stats.record_wager(amount, first_bet_in_round, clock.unix_timestamp)?;
+    round_state.record_player_volume(player_key, player_stats.total_wagered);
+    msg!("Player stats updated. Total wagered: {}, rounds played: {}", player_stats.total_wagered, player_stats.rounds_played);
+
+    if ctx.accounts.game_state.leaderboards.has_wagered_leaderboard {
+        let leaderboard = ctx
+            .accounts
+            .leaderboard
+            .as_mut()
+            .ok_or(ErrorCode::MissingLeaderboard)?;
+        if leaderboard.is_active(clock.unix_timestamp) {
+            leaderboard.record_score(player_key, amount)?;
+            msg!("Leaderboard {} updated.", leaderboard.leaderboard_id);
+        }
     }
 
     msg!("Preparing to transfer {} SOL from UserPlatformEscrow {} to GamePotSol PDA {}",
@@ -123,22 +148,16 @@ pub fn process_place_sol_bet(
         ctx.accounts.game_pot.key()
     );
     
-    **ctx.accounts.user_platform_escrow.to_account_info().try_borrow_mut_lamports()? -= amount;
-    ctx.accounts.user_platform_escrow.balance = ctx.accounts.user_platform_escrow.balance
-        .checked_sub(amount)
-        .ok_or(ErrorCode::InsufficientPlatformBalance)?;
-    
-    **ctx.accounts.game_pot.to_account_info().try_borrow_mut_lamports()? += amount;
+    accounting::transfer_from_escrow(
+        &mut ctx.accounts.user_platform_escrow,
+        &ctx.accounts.game_pot.to_account_info(),
+        amount,
+    )?;
 
     msg!("SOL transfer successful from UserPlatformEscrow to GamePotSol PDA.");
     msg!("UserPlatformEscrow new data balance: {}", ctx.accounts.user_platform_escrow.balance);
-    
-    round_state.total_sol_pot = round_state.total_sol_pot
-        .checked_add(amount)
-        .ok_or(ErrorCode::CalculationError)?;
-    msg!("RoundState.total_sol_pot (data field) updated to: {}", round_state.total_sol_pot);
 
-    msg!("Player {} successfully placed/updated bet of {} lamports using platform escrow for round {}.", player_key, amount, round_id_for_pdas);
+    msg!("Player {} successfully placed/updated bet of {} lamports using platform escrow for round {} on table {}.", player_key, amount, round_id_for_pdas, table_id);
     msg!("--- PlaceSolBet finished ---");
 
     Ok(())
@@ -0,0 +1,132 @@
+use crate::accounting;
+use crate::{
+    ErrorCode, GamePotSol, GameState, HouseBankroll, RoundState, RoundStatus, UserPlatformEscrow,
+};
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::clock::Clock;
+
+#[derive(Accounts)]
+#[instruction(table_id: u64, round_id_for_pdas: u64, segment: u8, amount: u64)]
+pub struct PlaceFixedOddsBet<'info> {
+    #[account(mut)]
+    pub player: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"user_escrow", player.key().as_ref()],
+        bump = user_platform_escrow.bump,
+        constraint = user_platform_escrow.user_authority == player.key() @ ErrorCode::UnauthorizedEscrowAccess,
+        constraint = user_platform_escrow.balance >= amount @ ErrorCode::InsufficientPlatformBalance
+    )]
+    pub user_platform_escrow: Account<'info, UserPlatformEscrow>,
+
+    #[account(
+        seeds = [b"game_state".as_ref(), &table_id.to_le_bytes()],
+        bump,
+        constraint = !game_state.is_retired @ ErrorCode::TableRetired,
+        constraint = !game_state.is_token_table() @ ErrorCode::InvalidTableDenomination
+    )]
+    pub game_state: Box<Account<'info, GameState>>,
+
+    #[account(
+        mut,
+        seeds = [b"round_state".as_ref(), &table_id.to_le_bytes(), &round_id_for_pdas.to_le_bytes()],
+        bump,
+        constraint = round_state.load()?.status_discriminant == RoundStatus::Active as u8 @ ErrorCode::RoundNotActive,
+        constraint = round_state.load()?.is_fixed_odds() @ ErrorCode::InvalidGameMode
+    )]
+    pub round_state: AccountLoader<'info, RoundState>,
+
+    #[account(
+        mut,
+        seeds = [b"sol_pot".as_ref(), &table_id.to_le_bytes(), &round_id_for_pdas.to_le_bytes()],
+        bump
+    )]
+    pub game_pot: Account<'info, GamePotSol>,
+
+    #[account(
+        mut,
+        seeds = [b"house_bankroll"],
+        bump = house_bankroll.bump
+    )]
+    pub house_bankroll: Box<Account<'info, HouseBankroll>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn process_place_fixed_odds_bet(
+    ctx: Context<PlaceFixedOddsBet>,
+    table_id: u64,
+    round_id_for_pdas: u64,
+    segment: u8,
+    amount: u64,
+) -> Result<()> {
+    msg!("--- Instruction: PlaceFixedOddsBet ---");
+    msg!("Player: {}", ctx.accounts.player.key());
+    msg!("Target Table ID: {}", table_id);
+    msg!("Target Round ID (for PDAs): {}", round_id_for_pdas);
+    msg!("Segment: {}, Bet Amount (SOL lamports): {}", segment, amount);
+    msg!("Current Escrow Balance: {}", ctx.accounts.user_platform_escrow.balance);
+
+    let player_key = ctx.accounts.player.key();
+    let min_bet_amount = ctx.accounts.game_state.min_bet_amount;
+    let max_bet_amount = ctx.accounts.game_state.max_bet_amount;
+    let round_state = &mut ctx.accounts.round_state.load_mut()?;
+    let clock = Clock::get()?;
+
+    require!(
+        clock.unix_timestamp < round_state.end_time,
+        ErrorCode::BetWindowClosed
+    );
+    require!(
+        amount >= min_bet_amount && amount <= max_bet_amount,
+        ErrorCode::InvalidBetAmount
+    );
+    msg!("Bet amount {} validated against table min {} and max {}.", amount, min_bet_amount, max_bet_amount);
+
+    let previous_reserve = round_state.bankroll_reserved;
+    round_state.record_fixed_odds_bet(player_key, amount, segment)?;
+
+    let player_round_total = round_state.stake_of(player_key);
+    ctx.accounts
+        .user_platform_escrow
+        .record_wager(amount, player_round_total, clock.unix_timestamp)?;
+    msg!("Wager within limits. Wagered today: {}", ctx.accounts.user_platform_escrow.daily_wagered);
+    let new_reserve = round_state.fixed_odds_required_reserve()?;
+
+    // Re-reserve the round's worst case on the bankroll; the bet fails if it cannot be covered.
+    let house_bankroll = &mut ctx.accounts.house_bankroll;
+    house_bankroll.reserved_exposure = house_bankroll
+        .reserved_exposure
+        .checked_sub(previous_reserve)
+        .and_then(|v| v.checked_add(new_reserve))
+        .ok_or(ErrorCode::CalculationError)?;
+    require!(
+        house_bankroll.reserved_exposure <= house_bankroll.balance,
+        ErrorCode::InsufficientBankrollLiquidity
+    );
+    house_bankroll.total_wagered = house_bankroll
+        .total_wagered
+        .checked_add(amount)
+        .ok_or(ErrorCode::CalculationError)?;
+    round_state.bankroll_reserved = new_reserve;
+    msg!(
+        "Round reserve {} -> {}. Bankroll balance: {}, reserved: {}",
+        previous_reserve,
+        new_reserve,
+        house_bankroll.balance,
+        house_bankroll.reserved_exposure
+    );
+
+    accounting::transfer_from_escrow(
+        &mut ctx.accounts.user_platform_escrow,
+        &ctx.accounts.game_pot.to_account_info(),
+        amount,
+    )?;
+
+    msg!("SOL transfer successful from UserPlatformEscrow to GamePotSol PDA.");
+    msg!("UserPlatformEscrow new data balance: {}", ctx.accounts.user_platform_escrow.balance);
+    msg!("Player {} placed fixed-odds bet of {} lamports on segment {} for round {} on table {}.", player_key, amount, segment, round_id_for_pdas, table_id);
+    msg!("--- PlaceFixedOddsBet finished ---");
+    Ok(())
+}
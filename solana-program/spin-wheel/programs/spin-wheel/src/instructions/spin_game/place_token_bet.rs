@@ -0,0 +1,161 @@
+use crate::instructions::self_exclude::require_not_self_excluded;
+use crate::token_transfer::transfer_checked_with_hook;
+use crate::{ErrorCode, GamePotSol, GameState, RoundState, RoundStatus, UserTokenEscrow};
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::clock::Clock;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface, TransferChecked};
+
+#[derive(Accounts)]
+#[instruction(table_id: u64, round_id_for_pdas: u64, amount: u64)]
+pub struct PlaceTokenBet<'info> {
+    #[account(mut)]
+    pub player: Signer<'info>,
+
+    #[account(
+        seeds = [b"game_state".as_ref(), &table_id.to_le_bytes()],
+        bump,
+        constraint = game_state.is_token_table() @ ErrorCode::InvalidTableDenomination
+    )]
+    pub game_state: Box<Account<'info, GameState>>,
+
+    #[account(address = game_state.bet_mint @ ErrorCode::InvalidMintAccount)]
+    pub bet_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        seeds = [b"user_token_escrow", bet_mint.key().as_ref(), player.key().as_ref()],
+        bump = user_token_escrow.bump,
+        constraint = user_token_escrow.user_authority == player.key() @ ErrorCode::UnauthorizedEscrowAccess,
+        constraint = user_token_escrow.balance >= amount @ ErrorCode::InsufficientPlatformBalance
+    )]
+    pub user_token_escrow: Box<Account<'info, UserTokenEscrow>>,
+
+    /// CHECK: The player's UserPlatformEscrow PDA, read for its self-exclusion. It may not exist.
+    #[account(seeds = [b"user_escrow", player.key().as_ref()], bump)]
+    pub user_platform_escrow: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        associated_token::mint = bet_mint,
+        associated_token::authority = user_token_escrow,
+        associated_token::token_program = token_program
+    )]
+    pub escrow_token_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [b"round_state".as_ref(), &table_id.to_le_bytes(), &round_id_for_pdas.to_le_bytes()],
+        bump,
+        constraint = round_state.load()?.status_discriminant == RoundStatus::Active as u8 @ ErrorCode::RoundNotActive,
+    )]
+    pub round_state: AccountLoader<'info, RoundState>,
+
+    #[account(
+        seeds = [b"sol_pot".as_ref(), &table_id.to_le_bytes(), &round_id_for_pdas.to_le_bytes()],
+        bump
+    )]
+    pub game_pot: Box<Account<'info, GamePotSol>>,
+
+    #[account(
+        mut,
+        associated_token::mint = bet_mint,
+        associated_token::authority = game_pot,
+        associated_token::token_program = token_program
+    )]
+    pub round_token_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn process_place_token_bet<'info>(
+    ctx: Context<'_, '_, 'info, 'info, PlaceTokenBet<'info>>,
+    table_id: u64,
+    round_id_for_pdas: u64,
+    amount: u64,
+) -> Result<()> {
+    msg!("--- Instruction: PlaceTokenBet ---");
+    msg!("Player: {}", ctx.accounts.player.key());
+    msg!("User Token Escrow Account: {}", ctx.accounts.user_token_escrow.key());
+    msg!("Target Table ID: {}", table_id);
+    msg!("Target Round ID (for PDAs): {}", round_id_for_pdas);
+    msg!("Bet Amount (token base units): {}", amount);
+    msg!("Bet Mint: {}", ctx.accounts.bet_mint.key());
+    msg!("Round Token Vault: {}", ctx.accounts.round_token_vault.key());
+    msg!("Current Escrow Balance: {}", ctx.accounts.user_token_escrow.balance);
+
+    let player_key = ctx.accounts.player.key();
+    let min_bet_amount = ctx.accounts.game_state.min_bet_amount;
+    let max_bet_amount = ctx.accounts.game_state.max_bet_amount;
+    let clock = Clock::get()?;
+
+    require_not_self_excluded(&ctx.accounts.user_platform_escrow, clock.unix_timestamp)?;
+
+    {
+        let round_ro = ctx.accounts.round_state.load()?;
+        require!(
+            clock.unix_timestamp < round_ro.end_time,
+            ErrorCode::BetWindowClosed
+        );
+        msg!("Bet window is open. (Current: {}, End: {}).", clock.unix_timestamp, round_ro.end_time);
+    }
+
+    require!(
+        amount >= min_bet_amount && amount <= max_bet_amount,
+        ErrorCode::InvalidBetAmount
+    );
+    msg!("Bet amount {} validated against table min {} and max {}.", amount, min_bet_amount, max_bet_amount);
+
+    let bet_mint_key = ctx.accounts.bet_mint.key();
+    let escrow_signer_seeds: &[&[u8]] = &[
+        b"user_token_escrow".as_ref(),
+        bet_mint_key.as_ref(),
+        player_key.as_ref(),
+        &[ctx.accounts.user_token_escrow.bump],
+    ];
+    let all_escrow_signer_seeds = &[escrow_signer_seeds][..];
+
+    let vault_balance_before = ctx.accounts.round_token_vault.amount;
+    transfer_checked_with_hook(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.escrow_token_vault.to_account_info(),
+                to: ctx.accounts.round_token_vault.to_account_info(),
+                authority: ctx.accounts.user_token_escrow.to_account_info(),
+                mint: ctx.accounts.bet_mint.to_account_info(),
+            },
+            all_escrow_signer_seeds,
+        )
+        .with_remaining_accounts(ctx.remaining_accounts.to_vec()),
+        amount,
+        ctx.accounts.bet_mint.decimals,
+    )?;
+
+    // The pot only holds what arrived after transfer fees, so that is what counts toward the bet.
+    ctx.accounts.round_token_vault.reload()?;
+    let amount_received = ctx
+        .accounts
+        .round_token_vault
+        .amount
+        .checked_sub(vault_balance_before)
+        .ok_or(ErrorCode::CalculationError)?;
+    msg!(
+        "Token transfer successful from escrow vault to round vault. Sent {}, received {}.",
+        amount,
+        amount_received
+    );
+
+    let escrow_account_data = &mut ctx.accounts.user_token_escrow;
+    escrow_account_data.balance = escrow_account_data
+        .balance
+        .checked_sub(amount)
+        .ok_or(ErrorCode::InsufficientPlatformBalance)?;
+    msg!("UserTokenEscrow new data balance: {}", escrow_account_data.balance);
+
+    let round_state = &mut ctx.accounts.round_state.load_mut()?;
+    round_state.record_bet(player_key, amount_received)?;
+
+    msg!("Player {} successfully placed/updated token bet of {} for round {} on table {}.", player_key, amount_received, round_id_for_pdas, table_id);
+    msg!("--- PlaceTokenBet finished ---");
+    Ok(())
+}
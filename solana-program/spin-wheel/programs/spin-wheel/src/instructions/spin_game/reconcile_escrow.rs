@@ -0,0 +1,61 @@
+use crate::accounting;
+use crate::{ErrorCode, EscrowReconciled, UserPlatformEscrow};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct ReconcileEscrow<'info> {
+    /// Anyone can report drift; only the escrow's owner can repair it.
+    pub caller: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"user_escrow", user_platform_escrow.user_authority.as_ref()],
+        bump = user_platform_escrow.bump
+    )]
+    pub user_platform_escrow: Account<'info, UserPlatformEscrow>,
+}
+
+pub fn process_reconcile_escrow(ctx: Context<ReconcileEscrow>, repair: bool) -> Result<()> {
+    msg!("--- Instruction: ReconcileEscrow ---");
+    msg!("Caller: {}", ctx.accounts.caller.key());
+    msg!("Escrow: {}", ctx.accounts.user_platform_escrow.key());
+    msg!("Repair requested: {}", repair);
+
+    let escrow = &mut ctx.accounts.user_platform_escrow;
+    let lamports = escrow.to_account_info().lamports();
+    let drift = accounting::escrow_drift(escrow)?;
+    msg!(
+        "Lamports: {}, balance: {}, rent minimum: {}, surplus: {}, deficit: {}",
+        lamports,
+        escrow.balance,
+        drift.rent_minimum,
+        drift.surplus,
+        drift.deficit
+    );
+
+    // Surplus lamports (e.g. SOL sent straight to the PDA) belong to the user and are credited
+    // to the balance. A deficit cannot be repaired here and is only reported.
+    let repaired = repair && drift.surplus > 0;
+    if repaired {
+        require_keys_eq!(
+            ctx.accounts.caller.key(),
+            escrow.user_authority,
+            ErrorCode::UnauthorizedEscrowAccess
+        );
+        accounting::record_escrow_credit(escrow, drift.surplus)?;
+        msg!("Credited {} surplus lamports. Balance now: {}", drift.surplus, escrow.balance);
+    }
+
+    emit!(EscrowReconciled {
+        user: escrow.user_authority,
+        lamports,
+        balance: escrow.balance,
+        rent_minimum: drift.rent_minimum,
+        surplus: if repaired { 0 } else { drift.surplus },
+        deficit: drift.deficit,
+        repaired,
+    });
+
+    msg!("--- ReconcileEscrow finished ---");
+    Ok(())
+}
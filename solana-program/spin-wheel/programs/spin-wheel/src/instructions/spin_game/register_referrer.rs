@@ -0,0 +1,262 @@
+use crate::instructions::finalize_round::load_singleton;
+use crate::{ErrorCode, PlayerData, ReferralAccount, ReferrerStats};
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::clock::Clock;
+
+#[derive(Accounts)]
+#[instruction(referrer: Pubkey)]
+pub struct RegisterReferrer<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    // `init` makes registration a one-time action per user.
+    #[account(
+        init,
+        payer = user,
+        space = 8 + std::mem::size_of::<ReferralAccount>(),
+        seeds = [b"referral", user.key().as_ref()],
+        bump
+    )]
+    pub referral_account: Box<Account<'info, ReferralAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = 8 + std::mem::size_of::<ReferrerStats>(),
+        seeds = [b"referrer_stats", referrer.as_ref()],
+        bump
+    )]
+    pub referrer_stats: Box<Account<'info, ReferrerStats>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Pays referrers their share of the house fee for each referred player in the round.
+/// `referral_accounts` holds one `[ReferralAccount, ReferrerStats]` pair per player, in round
+/// order, so a referred player can't be left out of the split. A player without a referrer
+/// passes their empty referral PDA and any second account, which is not read. Lamports move
+/// from `source` (the round pot) into each ReferrerStats PDA, where they wait for
+/// claim_referral_earnings. Returns the total paid.
+pub fn accrue_referral_shares<'info>(
+    players: &[PlayerData],
+    total_pot: u64,
+    house_fee: u64,
+    referral_share_basis_points: u16,
+    source: &AccountInfo<'info>,
+    referral_accounts: &'info [AccountInfo<'info>],
+) -> Result<u64> {
+    if referral_share_basis_points == 0 || total_pot == 0 {
+        return Ok(0);
+    }
+    require!(
+        referral_accounts.len() == players.len() * 2,
+        ErrorCode::InvalidReferralAccount
+    );
+
+    let mut total_paid: u64 = 0;
+    for (player, pair) in players.iter().zip(referral_accounts.chunks_exact(2)) {
+        let (expected_referral_key, _) =
+            Pubkey::find_program_address(&[b"referral", player.pubkey.as_ref()], &crate::ID);
+        require_keys_eq!(pair[0].key(), expected_referral_key, ErrorCode::InvalidReferralAccount);
+        let Some(referral) = load_singleton::<ReferralAccount>(&pair[0])? else {
+            continue;
+        };
+
+        let mut stats = Account::<ReferrerStats>::try_from(&pair[1])?;
+        require_keys_eq!(stats.referrer, referral.referrer, ErrorCode::InvalidReferralAccount);
+        let expected_stats_key = Pubkey::create_program_address(
+            &[b"referrer_stats", stats.referrer.as_ref(), &[stats.bump]],
+            &crate::ID,
+        )
+        .map_err(|_| ErrorCode::InvalidReferralAccount)?;
+        require_keys_eq!(pair[1].key(), expected_stats_key, ErrorCode::InvalidReferralAccount);
+
+        let share = (house_fee as u128)
+            .checked_mul(player.amount as u128)
+            .and_then(|v| v.checked_div(total_pot as u128))
+            .and_then(|v| v.checked_mul(referral_share_basis_points as u128))
+            .and_then(|v| v.checked_div(10_000))
+            .ok_or(ErrorCode::CalculationError)?;
+        let share = u64::try_from(share).map_err(|_| ErrorCode::CalculationError)?;
+        if share == 0 {
+            continue;
+        }
+
+        **source.try_borrow_mut_lamports()? -= share;
+        **pair[1].try_borrow_mut_lamports()? += share;
+        stats.accrued_lamports = stats
+            .accrued_lamports
+            .checked_add(share)
+            .ok_or(ErrorCode::CalculationError)?;
+        stats.total_earned_lamports = stats
+            .total_earned_lamports
+            .checked_add(share)
+            .ok_or(ErrorCode::CalculationError)?;
+        stats.exit(&crate::ID)?;
+
+        total_paid = total_paid
+            .checked_add(share)
+            .ok_or(ErrorCode::CalculationError)?;
+        msg!(
+            "  Referral share {} lamports for player {} -> referrer {}",
+            share,
+            referral.user,
+            stats.referrer
+        );
+    }
+
+    msg!("Accrued {} lamports of house fee to referrers.", total_paid);
+    Ok(total_paid)
+}
+
+pub fn process_register_referrer(ctx: Context<RegisterReferrer>, referrer: Pubkey) -> Result<()> {
+    msg!("--- Instruction: RegisterReferrer ---");
+    msg!("User: {}", ctx.accounts.user.key());
+    msg!("Referrer: {}", referrer);
+
+    require!(referrer != ctx.accounts.user.key(), ErrorCode::SelfReferral);
+    require!(referrer != Pubkey::default(), ErrorCode::InvalidReferralAccount);
+
+    let clock = Clock::get()?;
+    let referral_account = &mut ctx.accounts.referral_account;
+    referral_account.user = ctx.accounts.user.key();
+    referral_account.referrer = referrer;
+    referral_account.registered_at = clock.unix_timestamp;
+    referral_account.bump = ctx.bumps.referral_account;
+
+    let referrer_stats = &mut ctx.accounts.referrer_stats;
+    if referrer_stats.referrer == Pubkey::default() {
+        referrer_stats.referrer = referrer;
+        referrer_stats.bump = ctx.bumps.referrer_stats;
+        msg!("ReferrerStats initialized for referrer {}.", referrer);
+    }
+    referrer_stats.referred_count = referrer_stats
+        .referred_count
+        .checked_add(1)
+        .ok_or(ErrorCode::CalculationError)?;
+
+    msg!(
+        "User {} registered with referrer {}. Referrer now has {} referral(s).",
+        referral_account.user,
+        referral_account.referrer,
+        referrer_stats.referred_count
+    );
+    msg!("--- RegisterReferrer finished ---");
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn leaked_account(key: Pubkey, owner: Pubkey, lamports: u64, data: Vec<u8>) -> AccountInfo<'static> {
+        AccountInfo::new(
+            Box::leak(Box::new(key)),
+            false,
+            true,
+            Box::leak(Box::new(lamports)),
+            Box::leak(data.into_boxed_slice()),
+            Box::leak(Box::new(owner)),
+            false,
+            0,
+        )
+    }
+
+    fn program_account<T: AccountSerialize>(key: Pubkey, value: &T, lamports: u64) -> AccountInfo<'static> {
+        let mut data = Vec::new();
+        value.try_serialize(&mut data).unwrap();
+        leaked_account(key, crate::ID, lamports, data)
+    }
+
+    fn referral_pda(user: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&[b"referral", user.as_ref()], &crate::ID)
+    }
+
+    /// The referred player's pair followed by an unreferred player's empty referral PDA.
+    fn referral_pairs(referred: &Pubkey, unreferred: &Pubkey, referrer: Pubkey) -> &'static [AccountInfo<'static>] {
+        let (referral_key, referral_bump) = referral_pda(referred);
+        let (stats_key, stats_bump) =
+            Pubkey::find_program_address(&[b"referrer_stats", referrer.as_ref()], &crate::ID);
+        let referral = ReferralAccount {
+            user: *referred,
+            referrer,
+            registered_at: 0,
+            bump: referral_bump,
+        };
+        let stats = ReferrerStats {
+            referrer,
+            referred_count: 1,
+            bump: stats_bump,
+            ..Default::default()
+        };
+        let accounts = vec![
+            program_account(referral_key, &referral, 1),
+            program_account(stats_key, &stats, 1),
+            leaked_account(referral_pda(unreferred).0, Pubkey::default(), 0, Vec::new()),
+            leaked_account(Pubkey::default(), Pubkey::default(), 0, Vec::new()),
+        ];
+        Box::leak(accounts.into_boxed_slice())
+    }
+
+    fn players(referred: Pubkey, unreferred: Pubkey) -> [PlayerData; 2] {
+        [
+            PlayerData { pubkey: referred, amount: 100_000 },
+            PlayerData { pubkey: unreferred, amount: 300_000 },
+        ]
+    }
+
+    #[test]
+    fn pays_the_referrer_of_each_referred_player() {
+        let (referred, unreferred) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let referrer = Pubkey::new_unique();
+        let accounts = referral_pairs(&referred, &unreferred, referrer);
+        let pot = leaked_account(Pubkey::new_unique(), crate::ID, 1_000_000, Vec::new());
+
+        // 20% of the quarter of the 4_000 fee the referred player's bet brought in.
+        let paid =
+            accrue_referral_shares(&players(referred, unreferred), 400_000, 4_000, 2_000, &pot, accounts).unwrap();
+        assert_eq!(paid, 200);
+        assert_eq!(pot.lamports(), 1_000_000 - 200);
+        assert_eq!(accounts[1].lamports(), 1 + 200);
+        let stats = Account::<ReferrerStats>::try_from(&accounts[1]).unwrap();
+        assert_eq!(stats.accrued_lamports, 200);
+        assert_eq!(stats.total_earned_lamports, 200);
+    }
+
+    #[test]
+    fn referred_players_cannot_be_left_out() {
+        let (referred, unreferred) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let accounts = referral_pairs(&referred, &unreferred, Pubkey::new_unique());
+        let pot = leaked_account(Pubkey::new_unique(), crate::ID, 1_000_000, Vec::new());
+        let players = players(referred, unreferred);
+
+        for partial in [&accounts[..0], &accounts[2..]] {
+            assert_eq!(
+                accrue_referral_shares(&players, 400_000, 4_000, 2_000, &pot, partial).unwrap_err(),
+                error!(ErrorCode::InvalidReferralAccount)
+            );
+        }
+        // Passing the referred player's pair in the other player's slot doesn't work either.
+        let swapped: &'static [AccountInfo<'static>] = Box::leak(
+            vec![
+                accounts[2].clone(),
+                accounts[3].clone(),
+                accounts[0].clone(),
+                accounts[1].clone(),
+            ]
+            .into_boxed_slice(),
+        );
+        assert_eq!(
+            accrue_referral_shares(&players, 400_000, 4_000, 2_000, &pot, swapped).unwrap_err(),
+            error!(ErrorCode::InvalidReferralAccount)
+        );
+        assert_eq!(pot.lamports(), 1_000_000);
+    }
+
+    #[test]
+    fn nothing_is_read_without_a_referral_share() {
+        let pot = leaked_account(Pubkey::new_unique(), crate::ID, 1_000_000, Vec::new());
+        let players = players(Pubkey::new_unique(), Pubkey::new_unique());
+        assert_eq!(accrue_referral_shares(&players, 400_000, 4_000, 0, &pot, &[]).unwrap(), 0);
+    }
+}
@@ -0,0 +1,108 @@
+use crate::{ErrorCode, HouseBankroll, LpWithdrawalRequest};
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::clock::Clock;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{self, spl_token_2022, Mint, Token2022, TokenAccount, TransferChecked},
+};
+
+#[derive(Accounts)]
+#[instruction(shares: u64)]
+pub struct RequestHouseLiquidityWithdrawal<'info> {
+    #[account(mut)]
+    pub provider: Signer<'info>,
+
+    #[account(
+        seeds = [b"house_bankroll"],
+        bump = house_bankroll.bump
+    )]
+    pub house_bankroll: Box<Account<'info, HouseBankroll>>,
+
+    #[account(address = house_bankroll.lp_mint @ ErrorCode::InvalidMintAccount)]
+    pub lp_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        token::mint = lp_mint,
+        token::authority = provider,
+        token::token_program = token_program
+    )]
+    pub provider_lp_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = provider,
+        associated_token::mint = lp_mint,
+        associated_token::authority = house_bankroll,
+        associated_token::token_program = token_program
+    )]
+    pub lp_withdrawal_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = provider,
+        space = 8 + std::mem::size_of::<LpWithdrawalRequest>(),
+        seeds = [b"lp_withdrawal", provider.key().as_ref()],
+        bump
+    )]
+    pub lp_withdrawal_request: Box<Account<'info, LpWithdrawalRequest>>,
+
+    #[account(address = spl_token_2022::ID @ ErrorCode::InvalidTokenProgram)]
+    pub token_program: Program<'info, Token2022>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn process_request_house_liquidity_withdrawal(
+    ctx: Context<RequestHouseLiquidityWithdrawal>,
+    shares: u64,
+) -> Result<()> {
+    msg!("--- Instruction: RequestHouseLiquidityWithdrawal ---");
+    msg!("Provider: {}", ctx.accounts.provider.key());
+    msg!("Shares to withdraw: {}", shares);
+
+    require!(shares > 0, ErrorCode::InvalidLpShareAmount);
+
+    if ctx.accounts.lp_withdrawal_request.provider == Pubkey::default() {
+        let request = &mut ctx.accounts.lp_withdrawal_request;
+        request.provider = ctx.accounts.provider.key();
+        request.bump = ctx.bumps.lp_withdrawal_request;
+        msg!("LpWithdrawalRequest initialized for {}.", request.provider);
+    }
+
+    // Shares are parked with the pool so they cannot be moved while the request waits.
+    token_interface::transfer_checked(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.provider_lp_token_account.to_account_info(),
+                to: ctx.accounts.lp_withdrawal_vault.to_account_info(),
+                authority: ctx.accounts.provider.to_account_info(),
+                mint: ctx.accounts.lp_mint.to_account_info(),
+            },
+        ),
+        shares,
+        ctx.accounts.lp_mint.decimals,
+    )?;
+
+    // The payout is priced when the request completes, so outcomes known now cannot be front-run.
+    let clock = Clock::get()?;
+    let withdrawal_delay = ctx.accounts.house_bankroll.withdrawal_delay;
+    let request = &mut ctx.accounts.lp_withdrawal_request;
+    request.shares = request
+        .shares
+        .checked_add(shares)
+        .ok_or(ErrorCode::CalculationError)?;
+    request.available_at = clock
+        .unix_timestamp
+        .checked_add(withdrawal_delay)
+        .ok_or(ErrorCode::CalculationError)?;
+
+    msg!(
+        "LP withdrawal queued. Pending shares: {}, available at: {}",
+        request.shares,
+        request.available_at
+    );
+    msg!("--- RequestHouseLiquidityWithdrawal finished ---");
+    Ok(())
+}
@@ -0,0 +1,114 @@
+use crate::accounting;
+use crate::instructions::set_withdrawal_fee_schedule::cashino_holding_of;
+use crate::{ErrorCode, PendingWithdrawal, PlatformConfig, StakerPosition, UserPlatformEscrow};
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::clock::Clock;
+use anchor_spl::token_interface::TokenAccount;
+
+#[derive(Accounts)]
+#[instruction(amount: u64)]
+pub struct RequestSolWithdrawal<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"user_escrow", user.key().as_ref()],
+        bump = user_platform_escrow.bump,
+        constraint = user_platform_escrow.user_authority == user.key() @ ErrorCode::UnauthorizedEscrowAccess
+    )]
+    pub user_platform_escrow: Account<'info, UserPlatformEscrow>,
+
+    #[account(seeds = [b"platform_config"], bump)]
+    pub platform_config: Box<Account<'info, PlatformConfig>>,
+
+    /// Optional. The user's CASHINO token account, counted towards withdrawal fee discounts.
+    #[account(
+        constraint = cashino_token_account.owner == user.key() @ ErrorCode::UnauthorizedAccess,
+        constraint = cashino_token_account.mint == platform_config.cashino_mint @ ErrorCode::InvalidMintAccount
+    )]
+    pub cashino_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    /// Optional. The user's staking position, counted towards withdrawal fee discounts.
+    #[account(
+        seeds = [b"staker", user.key().as_ref()],
+        bump = staker_position.bump
+    )]
+    pub staker_position: Option<Box<Account<'info, StakerPosition>>>,
+
+    #[account(
+        init,
+        payer = user,
+        space = 8 + std::mem::size_of::<PendingWithdrawal>(),
+        seeds = [b"pending_withdrawal", user.key().as_ref()],
+        bump
+    )]
+    pub pending_withdrawal: Box<Account<'info, PendingWithdrawal>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn process_request_sol_withdrawal(ctx: Context<RequestSolWithdrawal>, amount: u64) -> Result<()> {
+    msg!("--- Instruction: RequestSolWithdrawal ---");
+    msg!("User {} requesting withdrawal of {} lamports.", ctx.accounts.user.key(), amount);
+
+    let clock = Clock::get()?;
+    require!(
+        !ctx.accounts.user_platform_escrow.withdrawals_frozen,
+        ErrorCode::EscrowWithdrawalsFrozen
+    );
+    let review_threshold = ctx.accounts.platform_config.withdrawal_review_threshold;
+    let withdrawn_in_window = ctx
+        .accounts
+        .user_platform_escrow
+        .withdrawn_in_window_with(amount, clock.unix_timestamp)?;
+    require!(
+        review_threshold > 0 && withdrawn_in_window > review_threshold,
+        ErrorCode::WithdrawalBelowReviewThreshold
+    );
+
+    // The fee is fixed at request time so the user knows exactly what will be paid out.
+    let cashino_holding = cashino_holding_of(
+        ctx.accounts.cashino_token_account.as_deref().map(|account| &**account),
+        ctx.accounts.staker_position.as_deref().map(|position| &**position),
+    );
+    let withdrawal_fee = ctx.accounts.platform_config.withdrawal_fee.fee_for(
+        amount,
+        cashino_holding,
+        clock.unix_timestamp,
+    )?;
+    msg!("CASHINO holding: {}, withdrawal fee: {}", cashino_holding, withdrawal_fee);
+
+    let total_debit = amount
+        .checked_add(withdrawal_fee)
+        .ok_or(ErrorCode::CalculationError)?;
+
+    // Funds leave the escrow now so they cannot be bet while the request is pending.
+    accounting::transfer_from_escrow(
+        &mut ctx.accounts.user_platform_escrow,
+        &ctx.accounts.pending_withdrawal.to_account_info(),
+        total_debit,
+    )?;
+    let escrow = &ctx.accounts.user_platform_escrow;
+
+    let pending_withdrawal = &mut ctx.accounts.pending_withdrawal;
+    pending_withdrawal.user = ctx.accounts.user.key();
+    pending_withdrawal.amount = amount;
+    pending_withdrawal.fee = withdrawal_fee;
+    pending_withdrawal.requested_at = clock.unix_timestamp;
+    pending_withdrawal.executable_at = clock
+        .unix_timestamp
+        .checked_add(ctx.accounts.platform_config.withdrawal_review_delay)
+        .ok_or(ErrorCode::CalculationError)?;
+    pending_withdrawal.bump = ctx.bumps.pending_withdrawal;
+
+    msg!(
+        "Withdrawal of {} (fee {}) pending until {}. Escrow data balance now: {}",
+        pending_withdrawal.amount,
+        pending_withdrawal.fee,
+        pending_withdrawal.executable_at,
+        escrow.balance
+    );
+    msg!("--- RequestSolWithdrawal finished ---");
+    Ok(())
+}
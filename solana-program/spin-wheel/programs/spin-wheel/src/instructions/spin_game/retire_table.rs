@@ -0,0 +1,43 @@
+use crate::{ErrorCode, GameState, PlatformConfig};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(table_id: u64)]
+pub struct RetireTable<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"platform_config"],
+        bump,
+        constraint = platform_config.authority == authority.key() @ ErrorCode::UnauthorizedAccess,
+    )]
+    pub platform_config: Box<Account<'info, PlatformConfig>>,
+
+    #[account(
+        mut,
+        seeds = [b"game_state".as_ref(), &table_id.to_le_bytes()],
+        bump,
+        constraint = !game_state.is_retired @ ErrorCode::TableRetired
+    )]
+    pub game_state: Box<Account<'info, GameState>>,
+}
+
+pub fn process_retire_table(ctx: Context<RetireTable>, table_id: u64) -> Result<()> {
+    msg!("--- Instruction: RetireTable ---");
+    msg!("Authority: {}", ctx.accounts.authority.key());
+    msg!("Table ID to retire: {}", table_id);
+    msg!("GameState PDA: {}", ctx.accounts.game_state.key());
+
+    let game_state = &mut ctx.accounts.game_state;
+    game_state.is_retired = true;
+
+    // Rounds already started on this table can still be finalized and claimed;
+    // only start_new_round checks the flag.
+    msg!(
+        "Table {} retired after {} round(s). No new rounds can be started.",
+        game_state.table_id,
+        game_state.round_counter
+    );
+    msg!("--- RetireTable finished ---");
+    Ok(())
+}
@@ -0,0 +1,55 @@
+use crate::instructions::finalize_round::validate_round_ready_for_finalize;
+use crate::{ErrorCode, GameState, RoundState, SeedArray};
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::clock::Clock;
+
+#[derive(Accounts)]
+#[instruction(table_id: u64, revealed_seed_arg: SeedArray, round_id_for_pdas: u64)]
+pub struct RevealFixedOddsSeed<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"game_state".as_ref(), &table_id.to_le_bytes()],
+        bump,
+        constraint = game_state.authority == authority.key() @ ErrorCode::UnauthorizedAccess,
+        constraint = game_state.is_initialized @ ErrorCode::UnauthorizedAccess
+    )]
+    pub game_state: Box<Account<'info, GameState>>,
+
+    #[account(
+        mut,
+        seeds = [b"round_state".as_ref(), &table_id.to_le_bytes(), &round_id_for_pdas.to_le_bytes()],
+        bump,
+        constraint = round_state.load()?.is_fixed_odds() @ ErrorCode::InvalidGameMode
+    )]
+    pub round_state: AccountLoader<'info, RoundState>,
+}
+
+/// First half of a fixed-odds settlement. Reveals the committed seed once betting has closed and
+/// records the current slot, whose hash settle_fixed_odds_round mixes into the spin. The slot's
+/// hash does not exist yet, so neither the bettors nor the house can tell the outcome from here.
+pub fn process_reveal_fixed_odds_seed(
+    ctx: Context<RevealFixedOddsSeed>,
+    table_id: u64,
+    revealed_seed_arg: SeedArray,
+    round_id_for_pdas: u64,
+) -> Result<()> {
+    msg!("--- Instruction: RevealFixedOddsSeed ---");
+    msg!("Table ID: {}", table_id);
+    msg!("Round ID for PDAs: {}", round_id_for_pdas);
+    msg!("Revealed seed (arg): {:?}", revealed_seed_arg);
+
+    let clock = Clock::get()?;
+    let mut round_rw = ctx.accounts.round_state.load_mut()?;
+    require!(
+        round_rw.get_revealed_seed().is_none(),
+        ErrorCode::SeedAlreadyRevealed
+    );
+    validate_round_ready_for_finalize(&round_rw, &revealed_seed_arg, clock.unix_timestamp)?;
+
+    round_rw.set_revealed_seed(Some(revealed_seed_arg));
+    round_rw.entropy_slot = clock.slot;
+    msg!("Seed revealed. The spin uses the hash of slot {}.", clock.slot);
+    msg!("--- RevealFixedOddsSeed finished ---");
+    Ok(())
+}
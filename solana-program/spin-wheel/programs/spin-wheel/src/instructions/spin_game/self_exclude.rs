@@ -0,0 +1,48 @@
+use crate::instructions::finalize_round::load_singleton;
+use crate::{ErrorCode, UserPlatformEscrow};
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::clock::Clock;
+
+#[derive(Accounts)]
+pub struct SelfExclude<'info> {
+    pub user: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"user_escrow", user.key().as_ref()],
+        bump = user_platform_escrow.bump,
+        constraint = user_platform_escrow.user_authority == user.key() @ ErrorCode::UnauthorizedEscrowAccess
+    )]
+    pub user_platform_escrow: Account<'info, UserPlatformEscrow>,
+}
+
+pub fn process_self_exclude(ctx: Context<SelfExclude>, duration_seconds: i64) -> Result<()> {
+    msg!("--- Instruction: SelfExclude ---");
+    msg!("User: {}", ctx.accounts.user.key());
+    msg!("Requested exclusion (seconds): {}", duration_seconds);
+
+    require!(duration_seconds > 0, ErrorCode::InvalidSelfExclusionPeriod);
+
+    let clock = Clock::get()?;
+    let requested_until = clock
+        .unix_timestamp
+        .checked_add(duration_seconds)
+        .ok_or(ErrorCode::CalculationError)?;
+
+    // An exclusion can be extended but never shortened.
+    let escrow = &mut ctx.accounts.user_platform_escrow;
+    escrow.self_excluded_until = escrow.self_excluded_until.max(requested_until);
+
+    msg!("Betting and deposits disabled until {}.", escrow.self_excluded_until);
+    msg!("--- SelfExclude finished ---");
+    Ok(())
+}
+
+/// Token tables keep balances in `UserTokenEscrow`, but the exclusion lives on the player's
+/// `UserPlatformEscrow`. A player who never deposited SOL has none and so cannot be excluded.
+pub(crate) fn require_not_self_excluded(user_platform_escrow: &AccountInfo, now: i64) -> Result<()> {
+    match load_singleton::<UserPlatformEscrow>(user_platform_escrow)? {
+        Some(escrow) => escrow.require_not_self_excluded(now),
+        None => Ok(()),
+    }
+}
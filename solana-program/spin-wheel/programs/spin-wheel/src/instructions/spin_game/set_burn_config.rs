@@ -0,0 +1,52 @@
+use crate::{ErrorCode, GameState, PlatformConfig, MIN_UNCLAIMED_REWARD_CLAIM_WINDOW};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(table_id: u64)]
+pub struct SetBurnConfig<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"platform_config"],
+        bump,
+        constraint = platform_config.authority == authority.key() @ ErrorCode::UnauthorizedAccess,
+        constraint = platform_config.is_initialized @ ErrorCode::UnauthorizedAccess
+    )]
+    pub platform_config: Box<Account<'info, PlatformConfig>>,
+
+    #[account(
+        mut,
+        seeds = [b"game_state".as_ref(), &table_id.to_le_bytes()],
+        bump,
+    )]
+    pub game_state: Box<Account<'info, GameState>>,
+}
+
+pub fn process_set_burn_config(
+    ctx: Context<SetBurnConfig>,
+    table_id: u64,
+    burn_unclaimed_rewards: bool,
+    unclaimed_reward_claim_window: i64,
+) -> Result<()> {
+    msg!("--- Instruction: SetBurnConfig ---");
+    msg!("Authority: {}", ctx.accounts.authority.key());
+    msg!("Table ID: {}", table_id);
+    msg!(
+        "Burn unclaimed rewards: {}, claim window: {} seconds",
+        burn_unclaimed_rewards,
+        unclaimed_reward_claim_window
+    );
+
+    require!(
+        unclaimed_reward_claim_window >= MIN_UNCLAIMED_REWARD_CLAIM_WINDOW,
+        ErrorCode::InvalidBurnConfig
+    );
+
+    let burn = &mut ctx.accounts.game_state.burn;
+    burn.burn_unclaimed_rewards = burn_unclaimed_rewards;
+    burn.unclaimed_reward_claim_window = unclaimed_reward_claim_window;
+
+    msg!("Burn config updated for table {}.", table_id);
+    msg!("--- SetBurnConfig finished ---");
+    Ok(())
+}
@@ -0,0 +1,83 @@
+use crate::{EmissionSchedule, ErrorCode, GameState, PlatformConfig, CASHINO_MAX_SUPPLY_UNITS};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(table_id: u64)]
+pub struct SetEmissionSchedule<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"platform_config"],
+        bump,
+        constraint = platform_config.authority == authority.key() @ ErrorCode::UnauthorizedAccess,
+    )]
+    pub platform_config: Box<Account<'info, PlatformConfig>>,
+
+    #[account(
+        mut,
+        seeds = [b"game_state".as_ref(), &table_id.to_le_bytes()],
+        bump,
+        constraint = !game_state.is_retired @ ErrorCode::TableRetired
+    )]
+    pub game_state: Box<Account<'info, GameState>>,
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn process_set_emission_schedule(
+    ctx: Context<SetEmissionSchedule>,
+    table_id: u64,
+    initial_per_round: u64,
+    halving_interval_rounds: u64,
+    halving_interval_seconds: i64,
+    max_supply: u64,
+    volume_reference: u64,
+    volume_multiplier_cap_basis_points: u16,
+) -> Result<()> {
+    msg!("--- Instruction: SetEmissionSchedule ---");
+    msg!("Authority: {}", ctx.accounts.authority.key());
+    msg!("Table ID: {}", table_id);
+    msg!(
+        "Initial per round: {}, halving every {} rounds / {} seconds, max supply: {}",
+        initial_per_round,
+        halving_interval_rounds,
+        halving_interval_seconds,
+        max_supply
+    );
+    msg!(
+        "Volume reference: {}, multiplier cap (bp): {}",
+        volume_reference,
+        volume_multiplier_cap_basis_points
+    );
+
+    require!(initial_per_round > 0, ErrorCode::InvalidEmissionSchedule);
+    require!(
+        halving_interval_seconds >= 0
+            && (halving_interval_rounds == 0 || halving_interval_seconds == 0),
+        ErrorCode::InvalidEmissionSchedule
+    );
+    require!(
+        max_supply > 0 && max_supply <= CASHINO_MAX_SUPPLY_UNITS,
+        ErrorCode::InvalidEmissionSchedule
+    );
+    require!(
+        volume_reference == 0 || volume_multiplier_cap_basis_points > 0,
+        ErrorCode::InvalidEmissionSchedule
+    );
+
+    let clock = Clock::get()?;
+    let game_state = &mut ctx.accounts.game_state;
+    game_state.emission = EmissionSchedule {
+        initial_per_round,
+        halving_interval_rounds,
+        halving_interval_seconds,
+        schedule_start: clock.unix_timestamp,
+        max_supply,
+        volume_reference,
+        volume_multiplier_cap_basis_points,
+        total_emitted: game_state.emission.total_emitted,
+    };
+
+    msg!("Emission schedule updated. Schedule start: {}", clock.unix_timestamp);
+    msg!("--- SetEmissionSchedule finished ---");
+    Ok(())
+}
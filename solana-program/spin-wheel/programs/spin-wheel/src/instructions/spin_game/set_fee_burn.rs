@@ -0,0 +1,66 @@
+use crate::{ErrorCode, PlatformConfig, MINT_AUTHORITY_SEED};
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{spl_token_2022, Mint, Token2022, TokenAccount},
+};
+
+#[derive(Accounts)]
+pub struct SetFeeBurn<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"platform_config"],
+        bump,
+        constraint = platform_config.authority == authority.key() @ ErrorCode::UnauthorizedAccess,
+        constraint = platform_config.is_initialized @ ErrorCode::UnauthorizedAccess
+    )]
+    pub platform_config: Box<Account<'info, PlatformConfig>>,
+
+    #[account(
+        address = platform_config.cashino_mint @ ErrorCode::InvalidMintAccount
+    )]
+    pub cashino_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// CHECK: The mint_authority_pda, which owns the fee burn vault.
+    #[account(
+        seeds = [MINT_AUTHORITY_SEED],
+        bump
+    )]
+    pub mint_authority_pda: AccountInfo<'info>,
+
+    /// Withheld fees pass through here so the burned share can be burned by the PDA.
+    #[account(
+        init_if_needed,
+        payer = authority,
+        associated_token::mint = cashino_mint,
+        associated_token::authority = mint_authority_pda,
+        associated_token::token_program = token_program
+    )]
+    pub fee_burn_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(address = spl_token_2022::ID @ ErrorCode::InvalidTokenProgram)]
+    pub token_program: Program<'info, Token2022>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Sets the share of collected transfer fees that is burned. The fees come from the one
+/// CASHINO mint every table shares, so the setting is platform-wide.
+pub fn process_set_fee_burn(ctx: Context<SetFeeBurn>, fee_burn_basis_points: u16) -> Result<()> {
+    msg!("--- Instruction: SetFeeBurn ---");
+    msg!("Authority: {}", ctx.accounts.authority.key());
+    msg!("Fee burn (bp): {}", fee_burn_basis_points);
+
+    require!(fee_burn_basis_points <= 10_000, ErrorCode::InvalidBurnConfig);
+    ctx.accounts.platform_config.fee_burn_basis_points = fee_burn_basis_points;
+
+    msg!(
+        "Fee burn updated. Fee burn vault: {}",
+        ctx.accounts.fee_burn_vault.key()
+    );
+    msg!("--- SetFeeBurn finished ---");
+    Ok(())
+}
@@ -0,0 +1,215 @@
+use crate::{ErrorCode, FeeRecipient, PlatformConfig, MAX_FEE_RECIPIENTS};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetFeeSplit<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"platform_config"],
+        bump,
+        constraint = platform_config.authority == authority.key() @ ErrorCode::UnauthorizedAccess,
+        constraint = platform_config.is_initialized @ ErrorCode::UnauthorizedAccess
+    )]
+    pub platform_config: Box<Account<'info, PlatformConfig>>,
+}
+
+/// Moves `amount` lamports of house fee out of `source` according to the platform fee split.
+/// `source` must be owned by this program. The configured recipients are expected, in order,
+/// at the front of `recipient_accounts`; with no split configured everything goes to `house_wallet`.
+/// The stakers' share is taken by finalize_round before this is called, so the staking pool is
+/// not listed here.
+pub fn distribute_house_fee<'info>(
+    platform_config: &PlatformConfig,
+    source: &AccountInfo<'info>,
+    house_wallet: &AccountInfo<'info>,
+    recipient_accounts: &[AccountInfo<'info>],
+    amount: u64,
+) -> Result<()> {
+    if amount == 0 {
+        return Ok(());
+    }
+
+    let recipient_count = platform_config.fee_recipient_count as usize;
+    if recipient_count == 0 {
+        **source.try_borrow_mut_lamports()? -= amount;
+        **house_wallet.try_borrow_mut_lamports()? += amount;
+        msg!("No fee split configured. Transferred {} lamports to house wallet {}.", amount, house_wallet.key());
+        return Ok(());
+    }
+
+    require!(
+        recipient_accounts.len() >= recipient_count,
+        ErrorCode::InvalidFeeRecipient
+    );
+
+    let mut shares = [0u64; MAX_FEE_RECIPIENTS];
+    let mut distributed: u64 = 0;
+    for (i, recipient) in platform_config.fee_recipients[..recipient_count].iter().enumerate() {
+        require_keys_eq!(
+            recipient_accounts[i].key(),
+            recipient.wallet,
+            ErrorCode::InvalidFeeRecipient
+        );
+        require!(recipient_accounts[i].is_writable, ErrorCode::InvalidFeeRecipient);
+        shares[i] = amount
+            .checked_mul(recipient.share_basis_points as u64)
+            .and_then(|v| v.checked_div(10_000))
+            .ok_or(ErrorCode::CalculationError)?;
+        distributed = distributed
+            .checked_add(shares[i])
+            .ok_or(ErrorCode::CalculationError)?;
+    }
+
+    let dust = amount
+        .checked_sub(distributed)
+        .ok_or(ErrorCode::CalculationError)?;
+    let dust_index = platform_config.fee_dust_recipient_index as usize;
+    shares[dust_index] = shares[dust_index]
+        .checked_add(dust)
+        .ok_or(ErrorCode::CalculationError)?;
+
+    **source.try_borrow_mut_lamports()? -= amount;
+    for (i, share) in shares[..recipient_count].iter().enumerate() {
+        **recipient_accounts[i].try_borrow_mut_lamports()? += *share;
+        msg!("  Fee share {} lamports -> {}", share, recipient_accounts[i].key());
+    }
+    msg!("Distributed {} lamports of house fee ({} dust to recipient index {}).", amount, dust, dust_index);
+    Ok(())
+}
+
+pub fn process_set_fee_split(
+    ctx: Context<SetFeeSplit>,
+    recipients: Vec<FeeRecipient>,
+    dust_recipient_index: u8,
+) -> Result<()> {
+    msg!("--- Instruction: SetFeeSplit ---");
+    msg!("Authority: {}", ctx.accounts.authority.key());
+    msg!("Recipient count: {}", recipients.len());
+
+    require!(
+        recipients.len() <= MAX_FEE_RECIPIENTS,
+        ErrorCode::TooManyFeeRecipients
+    );
+
+    if !recipients.is_empty() {
+        let mut total_share_basis_points: u32 = 0;
+        for recipient in recipients.iter() {
+            require!(
+                recipient.wallet != Pubkey::default(),
+                ErrorCode::InvalidFeeRecipient
+            );
+            total_share_basis_points += recipient.share_basis_points as u32;
+            msg!("  Recipient {}: {} bp", recipient.wallet, recipient.share_basis_points);
+        }
+        require!(total_share_basis_points == 10_000, ErrorCode::InvalidFeeSplit);
+        require!(
+            (dust_recipient_index as usize) < recipients.len(),
+            ErrorCode::InvalidFeeDustRecipient
+        );
+    }
+
+    let platform_config = &mut ctx.accounts.platform_config;
+    platform_config.fee_recipients = [FeeRecipient::default(); MAX_FEE_RECIPIENTS];
+    platform_config.fee_recipients[..recipients.len()].copy_from_slice(&recipients);
+    platform_config.fee_recipient_count = recipients.len() as u8;
+    platform_config.fee_dust_recipient_index = if recipients.is_empty() { 0 } else { dust_recipient_index };
+
+    if recipients.is_empty() {
+        msg!("Fee split cleared. House fees go to house wallet {}.", platform_config.house_wallet);
+    } else {
+        msg!(
+            "Fee split set across {} recipient(s), dust to index {}.",
+            platform_config.fee_recipient_count,
+            platform_config.fee_dust_recipient_index
+        );
+    }
+    msg!("--- SetFeeSplit finished ---");
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn wallet(key: Pubkey, lamports: u64, is_writable: bool) -> AccountInfo<'static> {
+        AccountInfo::new(
+            Box::leak(Box::new(key)),
+            false,
+            is_writable,
+            Box::leak(Box::new(lamports)),
+            Box::leak(Vec::new().into_boxed_slice()),
+            Box::leak(Box::new(Pubkey::default())),
+            false,
+            0,
+        )
+    }
+
+    /// A 50/30/20 split with the dust going to the second recipient.
+    fn three_way_split() -> (PlatformConfig, Vec<AccountInfo<'static>>) {
+        let shares = [5_000, 3_000, 2_000];
+        let mut platform_config = PlatformConfig {
+            fee_recipient_count: 3,
+            fee_dust_recipient_index: 1,
+            ..Default::default()
+        };
+        let mut recipients = Vec::new();
+        for (i, share_basis_points) in shares.into_iter().enumerate() {
+            let key = Pubkey::new_unique();
+            platform_config.fee_recipients[i] = FeeRecipient { wallet: key, share_basis_points };
+            recipients.push(wallet(key, 0, true));
+        }
+        (platform_config, recipients)
+    }
+
+    #[test]
+    fn without_a_split_the_house_wallet_takes_everything() {
+        let pot = wallet(Pubkey::new_unique(), 1_000, true);
+        let house_wallet = wallet(Pubkey::new_unique(), 0, true);
+        distribute_house_fee(&PlatformConfig::default(), &pot, &house_wallet, &[], 999).unwrap();
+        assert_eq!((pot.lamports(), house_wallet.lamports()), (1, 999));
+    }
+
+    #[test]
+    fn fee_is_split_by_share_with_the_dust_to_the_dust_recipient() {
+        let (platform_config, recipients) = three_way_split();
+        let pot = wallet(Pubkey::new_unique(), 1_000, true);
+        let house_wallet = wallet(Pubkey::new_unique(), 0, true);
+        distribute_house_fee(&platform_config, &pot, &house_wallet, &recipients, 999).unwrap();
+
+        let received: Vec<u64> = recipients.iter().map(|r| r.lamports()).collect();
+        // 499 + 299 + 199 = 997; the 2 lamports of dust go to the second recipient.
+        assert_eq!(received, vec![499, 301, 199]);
+        assert_eq!(pot.lamports(), 1);
+        assert_eq!(house_wallet.lamports(), 0);
+    }
+
+    #[test]
+    fn recipients_must_be_passed_in_configured_order() {
+        let (platform_config, mut recipients) = three_way_split();
+        recipients.swap(0, 2);
+        let pot = wallet(Pubkey::new_unique(), 1_000, true);
+        let house_wallet = wallet(Pubkey::new_unique(), 0, true);
+        assert_eq!(
+            distribute_house_fee(&platform_config, &pot, &house_wallet, &recipients, 999).unwrap_err(),
+            error!(ErrorCode::InvalidFeeRecipient)
+        );
+    }
+
+    #[test]
+    fn every_recipient_must_be_passed_and_writable() {
+        let (platform_config, mut recipients) = three_way_split();
+        recipients[2] = wallet(recipients[2].key(), 0, false);
+        let pot = wallet(Pubkey::new_unique(), 1_000, true);
+        let house_wallet = wallet(Pubkey::new_unique(), 0, true);
+        assert_eq!(
+            distribute_house_fee(&platform_config, &pot, &house_wallet, &recipients[..2], 999).unwrap_err(),
+            error!(ErrorCode::InvalidFeeRecipient)
+        );
+        assert_eq!(
+            distribute_house_fee(&platform_config, &pot, &house_wallet, &recipients, 999).unwrap_err(),
+            error!(ErrorCode::InvalidFeeRecipient)
+        );
+    }
+}
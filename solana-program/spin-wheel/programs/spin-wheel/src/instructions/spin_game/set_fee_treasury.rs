@@ -0,0 +1,51 @@
+use crate::{ErrorCode, PlatformConfig};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{spl_token_2022, Mint, Token2022, TokenAccount};
+
+#[derive(Accounts)]
+pub struct SetFeeTreasury<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"platform_config"],
+        bump,
+        constraint = platform_config.authority == authority.key() @ ErrorCode::UnauthorizedAccess,
+        constraint = platform_config.is_initialized @ ErrorCode::UnauthorizedAccess
+    )]
+    pub platform_config: Box<Account<'info, PlatformConfig>>,
+
+    #[account(
+        address = platform_config.cashino_mint @ ErrorCode::InvalidMintAccount
+    )]
+    pub cashino_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// CHECK: Owner of the treasury ATA, e.g. a multisig. Only used to derive the ATA.
+    pub treasury_owner: UncheckedAccount<'info>,
+
+    #[account(
+        associated_token::mint = cashino_mint,
+        associated_token::authority = treasury_owner,
+        associated_token::token_program = token_program
+    )]
+    pub fee_treasury: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(address = spl_token_2022::ID @ ErrorCode::InvalidTokenProgram)]
+    pub token_program: Program<'info, Token2022>,
+}
+
+pub fn process_set_fee_treasury(ctx: Context<SetFeeTreasury>) -> Result<()> {
+    msg!("--- Instruction: SetFeeTreasury ---");
+    msg!("Authority: {}", ctx.accounts.authority.key());
+    msg!(
+        "Fee treasury ATA: {} (owner {})",
+        ctx.accounts.fee_treasury.key(),
+        ctx.accounts.treasury_owner.key()
+    );
+
+    ctx.accounts.platform_config.fee_treasury = ctx.accounts.fee_treasury.key();
+
+    msg!("Platform fee treasury set.");
+    msg!("--- SetFeeTreasury finished ---");
+    Ok(())
+}
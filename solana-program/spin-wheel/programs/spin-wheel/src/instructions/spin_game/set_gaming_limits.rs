@@ -0,0 +1,37 @@
+use crate::{ErrorCode, GamingLimits, UserPlatformEscrow};
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::clock::Clock;
+
+#[derive(Accounts)]
+pub struct SetGamingLimits<'info> {
+    pub user: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"user_escrow", user.key().as_ref()],
+        bump = user_platform_escrow.bump,
+        constraint = user_platform_escrow.user_authority == user.key() @ ErrorCode::UnauthorizedEscrowAccess
+    )]
+    pub user_platform_escrow: Account<'info, UserPlatformEscrow>,
+}
+
+pub fn process_set_gaming_limits(ctx: Context<SetGamingLimits>, limits: GamingLimits) -> Result<()> {
+    msg!("--- Instruction: SetGamingLimits ---");
+    msg!("User: {}", ctx.accounts.user.key());
+    msg!("Requested limits: {:?}", limits);
+
+    let clock = Clock::get()?;
+    let escrow = &mut ctx.accounts.user_platform_escrow;
+    escrow.request_gaming_limits(limits, clock.unix_timestamp)?;
+
+    msg!("Limits in force: {:?}", escrow.limits);
+    if escrow.pending_limits_effective_at != 0 {
+        msg!(
+            "Loosened limits {:?} take effect at {}.",
+            escrow.pending_limits,
+            escrow.pending_limits_effective_at
+        );
+    }
+    msg!("--- SetGamingLimits finished ---");
+    Ok(())
+}
@@ -0,0 +1,60 @@
+use crate::{
+    ErrorCode, PlatformConfig, RakebackSchedule, RakebackTier, MAX_RAKEBACK_BASIS_POINTS,
+    MAX_RAKEBACK_TIERS,
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetRakebackSchedule<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"platform_config"],
+        bump,
+        constraint = platform_config.authority == authority.key() @ ErrorCode::UnauthorizedAccess,
+        constraint = platform_config.is_initialized @ ErrorCode::UnauthorizedAccess
+    )]
+    pub platform_config: Box<Account<'info, PlatformConfig>>,
+}
+
+pub fn process_set_rakeback_schedule(
+    ctx: Context<SetRakebackSchedule>,
+    base_basis_points: u16,
+    tiers: Vec<RakebackTier>,
+) -> Result<()> {
+    msg!("--- Instruction: SetRakebackSchedule ---");
+    msg!("Authority: {}", ctx.accounts.authority.key());
+    msg!("Base rakeback (bp of fee share): {}", base_basis_points);
+    msg!("Loyalty tiers: {:?}", tiers);
+
+    require!(
+        base_basis_points <= MAX_RAKEBACK_BASIS_POINTS,
+        ErrorCode::InvalidRakebackConfig
+    );
+    require!(tiers.len() <= MAX_RAKEBACK_TIERS, ErrorCode::InvalidRakebackConfig);
+    require!(
+        tiers
+            .iter()
+            .all(|tier| tier.rakeback_basis_points <= MAX_RAKEBACK_BASIS_POINTS),
+        ErrorCode::InvalidRakebackConfig
+    );
+    require!(
+        tiers
+            .windows(2)
+            .all(|pair| pair[0].min_total_wagered < pair[1].min_total_wagered),
+        ErrorCode::InvalidRakebackConfig
+    );
+
+    let mut schedule = RakebackSchedule {
+        base_basis_points,
+        tiers: [RakebackTier::default(); MAX_RAKEBACK_TIERS],
+        tier_count: tiers.len() as u8,
+    };
+    schedule.tiers[..tiers.len()].copy_from_slice(&tiers);
+    ctx.accounts.platform_config.rakeback = schedule;
+
+    msg!("Rakeback schedule updated. Highest rate: {} bp", schedule.max_rate());
+    msg!("--- SetRakebackSchedule finished ---");
+    Ok(())
+}
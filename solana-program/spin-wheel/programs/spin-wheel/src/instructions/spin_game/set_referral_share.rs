@@ -0,0 +1,37 @@
+use crate::{ErrorCode, PlatformConfig, MAX_REFERRAL_SHARE_BASIS_POINTS};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetReferralShare<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"platform_config"],
+        bump,
+        constraint = platform_config.authority == authority.key() @ ErrorCode::UnauthorizedAccess,
+        constraint = platform_config.is_initialized @ ErrorCode::UnauthorizedAccess
+    )]
+    pub platform_config: Box<Account<'info, PlatformConfig>>,
+}
+
+pub fn process_set_referral_share(
+    ctx: Context<SetReferralShare>,
+    referral_share_basis_points: u16,
+) -> Result<()> {
+    msg!("--- Instruction: SetReferralShare ---");
+    msg!("Authority: {}", ctx.accounts.authority.key());
+    msg!("New referral share (bp): {}", referral_share_basis_points);
+
+    require!(
+        referral_share_basis_points <= MAX_REFERRAL_SHARE_BASIS_POINTS,
+        ErrorCode::InvalidReferralShare
+    );
+
+    let platform_config = &mut ctx.accounts.platform_config;
+    platform_config.referral_share_basis_points = referral_share_basis_points;
+
+    msg!("Referral share set to {} bp.", platform_config.referral_share_basis_points);
+    msg!("--- SetReferralShare finished ---");
+    Ok(())
+}
@@ -0,0 +1,75 @@
+use crate::{ErrorCode, GameState, Leaderboard, LeaderboardMetric, PlatformConfig, TableLeaderboards};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(table_id: u64, wagered_leaderboard_id: Option<u64>, won_leaderboard_id: Option<u64>)]
+pub struct SetTableLeaderboards<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"platform_config"],
+        bump,
+        constraint = platform_config.authority == authority.key() @ ErrorCode::UnauthorizedAccess,
+    )]
+    pub platform_config: Box<Account<'info, PlatformConfig>>,
+
+    #[account(
+        mut,
+        seeds = [b"game_state".as_ref(), &table_id.to_le_bytes()],
+        bump,
+    )]
+    pub game_state: Box<Account<'info, GameState>>,
+
+    /// Required when `wagered_leaderboard_id` is set.
+    #[account(
+        seeds = [b"leaderboard".as_ref(), &wagered_leaderboard_id.unwrap_or_default().to_le_bytes()],
+        bump = wagered_leaderboard.bump,
+        constraint = wagered_leaderboard.metric == LeaderboardMetric::Wagered @ ErrorCode::InvalidLeaderboardConfig
+    )]
+    pub wagered_leaderboard: Option<Box<Account<'info, Leaderboard>>>,
+
+    /// Required when `won_leaderboard_id` is set.
+    #[account(
+        seeds = [b"leaderboard".as_ref(), &won_leaderboard_id.unwrap_or_default().to_le_bytes()],
+        bump = won_leaderboard.bump,
+        constraint = won_leaderboard.metric == LeaderboardMetric::Won @ ErrorCode::InvalidLeaderboardConfig
+    )]
+    pub won_leaderboard: Option<Box<Account<'info, Leaderboard>>>,
+}
+
+/// Binds the leaderboards the table's bets and wins count toward. `None` unbinds one.
+pub fn process_set_table_leaderboards(
+    ctx: Context<SetTableLeaderboards>,
+    table_id: u64,
+    wagered_leaderboard_id: Option<u64>,
+    won_leaderboard_id: Option<u64>,
+) -> Result<()> {
+    msg!("--- Instruction: SetTableLeaderboards ---");
+    msg!("Authority: {}", ctx.accounts.authority.key());
+    msg!("Table ID: {}", table_id);
+    msg!(
+        "Wagered leaderboard: {:?}, won leaderboard: {:?}",
+        wagered_leaderboard_id,
+        won_leaderboard_id
+    );
+
+    require!(
+        wagered_leaderboard_id.is_none() || ctx.accounts.wagered_leaderboard.is_some(),
+        ErrorCode::MissingLeaderboard
+    );
+    require!(
+        won_leaderboard_id.is_none() || ctx.accounts.won_leaderboard.is_some(),
+        ErrorCode::MissingLeaderboard
+    );
+
+    ctx.accounts.game_state.leaderboards = TableLeaderboards {
+        wagered_leaderboard_id: wagered_leaderboard_id.unwrap_or_default(),
+        won_leaderboard_id: won_leaderboard_id.unwrap_or_default(),
+        has_wagered_leaderboard: wagered_leaderboard_id.is_some(),
+        has_won_leaderboard: won_leaderboard_id.is_some(),
+    };
+
+    msg!("Leaderboards set for table {}.", table_id);
+    msg!("--- SetTableLeaderboards finished ---");
+    Ok(())
+}
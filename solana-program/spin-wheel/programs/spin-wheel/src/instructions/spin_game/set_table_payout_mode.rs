@@ -0,0 +1,69 @@
+use crate::{ErrorCode, GameState, PayoutMode, PlatformConfig, MAX_PAYOUT_TIERS};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(table_id: u64)]
+pub struct SetTablePayoutMode<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"platform_config"],
+        bump,
+        constraint = platform_config.authority == authority.key() @ ErrorCode::UnauthorizedAccess,
+    )]
+    pub platform_config: Box<Account<'info, PlatformConfig>>,
+
+    #[account(
+        mut,
+        seeds = [b"game_state".as_ref(), &table_id.to_le_bytes()],
+        bump,
+        constraint = !game_state.is_retired @ ErrorCode::TableRetired
+    )]
+    pub game_state: Box<Account<'info, GameState>>,
+}
+
+pub fn process_set_table_payout_mode(
+    ctx: Context<SetTablePayoutMode>,
+    table_id: u64,
+    payout_mode: PayoutMode,
+    payout_tiers: Vec<u16>,
+) -> Result<()> {
+    msg!("--- Instruction: SetTablePayoutMode ---");
+    msg!("Authority: {}", ctx.accounts.authority.key());
+    msg!("Table ID: {}", table_id);
+    msg!("Payout mode: {:?}, tiers (bp): {:?}", payout_mode, payout_tiers);
+
+    match payout_mode {
+        PayoutMode::SingleWinner => {
+            require!(payout_tiers.is_empty(), ErrorCode::InvalidPayoutTiers);
+        }
+        PayoutMode::Tiered => {
+            require!(
+                payout_tiers.len() >= 2 && payout_tiers.len() <= MAX_PAYOUT_TIERS,
+                ErrorCode::InvalidPayoutTiers
+            );
+            require!(
+                payout_tiers.iter().all(|tier| *tier > 0),
+                ErrorCode::InvalidPayoutTiers
+            );
+            let total_basis_points: u32 = payout_tiers.iter().map(|tier| *tier as u32).sum();
+            require!(total_basis_points == 10_000, ErrorCode::InvalidPayoutTiers);
+        }
+    }
+
+    // The mode is read at finalize, so it also applies to rounds that are already running.
+    let game_state = &mut ctx.accounts.game_state;
+    game_state.payout_mode = payout_mode;
+    game_state.payout_tiers = [0; MAX_PAYOUT_TIERS];
+    game_state.payout_tiers[..payout_tiers.len()].copy_from_slice(&payout_tiers);
+    game_state.payout_tier_count = payout_tiers.len() as u8;
+
+    msg!(
+        "Table {} payout mode set to {:?} with {} tier(s).",
+        game_state.table_id,
+        game_state.payout_mode,
+        game_state.payout_tier_count
+    );
+    msg!("--- SetTablePayoutMode finished ---");
+    Ok(())
+}
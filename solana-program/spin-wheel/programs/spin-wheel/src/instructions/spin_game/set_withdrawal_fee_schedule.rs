@@ -0,0 +1,91 @@
+use crate::{
+    ErrorCode, FeeDiscountTier, PlatformConfig, StakerPosition, WithdrawalFeeSchedule,
+    MAX_FEE_DISCOUNT_TIERS, MAX_WITHDRAWAL_FEE_BASIS_POINTS,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
+
+#[derive(Accounts)]
+pub struct SetWithdrawalFeeSchedule<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"platform_config"],
+        bump,
+        constraint = platform_config.authority == authority.key() @ ErrorCode::UnauthorizedAccess,
+        constraint = platform_config.is_initialized @ ErrorCode::UnauthorizedAccess
+    )]
+    pub platform_config: Box<Account<'info, PlatformConfig>>,
+}
+
+pub fn process_set_withdrawal_fee_schedule(
+    ctx: Context<SetWithdrawalFeeSchedule>,
+    min_fee_lamports: u64,
+    fee_basis_points: u16,
+    max_fee_lamports: u64,
+    discount_tiers: Vec<FeeDiscountTier>,
+    fee_free_start: i64,
+    fee_free_end: i64,
+) -> Result<()> {
+    msg!("--- Instruction: SetWithdrawalFeeSchedule ---");
+    msg!("Authority: {}", ctx.accounts.authority.key());
+    msg!(
+        "Min fee: {}, fee (bp): {}, max fee: {}",
+        min_fee_lamports,
+        fee_basis_points,
+        max_fee_lamports
+    );
+    msg!("Discount tiers: {:?}", discount_tiers);
+    msg!("Fee-free window: {} to {}", fee_free_start, fee_free_end);
+
+    require!(
+        fee_basis_points <= MAX_WITHDRAWAL_FEE_BASIS_POINTS,
+        ErrorCode::InvalidWithdrawalFeeSchedule
+    );
+    require!(
+        max_fee_lamports == 0 || max_fee_lamports >= min_fee_lamports,
+        ErrorCode::InvalidWithdrawalFeeSchedule
+    );
+    require!(
+        discount_tiers.len() <= MAX_FEE_DISCOUNT_TIERS,
+        ErrorCode::InvalidWithdrawalFeeSchedule
+    );
+    require!(
+        discount_tiers
+            .iter()
+            .all(|tier| tier.discount_basis_points <= 10_000),
+        ErrorCode::InvalidWithdrawalFeeSchedule
+    );
+    require!(
+        fee_free_end >= fee_free_start,
+        ErrorCode::InvalidWithdrawalFeeSchedule
+    );
+
+    let mut schedule = WithdrawalFeeSchedule {
+        min_fee_lamports,
+        fee_basis_points,
+        max_fee_lamports,
+        discount_tiers: [FeeDiscountTier::default(); MAX_FEE_DISCOUNT_TIERS],
+        discount_tier_count: discount_tiers.len() as u8,
+        fee_free_start,
+        fee_free_end,
+    };
+    schedule.discount_tiers[..discount_tiers.len()].copy_from_slice(&discount_tiers);
+    ctx.accounts.platform_config.withdrawal_fee = schedule;
+
+    msg!("Withdrawal fee schedule updated.");
+    msg!("--- SetWithdrawalFeeSchedule finished ---");
+    Ok(())
+}
+
+/// CASHINO counted towards fee discounts: the wallet balance of the token account passed in plus the staked amount.
+/// The callers' account constraints tie both accounts to the withdrawing user.
+pub(crate) fn cashino_holding_of(
+    cashino_token_account: Option<&TokenAccount>,
+    staker_position: Option<&StakerPosition>,
+) -> u64 {
+    let held = cashino_token_account.map_or(0, |account| account.amount);
+    let staked = staker_position.map_or(0, |position| position.staked_amount);
+    held.saturating_add(staked)
+}
@@ -0,0 +1,48 @@
+use crate::{ErrorCode, PlatformConfig, MAX_WITHDRAWAL_REVIEW_DELAY};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetWithdrawalReviewConfig<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"platform_config"],
+        bump,
+        constraint = platform_config.authority == authority.key() @ ErrorCode::UnauthorizedAccess,
+        constraint = platform_config.is_initialized @ ErrorCode::UnauthorizedAccess
+    )]
+    pub platform_config: Box<Account<'info, PlatformConfig>>,
+}
+
+pub fn process_set_withdrawal_review_config(
+    ctx: Context<SetWithdrawalReviewConfig>,
+    risk_admin: Pubkey,
+    withdrawal_review_threshold: u64,
+    withdrawal_review_delay: i64,
+) -> Result<()> {
+    msg!("--- Instruction: SetWithdrawalReviewConfig ---");
+    msg!("Authority: {}", ctx.accounts.authority.key());
+    msg!("Risk admin: {}", risk_admin);
+    msg!("Review threshold (lamports): {}", withdrawal_review_threshold);
+    msg!("Review delay (seconds): {}", withdrawal_review_delay);
+
+    require!(
+        (0..=MAX_WITHDRAWAL_REVIEW_DELAY).contains(&withdrawal_review_delay),
+        ErrorCode::InvalidWithdrawalReviewConfig
+    );
+    // Without a delay the risk admin could never block a reviewed withdrawal.
+    require!(
+        withdrawal_review_threshold == 0 || withdrawal_review_delay > 0,
+        ErrorCode::InvalidWithdrawalReviewConfig
+    );
+
+    let platform_config = &mut ctx.accounts.platform_config;
+    platform_config.risk_admin = risk_admin;
+    platform_config.withdrawal_review_threshold = withdrawal_review_threshold;
+    platform_config.withdrawal_review_delay = withdrawal_review_delay;
+
+    msg!("Withdrawal review config updated.");
+    msg!("--- SetWithdrawalReviewConfig finished ---");
+    Ok(())
+}
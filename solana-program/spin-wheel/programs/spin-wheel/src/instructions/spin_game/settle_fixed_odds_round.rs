@@ -0,0 +1,403 @@
+use crate::instructions::finalize_round::{draw_random_value, round_entropy};
+use crate::{
+    ErrorCode, GamePotSol, GameState, HouseBankroll, RoundState, RoundStatus, RoundWinner,
+    MAX_PLAYERS,
+};
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{clock::Clock, hash::hashv, sysvar::slot_hashes};
+
+const SLOT_HASH_ENTRY_LEN: usize = 8 + 32;
+
+#[derive(Accounts)]
+#[instruction(table_id: u64, round_id_for_pdas: u64)]
+pub struct SettleFixedOddsRound<'info> {
+    #[account(
+        seeds = [b"game_state".as_ref(), &table_id.to_le_bytes()],
+        bump,
+        constraint = game_state.is_initialized @ ErrorCode::UnauthorizedAccess
+    )]
+    pub game_state: Box<Account<'info, GameState>>,
+
+    #[account(
+        mut,
+        seeds = [b"round_state".as_ref(), &table_id.to_le_bytes(), &round_id_for_pdas.to_le_bytes()],
+        bump,
+        constraint = round_state.load()?.is_fixed_odds() @ ErrorCode::InvalidGameMode
+    )]
+    pub round_state: AccountLoader<'info, RoundState>,
+
+    #[account(
+        mut,
+        seeds = [b"sol_pot".as_ref(), &table_id.to_le_bytes(), &round_id_for_pdas.to_le_bytes()],
+        bump
+    )]
+    pub game_pot_sol: Account<'info, GamePotSol>,
+
+    #[account(
+        mut,
+        seeds = [b"house_bankroll"],
+        bump = house_bankroll.bump
+    )]
+    pub house_bankroll: Box<Account<'info, HouseBankroll>>,
+
+    /// CHECK: The SlotHashes sysvar. Read raw because it is too large to deserialize.
+    #[account(address = slot_hashes::ID)]
+    pub slot_hashes: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Hash of the first slot at or after `slot` in raw SlotHashes data, which lists the most recent
+/// slots newest first. `Ok(None)` once that slot has aged out of the sysvar.
+pub(crate) fn slot_hash_at_or_after(slot_hashes_data: &[u8], slot: u64) -> Result<Option<[u8; 32]>> {
+    let read_u64 = |offset: usize| -> Result<u64> {
+        let bytes = slot_hashes_data
+            .get(offset..offset + 8)
+            .ok_or(ErrorCode::GameCalculationError)?;
+        Ok(u64::from_le_bytes(
+            bytes.try_into().map_err(|_| ErrorCode::GameCalculationError)?,
+        ))
+    };
+
+    let entry_count = read_u64(0)? as usize;
+    let mut newer_hash: Option<[u8; 32]> = None;
+    for i in 0..entry_count {
+        let offset = 8 + i * SLOT_HASH_ENTRY_LEN;
+        let entry_slot = read_u64(offset)?;
+        if entry_slot < slot {
+            return match newer_hash {
+                Some(hash) => Ok(Some(hash)),
+                None => err!(ErrorCode::EntropyNotAvailable),
+            };
+        }
+        let hash_bytes = slot_hashes_data
+            .get(offset + 8..offset + SLOT_HASH_ENTRY_LEN)
+            .ok_or(ErrorCode::GameCalculationError)?;
+        newer_hash = Some(
+            hash_bytes
+                .try_into()
+                .map_err(|_| ErrorCode::GameCalculationError)?,
+        );
+        if entry_slot == slot {
+            return Ok(newer_hash);
+        }
+    }
+    if entry_count == 0 {
+        return err!(ErrorCode::EntropyNotAvailable);
+    }
+    Ok(None)
+}
+
+/// Spins the round's snapshotted wheel and returns the winning segment index.
+/// The outcome mixes the revealed seed and fixed round data with the hash of the round's
+/// entropy slot, which is only produced after betting has closed and the seed is revealed.
+fn spin_wheel_segment(round_state: &RoundState, slot_hash: &[u8; 32]) -> Result<u8> {
+    let round_bytes = round_entropy(round_state, round_state.end_time)?;
+    let combined_entropy = hashv(&[&round_bytes, slot_hash]).to_bytes();
+    let random_value = draw_random_value(&combined_entropy, b"wheel_segment")?;
+
+    let segments = &round_state.wheel_segments[..round_state.wheel_segment_count as usize];
+    let total_weight: u64 = segments.iter().map(|segment| segment.weight as u64).sum();
+    require!(total_weight > 0, ErrorCode::InvalidWheelSegment);
+
+    let scaled_random = random_value % total_weight;
+    let mut cumulative_weight: u64 = 0;
+    for (i, segment) in segments.iter().enumerate() {
+        cumulative_weight += segment.weight as u64;
+        if scaled_random < cumulative_weight {
+            return Ok(i as u8);
+        }
+    }
+    err!(ErrorCode::GameCalculationError)
+}
+
+/// Lamports to move between the round pot and the bankroll once a fixed-odds round is settled.
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) enum BankrollTransfer {
+    /// The payouts exceed the stakes and the bankroll covers the difference.
+    ToPot(u64),
+    /// The bankroll keeps the stakes that were not paid out.
+    ToBankroll(u64),
+}
+
+/// Pays every bettor on `winning_segment` at its multiplier, books the difference against the
+/// bankroll and releases the round's reserve. Moving the lamports is left to the caller.
+pub(crate) fn settle_fixed_odds_payouts(
+    round_rw: &mut RoundState,
+    house_bankroll: &mut HouseBankroll,
+    winning_segment: u8,
+) -> Result<BankrollTransfer> {
+    let multiplier = round_rw.wheel_segments[winning_segment as usize].multiplier_basis_points as u128;
+    round_rw.winners = [RoundWinner::default(); MAX_PLAYERS];
+    let mut winner_count: usize = 0;
+    for i in 0..(round_rw.player_count as usize) {
+        if round_rw.player_segments[i] != winning_segment {
+            continue;
+        }
+        let payout = u64::try_from((round_rw.players[i].amount as u128) * multiplier / 10_000)
+            .map_err(|_| ErrorCode::CalculationError)?;
+        round_rw.winners[winner_count] = RoundWinner {
+            pubkey: round_rw.players[i].pubkey,
+            amount: payout,
+            claimed: 0,
+            _padding_rw: [0; 7],
+        };
+        if winner_count == 0 {
+            round_rw.set_winner_index(Some(i as u8));
+        }
+        winner_count += 1;
+        msg!("  Winner {}: {} paid {}", winner_count, round_rw.players[i].pubkey, payout);
+    }
+    round_rw.winner_count = winner_count as u8;
+
+    let total_payout = round_rw.fixed_odds_payout_for(winning_segment)?;
+    let total_stakes = round_rw.total_sol_pot;
+    msg!("Total stakes: {}, total payout: {}", total_stakes, total_payout);
+
+    let transfer = if total_payout > total_stakes {
+        let shortfall = total_payout - total_stakes;
+        require!(
+            house_bankroll.balance >= shortfall,
+            ErrorCode::InsufficientBankrollLiquidity
+        );
+        house_bankroll.balance -= shortfall;
+        house_bankroll.total_paid_out = house_bankroll
+            .total_paid_out
+            .checked_add(shortfall)
+            .ok_or(ErrorCode::CalculationError)?;
+        BankrollTransfer::ToPot(shortfall)
+    } else {
+        let surplus = total_stakes - total_payout;
+        house_bankroll.balance = house_bankroll
+            .balance
+            .checked_add(surplus)
+            .ok_or(ErrorCode::CalculationError)?;
+        BankrollTransfer::ToBankroll(surplus)
+    };
+
+    house_bankroll.reserved_exposure = house_bankroll
+        .reserved_exposure
+        .checked_sub(round_rw.bankroll_reserved)
+        .ok_or(ErrorCode::CalculationError)?;
+    round_rw.bankroll_reserved = 0;
+
+    // The house edge is built into the odds, so fixed-odds rounds take no house fee.
+    round_rw.house_sol_fee = 0;
+    round_rw.winner_sol_pubkey = round_rw.winners[0].pubkey;
+    round_rw.winner_sol_amount = round_rw.winners[0].amount;
+    round_rw.winner_sol_claimed = 0;
+    if winner_count > 0 {
+        round_rw.set_status(RoundStatus::AwaitingSolClaim);
+        msg!("Round status updated to AwaitingSolClaim with {} winner(s).", winner_count);
+    } else {
+        round_rw.set_status(RoundStatus::SolClaimed);
+        msg!("No winning bets. Round status updated to SolClaimed.");
+    }
+    Ok(transfer)
+}
+
+/// Permissionless second half of a fixed-odds settlement, run after reveal_fixed_odds_seed.
+/// If nobody settles before the entropy slot ages out of SlotHashes (about 512 slots), the call
+/// records a fresh slot instead and the round is settled by a later call.
+pub fn process_settle_fixed_odds_round(
+    ctx: Context<SettleFixedOddsRound>,
+    table_id: u64,
+    round_id_for_pdas: u64,
+) -> Result<()> {
+    msg!("--- Instruction: SettleFixedOddsRound ---");
+    msg!("Table ID: {}", table_id);
+    msg!("Round ID for PDAs: {}", round_id_for_pdas);
+
+    let clock = Clock::get()?;
+
+    let mut round_rw = ctx.accounts.round_state.load_mut()?;
+    require!(
+        round_rw.status_discriminant == RoundStatus::Active as u8,
+        ErrorCode::RoundNotActive
+    );
+    require!(
+        round_rw.get_revealed_seed().is_some(),
+        ErrorCode::InvalidRevealedSeed
+    );
+
+    let slot_hash = {
+        let slot_hashes_data = ctx.accounts.slot_hashes.try_borrow_data()?;
+        slot_hash_at_or_after(&slot_hashes_data, round_rw.entropy_slot)?
+    };
+    let Some(slot_hash) = slot_hash else {
+        msg!(
+            "Slot {} is no longer in SlotHashes. The spin will use slot {} instead.",
+            round_rw.entropy_slot,
+            clock.slot
+        );
+        round_rw.entropy_slot = clock.slot;
+        msg!("--- SettleFixedOddsRound finished ---");
+        return Ok(());
+    };
+    msg!("Entropy slot: {}", round_rw.entropy_slot);
+
+    let winning_segment = spin_wheel_segment(&round_rw, &slot_hash)?;
+    round_rw.winning_segment_val = winning_segment;
+    round_rw.has_winning_segment_val = 1;
+    msg!(
+        "Wheel landed on segment {} ({} bp).",
+        winning_segment,
+        round_rw.wheel_segments[winning_segment as usize].multiplier_basis_points
+    );
+
+    // The pot already holds the stakes; the difference is settled against the bankroll.
+    let game_pot_account_info = ctx.accounts.game_pot_sol.to_account_info();
+    let bankroll_account_info = ctx.accounts.house_bankroll.to_account_info();
+    match settle_fixed_odds_payouts(&mut round_rw, &mut ctx.accounts.house_bankroll, winning_segment)? {
+        BankrollTransfer::ToPot(shortfall) => {
+            **bankroll_account_info.try_borrow_mut_lamports()? -= shortfall;
+            **game_pot_account_info.try_borrow_mut_lamports()? += shortfall;
+            msg!("Bankroll paid {} lamports into the round pot.", shortfall);
+        }
+        BankrollTransfer::ToBankroll(surplus) => {
+            **game_pot_account_info.try_borrow_mut_lamports()? -= surplus;
+            **bankroll_account_info.try_borrow_mut_lamports()? += surplus;
+            msg!("Bankroll collected {} lamports from the round pot.", surplus);
+        }
+    }
+    msg!(
+        "Bankroll balance: {}, reserved: {}",
+        ctx.accounts.house_bankroll.balance,
+        ctx.accounts.house_bankroll.reserved_exposure
+    );
+
+    msg!("--- SettleFixedOddsRound finished ---");
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::WheelSegment;
+    use bytemuck::Zeroable;
+    use std::collections::BTreeSet;
+
+    fn slot_hashes_data(slots: &[u64]) -> Vec<u8> {
+        let mut data = (slots.len() as u64).to_le_bytes().to_vec();
+        for &slot in slots {
+            data.extend_from_slice(&slot.to_le_bytes());
+            data.extend_from_slice(&[slot as u8; 32]);
+        }
+        data
+    }
+
+    fn revealed_fixed_odds_round() -> RoundState {
+        let mut round = RoundState::zeroed();
+        round.id = 7;
+        round.end_time = 1_000;
+        round.total_sol_pot = 300;
+        round.player_count = 2;
+        let even_money = WheelSegment {
+            multiplier_basis_points: 20_000,
+            weight: 1,
+            ..Default::default()
+        };
+        round.set_fixed_odds_wheel(&[even_money, even_money]);
+        round.set_revealed_seed(Some([7; 32]));
+        round
+    }
+
+    #[test]
+    fn slot_hash_lookup_takes_the_first_slot_at_or_after_the_target() {
+        // Slot 104 was skipped.
+        let data = slot_hashes_data(&[105, 103, 100]);
+        assert_eq!(slot_hash_at_or_after(&data, 105).unwrap(), Some([105; 32]));
+        assert_eq!(slot_hash_at_or_after(&data, 104).unwrap(), Some([105; 32]));
+        assert_eq!(slot_hash_at_or_after(&data, 101).unwrap(), Some([103; 32]));
+        assert_eq!(slot_hash_at_or_after(&data, 100).unwrap(), Some([100; 32]));
+    }
+
+    #[test]
+    fn slot_hash_lookup_tells_pending_from_expired() {
+        let data = slot_hashes_data(&[105, 103, 100]);
+        assert_eq!(
+            slot_hash_at_or_after(&data, 106).unwrap_err(),
+            error!(ErrorCode::EntropyNotAvailable)
+        );
+        assert_eq!(slot_hash_at_or_after(&data, 99).unwrap(), None);
+    }
+
+    #[test]
+    fn segment_cannot_be_computed_before_the_entropy_slot_exists() {
+        // Everything a bettor can see before betting closes, and even the seed, is fixed here;
+        // only the hash of the entropy slot varies.
+        let round = revealed_fixed_odds_round();
+        let segments: BTreeSet<u8> = (0..16u8)
+            .map(|i| spin_wheel_segment(&round, &[i; 32]).unwrap())
+            .collect();
+        assert_eq!(segments.len(), 2);
+    }
+
+    #[test]
+    fn segment_needs_the_revealed_seed() {
+        let mut round = revealed_fixed_odds_round();
+        round.set_revealed_seed(None);
+        assert_eq!(
+            spin_wheel_segment(&round, &[0; 32]).unwrap_err(),
+            error!(ErrorCode::InvalidRevealedSeed)
+        );
+    }
+
+    /// Two players staking 100 and 200 on `segments` of a 2x wheel, with the worst case reserved
+    /// on the bankroll.
+    fn settled_bets(segments: [u8; 2]) -> (RoundState, HouseBankroll) {
+        let mut round = revealed_fixed_odds_round();
+        round.total_sol_pot = 0;
+        round.player_count = 0;
+        round.record_fixed_odds_bet(Pubkey::new_unique(), 100, segments[0]).unwrap();
+        round.record_fixed_odds_bet(Pubkey::new_unique(), 200, segments[1]).unwrap();
+        round.bankroll_reserved = round.fixed_odds_required_reserve().unwrap();
+        let bankroll = HouseBankroll {
+            balance: 1_000,
+            reserved_exposure: round.bankroll_reserved,
+            ..Default::default()
+        };
+        (round, bankroll)
+    }
+
+    #[test]
+    fn bankroll_covers_winnings_beyond_the_stakes() {
+        let (mut round, mut bankroll) = settled_bets([0, 1]);
+        assert_eq!(round.bankroll_reserved, 100);
+
+        let transfer = settle_fixed_odds_payouts(&mut round, &mut bankroll, 1).unwrap();
+        assert_eq!(transfer, BankrollTransfer::ToPot(100));
+        assert_eq!((round.winner_count, round.winners[0].amount), (1, 400));
+        assert_eq!((bankroll.balance, bankroll.total_paid_out), (900, 100));
+        assert_eq!((bankroll.reserved_exposure, round.bankroll_reserved), (0, 0));
+        assert_eq!(round.get_status().unwrap(), RoundStatus::AwaitingSolClaim);
+    }
+
+    #[test]
+    fn bankroll_keeps_the_stakes_it_does_not_pay_out() {
+        let (mut round, mut bankroll) = settled_bets([0, 1]);
+        let transfer = settle_fixed_odds_payouts(&mut round, &mut bankroll, 0).unwrap();
+        assert_eq!(transfer, BankrollTransfer::ToBankroll(100));
+        assert_eq!(bankroll.balance, 1_100);
+        assert_eq!(bankroll.reserved_exposure, 0);
+    }
+
+    #[test]
+    fn a_round_nobody_wins_closes_with_all_stakes_to_the_bankroll() {
+        let (mut round, mut bankroll) = settled_bets([0, 0]);
+        let transfer = settle_fixed_odds_payouts(&mut round, &mut bankroll, 1).unwrap();
+        assert_eq!(transfer, BankrollTransfer::ToBankroll(300));
+        assert_eq!(round.winner_count, 0);
+        assert_eq!(round.get_status().unwrap(), RoundStatus::SolClaimed);
+    }
+
+    #[test]
+    fn payouts_cannot_exceed_the_bankroll() {
+        let (mut round, mut bankroll) = settled_bets([0, 1]);
+        bankroll.balance = 99;
+        assert_eq!(
+            settle_fixed_odds_payouts(&mut round, &mut bankroll, 1).unwrap_err(),
+            error!(ErrorCode::InsufficientBankrollLiquidity)
+        );
+    }
+}
@@ -1,22 +1,23 @@
 use crate::{
-    ErrorCode, GamePotSol, GameState, PlayerData, RoundState, RoundStatus, SeedArray, MAX_PLAYERS,
-    MAX_ROUND_DURATION, MIN_ROUND_DURATION, SEED_BYTES_LENGTH, PlayerCashinoRewards
+    ErrorCode, GameMode, GamePotSol, GameState, PlayerData, RoundState, RoundStatus, SeedArray, MAX_PLAYERS,
+    SEED_BYTES_LENGTH, PlayerCashinoRewards
 };
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::clock::Clock;
 
 #[derive(Accounts)]
-#[instruction(seed_commitment: SeedArray, round_duration: i64, round_id_for_seed: u64)]
+#[instruction(table_id: u64, seed_commitment: SeedArray, round_id_for_seed: u64)]
 pub struct StartNewRound<'info> {
     #[account(mut)]
     pub authority: Signer<'info>,
 
     #[account(
         mut,
-        seeds = [b"game_state"],
+        seeds = [b"game_state".as_ref(), &table_id.to_le_bytes()],
         bump,
         constraint = game_state.authority == authority.key() @ErrorCode::UnauthorizedAccess,
         constraint = game_state.is_initialized @ErrorCode::UnauthorizedAccess,
+        constraint = !game_state.is_retired @ErrorCode::TableRetired,
         constraint = game_state.round_counter == round_id_for_seed @ErrorCode::InvalidRoundIdForSeed
     )]
     pub game_state: Box<Account<'info, GameState>>,
@@ -25,7 +26,7 @@ pub struct StartNewRound<'info> {
         init,
         payer = authority,
         space = 8 + std::mem::size_of::<RoundState>(),
-        seeds = [b"round_state".as_ref(), &round_id_for_seed.to_le_bytes().as_ref()],
+        seeds = [b"round_state".as_ref(), &table_id.to_le_bytes(), &round_id_for_seed.to_le_bytes().as_ref()],
         bump
     )]
     pub round_state: AccountLoader<'info, RoundState>,
@@ -36,7 +37,7 @@ pub struct StartNewRound<'info> {
         init,
         payer = authority,
         space = 8,
-        seeds = [b"sol_pot".as_ref(), &round_id_for_seed.to_le_bytes().as_ref()],
+        seeds = [b"sol_pot".as_ref(), &table_id.to_le_bytes(), &round_id_for_seed.to_le_bytes().as_ref()],
         bump
     )]
     pub game_pot: Box<Account<'info, GamePotSol>>,
@@ -44,12 +45,13 @@ pub struct StartNewRound<'info> {
 
 pub fn process_start_new_round(
     ctx: Context<StartNewRound>,
+    table_id: u64,
     seed_commitment: SeedArray,
-    round_duration: i64,
     round_id_for_seed: u64,
 ) -> Result<()> {
     msg!("--- Instruction: StartNewRound ---");
     msg!("Authority: {}", ctx.accounts.authority.key());
+    msg!("Table ID: {}", table_id);
     msg!("Game State PDA: {}", ctx.accounts.game_state.key());
     msg!(
         "RoundState PDA to be initialized at: {}",
@@ -63,7 +65,6 @@ pub fn process_start_new_round(
         "Seed Commitment: {:?}",
         &seed_commitment
     );
-    msg!("Requested Round Duration: {} seconds", round_duration);
     msg!(
         "Round ID for Seed (from client, current game_state.round_counter): {}",
         round_id_for_seed
@@ -73,11 +74,9 @@ pub fn process_start_new_round(
     let round_state = &mut ctx.accounts.round_state.load_init()?;
     let clock = Clock::get()?;
 
-    require!(
-        round_duration >= MIN_ROUND_DURATION && round_duration <= MAX_ROUND_DURATION,
-        ErrorCode::InvalidTimeParameters
-    );
-    msg!("Round duration validated ({} seconds).", round_duration);
+    // Duration was validated against MIN/MAX_ROUND_DURATION when the table was created.
+    let round_duration = game_state.round_duration;
+    msg!("Table round duration: {} seconds", round_duration);
 
     game_state.round_counter += 1;
     let new_round_id_for_state_struct = game_state.round_counter;
@@ -94,6 +93,7 @@ pub fn process_start_new_round(
     );
 
     round_state.id = new_round_id_for_state_struct;
+    round_state.table_id = table_id;
     round_state.start_time = clock.unix_timestamp;
     round_state.end_time = clock.unix_timestamp + round_duration;
     round_state.seed_commitment = seed_commitment;
@@ -110,9 +110,14 @@ pub fn process_start_new_round(
     round_state.has_winner_val = 0;
     round_state.winner_index_val = 0;
     round_state.house_sol_fee = 0;
+    if game_state.game_mode == GameMode::FixedOdds {
+        round_state.set_fixed_odds_wheel(game_state.active_wheel_segments());
+        msg!("  Fixed-odds wheel snapshotted with {} segment(s).", round_state.wheel_segment_count);
+    }
 
     msg!("New RoundState PDA data initialized:");
     msg!("  Round ID in state: {}", round_state.id);
+    msg!("  Table ID in state: {}", round_state.table_id);
     msg!("  Start Time: {}", round_state.start_time);
     msg!("  End Time: {}", round_state.end_time);
     msg!("  Is Active: {:?}", round_state.status_discriminant);
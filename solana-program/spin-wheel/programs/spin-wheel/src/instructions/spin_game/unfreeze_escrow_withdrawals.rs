@@ -0,0 +1,38 @@
+use crate::{ErrorCode, PlatformConfig, UserPlatformEscrow};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(user: Pubkey)]
+pub struct UnfreezeEscrowWithdrawals<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"platform_config"],
+        bump,
+        constraint = platform_config.authority == authority.key() @ ErrorCode::UnauthorizedAccess,
+    )]
+    pub platform_config: Box<Account<'info, PlatformConfig>>,
+
+    #[account(
+        mut,
+        seeds = [b"user_escrow", user.as_ref()],
+        bump = user_platform_escrow.bump
+    )]
+    pub user_platform_escrow: Account<'info, UserPlatformEscrow>,
+}
+
+/// Clears the freeze a blocked withdrawal put on the user's escrow.
+pub fn process_unfreeze_escrow_withdrawals(
+    ctx: Context<UnfreezeEscrowWithdrawals>,
+    user: Pubkey,
+) -> Result<()> {
+    msg!("--- Instruction: UnfreezeEscrowWithdrawals ---");
+    msg!("Authority: {}", ctx.accounts.authority.key());
+    msg!("User: {}", user);
+
+    ctx.accounts.user_platform_escrow.withdrawals_frozen = false;
+
+    msg!("Escrow withdrawals unfrozen.");
+    msg!("--- UnfreezeEscrowWithdrawals finished ---");
+    Ok(())
+}
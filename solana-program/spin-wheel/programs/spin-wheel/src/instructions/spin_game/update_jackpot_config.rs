@@ -0,0 +1,37 @@
+use crate::instructions::initialize_jackpot::validate_jackpot_config;
+use crate::{ErrorCode, JackpotPool};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct UpdateJackpotConfig<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"jackpot"],
+        bump = jackpot_pool.bump,
+        constraint = jackpot_pool.authority == authority.key() @ ErrorCode::UnauthorizedAccess
+    )]
+    pub jackpot_pool: Box<Account<'info, JackpotPool>>,
+}
+
+pub fn process_update_jackpot_config(
+    ctx: Context<UpdateJackpotConfig>,
+    contribution_basis_points: u16,
+    odds: u64,
+) -> Result<()> {
+    msg!("--- Instruction: UpdateJackpotConfig ---");
+    msg!("Authority: {}", ctx.accounts.authority.key());
+    msg!("New contribution (bp of total pot): {}", contribution_basis_points);
+    msg!("New odds: 1 in {}", odds);
+
+    validate_jackpot_config(contribution_basis_points, odds)?;
+
+    let jackpot_pool = &mut ctx.accounts.jackpot_pool;
+    jackpot_pool.contribution_basis_points = contribution_basis_points;
+    jackpot_pool.odds = odds;
+
+    msg!("Jackpot config updated. Current balance: {}", jackpot_pool.balance);
+    msg!("--- UpdateJackpotConfig finished ---");
+    Ok(())
+}
@@ -1,6 +1,9 @@
 use anchor_lang::prelude::*;
-use anchor_lang::solana_program::rent::Rent;
-use crate::{UserPlatformEscrow, GameState, ErrorCode, WITHDRAWAL_FEE_LAMPORTS};
+use anchor_spl::token_interface::TokenAccount;
+use crate::accounting;
+use crate::instructions::set_fee_split::distribute_house_fee;
+use crate::instructions::set_withdrawal_fee_schedule::cashino_holding_of;
+use crate::{UserPlatformEscrow, PlatformConfig, StakerPosition, ErrorCode};
 
 #[derive(Accounts)]
 #[instruction(amount_to_withdraw: u64)]
@@ -16,21 +19,35 @@ pub struct WithdrawSolFromPlatform<'info> {
     )]
     pub user_platform_escrow: Account<'info, UserPlatformEscrow>,
 
-    #[account(seeds = [b"game_state"], bump)]
-    pub game_state: Box<Account<'info, GameState>>,
+    #[account(seeds = [b"platform_config"], bump)]
+    pub platform_config: Box<Account<'info, PlatformConfig>>,
 
-    /// CHECK: This is the house_wallet Pubkey stored in game_state.
+    /// CHECK: This is the house_wallet Pubkey stored in platform_config.
     #[account(
         mut,
-        address = game_state.house_wallet @ ErrorCode::InvalidHouseWalletAddress
+        address = platform_config.house_wallet @ ErrorCode::InvalidHouseWalletAddress
     )]
     pub house_wallet: AccountInfo<'info>,
 
+    /// Optional. The user's CASHINO token account, counted towards withdrawal fee discounts.
+    #[account(
+        constraint = cashino_token_account.owner == user.key() @ ErrorCode::UnauthorizedAccess,
+        constraint = cashino_token_account.mint == platform_config.cashino_mint @ ErrorCode::InvalidMintAccount
+    )]
+    pub cashino_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    /// Optional. The user's staking position, counted towards withdrawal fee discounts.
+    #[account(
+        seeds = [b"staker", user.key().as_ref()],
+        bump = staker_position.bump
+    )]
+    pub staker_position: Option<Box<Account<'info, StakerPosition>>>,
+
     pub system_program: Program<'info, System>,
 }
 
-pub fn process_withdraw_sol_from_platform(
-    ctx: Context<WithdrawSolFromPlatform>,
+pub fn process_withdraw_sol_from_platform<'info>(
+    ctx: Context<'_, '_, 'info, 'info, WithdrawSolFromPlatform<'info>>,
     amount_to_withdraw: u64,
 ) -> Result<()> {
     msg!("--- Instruction: WithdrawSolFromPlatform ---");
@@ -42,9 +59,35 @@ pub fn process_withdraw_sol_from_platform(
         return err!(ErrorCode::InvalidWithdrawalAmount);
     }
 
+    // Instant withdrawals are counted over a rolling window, so splitting a large withdrawal
+    // into smaller ones still hits the review threshold.
+    let clock = Clock::get()?;
+    let review_threshold = ctx.accounts.platform_config.withdrawal_review_threshold;
+    ctx.accounts.user_platform_escrow.record_instant_withdrawal(
+        amount_to_withdraw,
+        review_threshold,
+        clock.unix_timestamp,
+    )?;
+    msg!(
+        "Withdrawn in the current window: {} (review threshold {})",
+        ctx.accounts.user_platform_escrow.window_withdrawn,
+        review_threshold
+    );
+
+    let cashino_holding = cashino_holding_of(
+        ctx.accounts.cashino_token_account.as_deref().map(|account| &**account),
+        ctx.accounts.staker_position.as_deref().map(|position| &**position),
+    );
+    let withdrawal_fee = ctx.accounts.platform_config.withdrawal_fee.fee_for(
+        amount_to_withdraw,
+        cashino_holding,
+        clock.unix_timestamp,
+    )?;
+    msg!("CASHINO holding: {}, withdrawal fee: {}", cashino_holding, withdrawal_fee);
+
     let escrow_account_data = &mut ctx.accounts.user_platform_escrow;
     let total_debit_from_escrow_balance_field = amount_to_withdraw
-        .checked_add(WITHDRAWAL_FEE_LAMPORTS)
+        .checked_add(withdrawal_fee)
         .ok_or(ErrorCode::CalculationError)?;
 
     if escrow_account_data.balance < total_debit_from_escrow_balance_field {
@@ -56,38 +99,24 @@ pub fn process_withdraw_sol_from_platform(
     let escrow_account_info = escrow_account_data.to_account_info();
     let user_account_info = ctx.accounts.user.to_account_info();
     let house_wallet_account_info = ctx.accounts.house_wallet.to_account_info();
-
-    let escrow_initial_lamports = escrow_account_info.lamports();
-    msg!("Escrow PDA initial lamports: {}", escrow_initial_lamports);
-
-    let rent_for_escrow_pda = Rent::get()?.minimum_balance(8 + std::mem::size_of::<UserPlatformEscrow>());
-    msg!("Rent exempt minimum for escrow PDA: {}", rent_for_escrow_pda);
-
-    if escrow_initial_lamports < total_debit_from_escrow_balance_field.checked_add(rent_for_escrow_pda).ok_or(ErrorCode::CalculationError)? &&
-        escrow_account_data.balance == total_debit_from_escrow_balance_field {
-        require!(
-            escrow_initial_lamports.checked_sub(total_debit_from_escrow_balance_field).unwrap_or(0) >= rent_for_escrow_pda,
-            ErrorCode::WithdrawWouldMakeEscrowRentDeficient
-        );
-        msg!("Rent check passed: Escrow will have {} lamports after debit, rent needed is {}.",
-            escrow_initial_lamports.checked_sub(total_debit_from_escrow_balance_field).unwrap_or(0),
-            rent_for_escrow_pda);
-    }
-
-    if WITHDRAWAL_FEE_LAMPORTS > 0 {
-        **escrow_account_info.try_borrow_mut_lamports()? -= WITHDRAWAL_FEE_LAMPORTS;
-        **house_wallet_account_info.try_borrow_mut_lamports()? += WITHDRAWAL_FEE_LAMPORTS;
-        msg!("Transferred withdrawal fee of {} to house wallet {}.", WITHDRAWAL_FEE_LAMPORTS, house_wallet_account_info.key());
+    msg!("Escrow PDA initial lamports: {}", escrow_account_info.lamports());
+
+    // The accounting helpers keep the escrow's lamports covering its balance plus rent after each step.
+    if withdrawal_fee > 0 {
+        distribute_house_fee(
+            &ctx.accounts.platform_config,
+            &escrow_account_info,
+            &house_wallet_account_info,
+            ctx.remaining_accounts,
+            withdrawal_fee,
+        )?;
+        accounting::record_escrow_debit(escrow_account_data, withdrawal_fee)?;
+        msg!("Withdrawal fee of {} distributed.", withdrawal_fee);
     }
 
-    **escrow_account_info.try_borrow_mut_lamports()? -= amount_to_withdraw;
-    **user_account_info.try_borrow_mut_lamports()? += amount_to_withdraw;
+    accounting::transfer_from_escrow(escrow_account_data, &user_account_info, amount_to_withdraw)?;
     msg!("Transferred withdrawal amount of {} to user {}.", amount_to_withdraw, user_account_info.key());
 
-    escrow_account_data.balance = escrow_account_data.balance
-        .checked_sub(total_debit_from_escrow_balance_field)
-        .ok_or(ErrorCode::CalculationError)?;
-
     msg!("Escrow data balance updated to: {}. Escrow PDA lamports now: {}", escrow_account_data.balance, escrow_account_info.lamports());
     msg!("--- WithdrawSolFromPlatform finished for user {} ---", ctx.accounts.user.key());
     Ok(())
@@ -0,0 +1,113 @@
+use crate::token_transfer::transfer_checked_with_hook;
+use crate::{ErrorCode, UserTokenEscrow};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface, TransferChecked};
+
+#[derive(Accounts)]
+#[instruction(amount_to_withdraw: u64)]
+pub struct WithdrawTokenFromPlatform<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    pub bet_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        seeds = [b"user_token_escrow", bet_mint.key().as_ref(), user.key().as_ref()],
+        bump = user_token_escrow.bump,
+        constraint = user_token_escrow.user_authority == user.key() @ ErrorCode::UnauthorizedEscrowAccess,
+        constraint = user_token_escrow.mint == bet_mint.key() @ ErrorCode::InvalidEscrowMint
+    )]
+    pub user_token_escrow: Box<Account<'info, UserTokenEscrow>>,
+
+    #[account(
+        mut,
+        associated_token::mint = bet_mint,
+        associated_token::authority = user_token_escrow,
+        associated_token::token_program = token_program
+    )]
+    pub escrow_token_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = bet_mint,
+        token::authority = user,
+        token::token_program = token_program
+    )]
+    pub user_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn process_withdraw_token_from_platform<'info>(
+    ctx: Context<'_, '_, 'info, 'info, WithdrawTokenFromPlatform<'info>>,
+    amount_to_withdraw: u64,
+) -> Result<()> {
+    msg!("--- Instruction: WithdrawTokenFromPlatform ---");
+    msg!(
+        "User {} attempting to withdraw {} base units of mint {}.",
+        ctx.accounts.user.key(),
+        amount_to_withdraw,
+        ctx.accounts.bet_mint.key()
+    );
+    msg!(
+        "Token escrow account: {}, Current escrow data balance: {}",
+        ctx.accounts.user_token_escrow.key(),
+        ctx.accounts.user_token_escrow.balance
+    );
+
+    if amount_to_withdraw == 0 {
+        msg!("Withdrawal amount is 0. No tokens transferred.");
+        return err!(ErrorCode::InvalidWithdrawalAmount);
+    }
+
+    if ctx.accounts.user_token_escrow.balance < amount_to_withdraw {
+        msg!(
+            "Error: Insufficient platform balance. Has {}, needs {}.",
+            ctx.accounts.user_token_escrow.balance,
+            amount_to_withdraw
+        );
+        return err!(ErrorCode::InsufficientPlatformBalance);
+    }
+
+    let bet_mint_key = ctx.accounts.bet_mint.key();
+    let user_key = ctx.accounts.user.key();
+    let escrow_signer_seeds: &[&[u8]] = &[
+        b"user_token_escrow".as_ref(),
+        bet_mint_key.as_ref(),
+        user_key.as_ref(),
+        &[ctx.accounts.user_token_escrow.bump],
+    ];
+    let all_escrow_signer_seeds = &[escrow_signer_seeds][..];
+
+    transfer_checked_with_hook(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.escrow_token_vault.to_account_info(),
+                to: ctx.accounts.user_token_account.to_account_info(),
+                authority: ctx.accounts.user_token_escrow.to_account_info(),
+                mint: ctx.accounts.bet_mint.to_account_info(),
+            },
+            all_escrow_signer_seeds,
+        )
+        .with_remaining_accounts(ctx.remaining_accounts.to_vec()),
+        amount_to_withdraw,
+        ctx.accounts.bet_mint.decimals,
+    )?;
+    msg!(
+        "Transferred {} base units from escrow vault to user token account {}.",
+        amount_to_withdraw,
+        ctx.accounts.user_token_account.key()
+    );
+
+    let escrow_account_data = &mut ctx.accounts.user_token_escrow;
+    escrow_account_data.balance = escrow_account_data
+        .balance
+        .checked_sub(amount_to_withdraw)
+        .ok_or(ErrorCode::CalculationError)?;
+
+    msg!("Token escrow data balance updated to: {}", escrow_account_data.balance);
+    msg!("--- WithdrawTokenFromPlatform finished for user {} ---", ctx.accounts.user.key());
+    Ok(())
+}
@@ -0,0 +1,63 @@
+use crate::{ErrorCode, StakerPosition, StakingPool};
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::rent::Rent;
+
+#[derive(Accounts)]
+pub struct ClaimStakingYield<'info> {
+    #[account(mut)]
+    pub staker: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"staking_pool"],
+        bump = staking_pool.bump
+    )]
+    pub staking_pool: Box<Account<'info, StakingPool>>,
+
+    #[account(
+        mut,
+        seeds = [b"staker", staker.key().as_ref()],
+        bump = staker_position.bump,
+        constraint = staker_position.owner == staker.key() @ ErrorCode::UnauthorizedAccess
+    )]
+    pub staker_position: Box<Account<'info, StakerPosition>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn process_claim_staking_yield(ctx: Context<ClaimStakingYield>) -> Result<()> {
+    msg!("--- Instruction: ClaimStakingYield ---");
+    msg!("Staker: {}", ctx.accounts.staker.key());
+
+    {
+        let staking_pool = &ctx.accounts.staking_pool;
+        let position = &mut ctx.accounts.staker_position;
+        position.settle_yield(staking_pool)?;
+    }
+
+    let yield_amount = ctx.accounts.staker_position.pending_sol;
+    msg!("SOL yield available: {}", yield_amount);
+    require!(yield_amount > 0, ErrorCode::NoStakingYield);
+
+    let pool_account_info = ctx.accounts.staking_pool.to_account_info();
+    let staker_account_info = ctx.accounts.staker.to_account_info();
+
+    let rent_for_pool = Rent::get()?.minimum_balance(pool_account_info.data_len());
+    require!(
+        pool_account_info.lamports().saturating_sub(yield_amount) >= rent_for_pool,
+        ErrorCode::InsufficientFunds
+    );
+
+    **pool_account_info.try_borrow_mut_lamports()? -= yield_amount;
+    **staker_account_info.try_borrow_mut_lamports()? += yield_amount;
+
+    ctx.accounts.staker_position.pending_sol = 0;
+
+    msg!(
+        "Transferred {} lamports of staking yield from StakingPool to {}.",
+        yield_amount,
+        ctx.accounts.staker.key()
+    );
+    msg!("--- ClaimStakingYield finished ---");
+    Ok(())
+}
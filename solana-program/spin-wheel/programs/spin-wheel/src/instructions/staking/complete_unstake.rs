@@ -0,0 +1,95 @@
+use crate::{ErrorCode, StakerPosition, StakingPool};
+use crate::token_transfer::transfer_checked_with_hook;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::clock::Clock;
+use anchor_spl::token_interface::{spl_token_2022, Mint, Token2022, TokenAccount, TransferChecked};
+
+#[derive(Accounts)]
+pub struct CompleteUnstake<'info> {
+    #[account(mut)]
+    pub staker: Signer<'info>,
+
+    #[account(
+        seeds = [b"staking_pool"],
+        bump = staking_pool.bump
+    )]
+    pub staking_pool: Box<Account<'info, StakingPool>>,
+
+    #[account(
+        mut,
+        seeds = [b"staker", staker.key().as_ref()],
+        bump = staker_position.bump,
+        constraint = staker_position.owner == staker.key() @ ErrorCode::UnauthorizedAccess
+    )]
+    pub staker_position: Box<Account<'info, StakerPosition>>,
+
+    #[account(address = staking_pool.cashino_mint @ ErrorCode::InvalidMintAccount)]
+    pub cashino_token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        token::mint = cashino_token_mint,
+        token::authority = staker,
+        token::token_program = token_program
+    )]
+    pub staker_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        associated_token::mint = cashino_token_mint,
+        associated_token::authority = staking_pool,
+        associated_token::token_program = token_program
+    )]
+    pub stake_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(address = spl_token_2022::ID @ ErrorCode::InvalidTokenProgram)]
+    pub token_program: Program<'info, Token2022>,
+}
+
+pub fn process_complete_unstake<'info>(ctx: Context<'_, '_, 'info, 'info, CompleteUnstake<'info>>) -> Result<()> {
+    msg!("--- Instruction: CompleteUnstake ---");
+    msg!("Staker: {}", ctx.accounts.staker.key());
+
+    let pending_amount = ctx.accounts.staker_position.pending_unstake_amount;
+    let available_at = ctx.accounts.staker_position.unstake_available_at;
+    let clock = Clock::get()?;
+    msg!(
+        "Pending unstake: {}, available at: {}, now: {}",
+        pending_amount,
+        available_at,
+        clock.unix_timestamp
+    );
+
+    require!(pending_amount > 0, ErrorCode::NoPendingUnstake);
+    require!(
+        clock.unix_timestamp >= available_at,
+        ErrorCode::UnstakeCooldownActive
+    );
+
+    let pool_signer_seeds: &[&[u8]] = &[b"staking_pool", &[ctx.accounts.staking_pool.bump]];
+    let all_pool_signer_seeds = &[pool_signer_seeds][..];
+
+    transfer_checked_with_hook(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.stake_vault.to_account_info(),
+                to: ctx.accounts.staker_token_account.to_account_info(),
+                authority: ctx.accounts.staking_pool.to_account_info(),
+                mint: ctx.accounts.cashino_token_mint.to_account_info(),
+            },
+            all_pool_signer_seeds,
+        )
+        .with_remaining_accounts(ctx.remaining_accounts.to_vec()),
+        pending_amount,
+        ctx.accounts.cashino_token_mint.decimals,
+    )?;
+
+    let position = &mut ctx.accounts.staker_position;
+    position.pending_unstake_amount = 0;
+    position.unstake_available_at = 0;
+
+    msg!("Transferred {} $CASHINO back to staker.", pending_amount);
+    msg!("--- CompleteUnstake finished ---");
+    Ok(())
+}
@@ -0,0 +1,83 @@
+use crate::{ErrorCode, PlatformConfig, StakingPool, MAX_UNSTAKE_COOLDOWN};
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{spl_token_2022, Mint, Token2022, TokenAccount},
+};
+
+#[derive(Accounts)]
+pub struct InitializeStakingPool<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"platform_config"],
+        bump,
+        constraint = platform_config.authority == authority.key() @ ErrorCode::UnauthorizedAccess,
+        constraint = platform_config.is_initialized @ ErrorCode::UnauthorizedAccess
+    )]
+    pub platform_config: Box<Account<'info, PlatformConfig>>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + std::mem::size_of::<StakingPool>(),
+        seeds = [b"staking_pool"],
+        bump
+    )]
+    pub staking_pool: Box<Account<'info, StakingPool>>,
+
+    #[account(address = platform_config.cashino_mint @ ErrorCode::InvalidMintAccount)]
+    pub cashino_token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        init,
+        payer = authority,
+        associated_token::mint = cashino_token_mint,
+        associated_token::authority = staking_pool,
+        associated_token::token_program = token_program
+    )]
+    pub stake_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(address = spl_token_2022::ID @ ErrorCode::InvalidTokenProgram)]
+    pub token_program: Program<'info, Token2022>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn process_initialize_staking_pool(
+    ctx: Context<InitializeStakingPool>,
+    revenue_share_basis_points: u16,
+    unstake_cooldown: i64,
+) -> Result<()> {
+    msg!("--- Instruction: InitializeStakingPool ---");
+    msg!("Authority: {}", ctx.accounts.authority.key());
+    msg!("StakingPool PDA: {}", ctx.accounts.staking_pool.key());
+    msg!("Stake Vault: {}", ctx.accounts.stake_vault.key());
+    msg!("Revenue share (bp): {}", revenue_share_basis_points);
+    msg!("Unstake cooldown (s): {}", unstake_cooldown);
+
+    require!(
+        revenue_share_basis_points <= 10_000,
+        ErrorCode::InvalidRevenueShare
+    );
+    require!(
+        (0..=MAX_UNSTAKE_COOLDOWN).contains(&unstake_cooldown),
+        ErrorCode::InvalidUnstakeCooldown
+    );
+
+    let staking_pool = &mut ctx.accounts.staking_pool;
+    staking_pool.authority = ctx.accounts.authority.key();
+    staking_pool.cashino_mint = ctx.accounts.cashino_token_mint.key();
+    staking_pool.total_staked = 0;
+    staking_pool.acc_sol_per_share = 0;
+    staking_pool.revenue_share_basis_points = revenue_share_basis_points;
+    staking_pool.unstake_cooldown = unstake_cooldown;
+    staking_pool.total_sol_distributed = 0;
+    staking_pool.bump = ctx.bumps.staking_pool;
+    staking_pool.is_initialized = true;
+
+    msg!("Staking pool initialized for $CASHINO mint {}.", staking_pool.cashino_mint);
+    msg!("--- InitializeStakingPool finished ---");
+    Ok(())
+}
@@ -0,0 +1,14 @@
+pub mod initialize_staking_pool;
+pub use initialize_staking_pool::*;
+
+pub mod stake_cashino;
+pub use stake_cashino::*;
+
+pub mod unstake_cashino;
+pub use unstake_cashino::*;
+
+pub mod complete_unstake;
+pub use complete_unstake::*;
+
+pub mod claim_staking_yield;
+pub use claim_staking_yield::*;
@@ -0,0 +1,120 @@
+use crate::{ErrorCode, StakerPosition, StakingPool};
+use crate::token_transfer::transfer_checked_with_hook;
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{spl_token_2022, Mint, Token2022, TokenAccount, TransferChecked};
+
+#[derive(Accounts)]
+#[instruction(amount: u64)]
+pub struct StakeCashino<'info> {
+    #[account(mut)]
+    pub staker: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"staking_pool"],
+        bump = staking_pool.bump,
+        constraint = staking_pool.is_initialized @ ErrorCode::UnauthorizedAccess
+    )]
+    pub staking_pool: Box<Account<'info, StakingPool>>,
+
+    #[account(
+        init_if_needed,
+        payer = staker,
+        space = 8 + std::mem::size_of::<StakerPosition>(),
+        seeds = [b"staker", staker.key().as_ref()],
+        bump
+    )]
+    pub staker_position: Box<Account<'info, StakerPosition>>,
+
+    #[account(address = staking_pool.cashino_mint @ ErrorCode::InvalidMintAccount)]
+    pub cashino_token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        token::mint = cashino_token_mint,
+        token::authority = staker,
+        token::token_program = token_program
+    )]
+    pub staker_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        associated_token::mint = cashino_token_mint,
+        associated_token::authority = staking_pool,
+        associated_token::token_program = token_program
+    )]
+    pub stake_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(address = spl_token_2022::ID @ ErrorCode::InvalidTokenProgram)]
+    pub token_program: Program<'info, Token2022>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn process_stake_cashino<'info>(ctx: Context<'_, '_, 'info, 'info, StakeCashino<'info>>, amount: u64) -> Result<()> {
+    msg!("--- Instruction: StakeCashino ---");
+    msg!("Staker: {}", ctx.accounts.staker.key());
+    msg!("StakerPosition PDA: {}", ctx.accounts.staker_position.key());
+    msg!("Amount to stake: {}", amount);
+
+    require!(amount > 0, ErrorCode::InvalidStakeAmount);
+
+    if ctx.accounts.staker_position.owner == Pubkey::default() {
+        let position = &mut ctx.accounts.staker_position;
+        position.owner = ctx.accounts.staker.key();
+        position.bump = ctx.bumps.staker_position;
+        msg!("StakerPosition initialized for {}.", position.owner);
+    }
+    require_keys_eq!(
+        ctx.accounts.staker_position.owner,
+        ctx.accounts.staker.key(),
+        ErrorCode::UnauthorizedAccess
+    );
+
+    let vault_balance_before = ctx.accounts.stake_vault.amount;
+    transfer_checked_with_hook(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.staker_token_account.to_account_info(),
+                to: ctx.accounts.stake_vault.to_account_info(),
+                authority: ctx.accounts.staker.to_account_info(),
+                mint: ctx.accounts.cashino_token_mint.to_account_info(),
+            },
+        )
+        .with_remaining_accounts(ctx.remaining_accounts.to_vec()),
+        amount,
+        ctx.accounts.cashino_token_mint.decimals,
+    )?;
+
+    // $CASHINO carries a transfer fee, so only what reached the vault is staked.
+    ctx.accounts.stake_vault.reload()?;
+    let amount_received = ctx
+        .accounts
+        .stake_vault
+        .amount
+        .checked_sub(vault_balance_before)
+        .ok_or(ErrorCode::CalculationError)?;
+    msg!("Sent {} $CASHINO, vault received {}.", amount, amount_received);
+
+    let staking_pool = &mut ctx.accounts.staking_pool;
+    let position = &mut ctx.accounts.staker_position;
+    position.settle_yield(staking_pool)?;
+    position.staked_amount = position
+        .staked_amount
+        .checked_add(amount_received)
+        .ok_or(ErrorCode::CalculationError)?;
+    position.reset_reward_debt(staking_pool)?;
+    staking_pool.total_staked = staking_pool
+        .total_staked
+        .checked_add(amount_received)
+        .ok_or(ErrorCode::CalculationError)?;
+
+    msg!(
+        "Position staked: {}, pending SOL yield: {}. Pool total staked: {}",
+        position.staked_amount,
+        position.pending_sol,
+        staking_pool.total_staked
+    );
+    msg!("--- StakeCashino finished ---");
+    Ok(())
+}
@@ -0,0 +1,122 @@
+use crate::{ErrorCode, StakerPosition, StakingPool};
+use crate::token_transfer::transfer_checked_with_hook;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::clock::Clock;
+use anchor_spl::token_interface::{spl_token_2022, Mint, Token2022, TokenAccount, TransferChecked};
+
+#[derive(Accounts)]
+#[instruction(amount: u64)]
+pub struct UnstakeCashino<'info> {
+    #[account(mut)]
+    pub staker: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"staking_pool"],
+        bump = staking_pool.bump
+    )]
+    pub staking_pool: Box<Account<'info, StakingPool>>,
+
+    #[account(
+        mut,
+        seeds = [b"staker", staker.key().as_ref()],
+        bump = staker_position.bump,
+        constraint = staker_position.owner == staker.key() @ ErrorCode::UnauthorizedAccess
+    )]
+    pub staker_position: Box<Account<'info, StakerPosition>>,
+
+    #[account(address = staking_pool.cashino_mint @ ErrorCode::InvalidMintAccount)]
+    pub cashino_token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        token::mint = cashino_token_mint,
+        token::authority = staker,
+        token::token_program = token_program
+    )]
+    pub staker_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        associated_token::mint = cashino_token_mint,
+        associated_token::authority = staking_pool,
+        associated_token::token_program = token_program
+    )]
+    pub stake_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(address = spl_token_2022::ID @ ErrorCode::InvalidTokenProgram)]
+    pub token_program: Program<'info, Token2022>,
+}
+
+pub fn process_unstake_cashino<'info>(ctx: Context<'_, '_, 'info, 'info, UnstakeCashino<'info>>, amount: u64) -> Result<()> {
+    msg!("--- Instruction: UnstakeCashino ---");
+    msg!("Staker: {}", ctx.accounts.staker.key());
+    msg!("Amount to unstake: {}", amount);
+    msg!("Currently staked: {}", ctx.accounts.staker_position.staked_amount);
+
+    require!(amount > 0, ErrorCode::InvalidStakeAmount);
+    require!(
+        ctx.accounts.staker_position.staked_amount >= amount,
+        ErrorCode::InsufficientStakedBalance
+    );
+
+    // Yield stops accruing on the unstaked amount as soon as it leaves the pool total.
+    {
+        let staking_pool = &mut ctx.accounts.staking_pool;
+        let position = &mut ctx.accounts.staker_position;
+        position.settle_yield(staking_pool)?;
+        position.staked_amount = position
+            .staked_amount
+            .checked_sub(amount)
+            .ok_or(ErrorCode::InsufficientStakedBalance)?;
+        position.reset_reward_debt(staking_pool)?;
+        staking_pool.total_staked = staking_pool
+            .total_staked
+            .checked_sub(amount)
+            .ok_or(ErrorCode::CalculationError)?;
+    }
+
+    let unstake_cooldown = ctx.accounts.staking_pool.unstake_cooldown;
+    if unstake_cooldown > 0 {
+        let clock = Clock::get()?;
+        let position = &mut ctx.accounts.staker_position;
+        position.pending_unstake_amount = position
+            .pending_unstake_amount
+            .checked_add(amount)
+            .ok_or(ErrorCode::CalculationError)?;
+        position.unstake_available_at = clock
+            .unix_timestamp
+            .checked_add(unstake_cooldown)
+            .ok_or(ErrorCode::CalculationError)?;
+        msg!(
+            "Unstake of {} queued. Pending total: {}, available at: {}",
+            amount,
+            position.pending_unstake_amount,
+            position.unstake_available_at
+        );
+    } else {
+        let pool_signer_seeds: &[&[u8]] = &[b"staking_pool", &[ctx.accounts.staking_pool.bump]];
+        let all_pool_signer_seeds = &[pool_signer_seeds][..];
+
+        transfer_checked_with_hook(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.stake_vault.to_account_info(),
+                    to: ctx.accounts.staker_token_account.to_account_info(),
+                    authority: ctx.accounts.staking_pool.to_account_info(),
+                    mint: ctx.accounts.cashino_token_mint.to_account_info(),
+                },
+                all_pool_signer_seeds,
+            )
+            .with_remaining_accounts(ctx.remaining_accounts.to_vec()),
+            amount,
+            ctx.accounts.cashino_token_mint.decimals,
+        )?;
+        msg!("No cooldown configured. Transferred {} $CASHINO back to staker.", amount);
+    }
+
+    msg!("Pool total staked: {}", ctx.accounts.staking_pool.total_staked);
+    msg!("--- UnstakeCashino finished ---");
+    Ok(())
+}
@@ -0,0 +1,259 @@
+use crate::token_transfer::transfer_checked_with_hook;
+use crate::{ErrorCode, PlatformConfig, TransferFeesCollected, MINT_AUTHORITY_SEED};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    burn, harvest_withheld_tokens_to_mint, spl_token_2022, withdraw_withheld_tokens_from_mint,
+    Burn, HarvestWithheldTokensToMint, Mint, Token2022, TokenAccount, TransferChecked,
+    WithdrawWithheldTokensFromMint,
+};
+
+#[derive(Accounts)]
+pub struct CollectTransferFees<'info> {
+    #[account(
+        mut,
+        seeds = [b"platform_config"],
+        bump,
+        constraint = platform_config.fee_treasury != Pubkey::default() @ ErrorCode::FeeTreasuryNotSet
+    )]
+    pub platform_config: Box<Account<'info, PlatformConfig>>,
+
+    /// CHECK: The mint_authority_pda, which is the withdraw withheld authority.
+    #[account(
+        seeds = [MINT_AUTHORITY_SEED],
+        bump
+    )]
+    pub mint_authority_pda: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        address = platform_config.cashino_mint @ ErrorCode::InvalidMintAccount
+    )]
+    pub cashino_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        address = platform_config.fee_treasury @ ErrorCode::InvalidFeeTreasury
+    )]
+    pub fee_treasury: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Required while `platform_config.fee_burn_basis_points` is non-zero; created by `set_fee_burn`.
+    #[account(
+        mut,
+        associated_token::mint = cashino_mint,
+        associated_token::authority = mint_authority_pda,
+        associated_token::token_program = token_program
+    )]
+    pub fee_burn_vault: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(address = spl_token_2022::ID @ ErrorCode::InvalidTokenProgram)]
+    pub token_program: Program<'info, Token2022>,
+}
+
+/// Whether a remaining account is a Token-2022 account of the CASHINO mint, which fees can be
+/// harvested from. Anything else passed along, such as the transfer hook's accounts, is skipped.
+fn is_fee_source<'info>(account_info: &'info AccountInfo<'info>, mint_key: &Pubkey) -> bool {
+    account_info.owner == &spl_token_2022::ID
+        && InterfaceAccount::<TokenAccount>::try_from(account_info)
+            .map(|token_account| token_account.mint == *mint_key)
+            .unwrap_or(false)
+}
+
+/// Permissionless: harvests withheld fees from the CASHINO token accounts passed as remaining
+/// accounts, then withdraws everything withheld on the mint to the platform fee treasury.
+/// The mint is shared by every table, so the fees and their running total are platform-wide.
+/// Callers pack as many source accounts as fit in the transaction; others are skipped.
+///
+/// With a fee burn configured, the fees are withdrawn to the fee burn vault instead, the burn
+/// share is burned there and the rest is transferred on to the treasury. That transfer pays the
+/// mint's transfer fee like any other, and its hook accounts go in the remaining accounts too.
+pub fn process_collect_transfer_fees<'info>(
+    ctx: Context<'_, '_, 'info, 'info, CollectTransferFees<'info>>,
+) -> Result<()> {
+    msg!("--- Instruction: CollectTransferFees ---");
+    msg!("Mint: {}", ctx.accounts.cashino_mint.key());
+    msg!("Fee treasury: {}", ctx.accounts.fee_treasury.key());
+
+    let mint_key = ctx.accounts.cashino_mint.key();
+    let sources = ctx
+        .remaining_accounts
+        .iter()
+        .filter(|account_info| {
+            let is_source = is_fee_source(account_info, &mint_key);
+            if !is_source {
+                msg!("  - Skipping account {}", account_info.key());
+            }
+            is_source
+        })
+        .cloned()
+        .collect::<Vec<_>>();
+    let sources_harvested = sources.len() as u32;
+    msg!("Harvesting from {} source account(s).", sources_harvested);
+
+    if !sources.is_empty() {
+        harvest_withheld_tokens_to_mint(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                HarvestWithheldTokensToMint {
+                    token_program_id: ctx.accounts.token_program.to_account_info(),
+                    mint: ctx.accounts.cashino_mint.to_account_info(),
+                },
+            ),
+            sources,
+        )?;
+    }
+
+    let bump = ctx.bumps.mint_authority_pda;
+    let pda_signer_seeds: &[&[u8]] = &[MINT_AUTHORITY_SEED, &[bump]];
+    let signer_seeds = &[pda_signer_seeds];
+
+    let fee_burn_basis_points = ctx.accounts.platform_config.fee_burn_basis_points;
+    let fee_burn_vault = if fee_burn_basis_points > 0 {
+        Some(
+            ctx.accounts
+                .fee_burn_vault
+                .as_mut()
+                .ok_or(ErrorCode::MissingFeeBurnVault)?,
+        )
+    } else {
+        None
+    };
+    let destination = match &fee_burn_vault {
+        Some(vault) => vault.to_account_info(),
+        None => ctx.accounts.fee_treasury.to_account_info(),
+    };
+    let destination_balance_before = match &fee_burn_vault {
+        Some(vault) => vault.amount,
+        None => ctx.accounts.fee_treasury.amount,
+    };
+
+    withdraw_withheld_tokens_from_mint(CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        WithdrawWithheldTokensFromMint {
+            token_program_id: ctx.accounts.token_program.to_account_info(),
+            mint: ctx.accounts.cashino_mint.to_account_info(),
+            destination,
+            authority: ctx.accounts.mint_authority_pda.to_account_info(),
+        },
+        signer_seeds,
+    ))?;
+
+    let (amount, burned) = match fee_burn_vault {
+        Some(vault) => {
+            vault.reload()?;
+            let amount = vault
+                .amount
+                .checked_sub(destination_balance_before)
+                .ok_or(ErrorCode::CalculationError)?;
+            let burned = ctx.accounts.platform_config.fee_burn_amount(amount)?;
+            let forwarded = amount.checked_sub(burned).ok_or(ErrorCode::CalculationError)?;
+
+            if burned > 0 {
+                burn(
+                    CpiContext::new_with_signer(
+                        ctx.accounts.token_program.to_account_info(),
+                        Burn {
+                            mint: ctx.accounts.cashino_mint.to_account_info(),
+                            from: vault.to_account_info(),
+                            authority: ctx.accounts.mint_authority_pda.to_account_info(),
+                        },
+                        signer_seeds,
+                    ),
+                    burned,
+                )?;
+            }
+            if forwarded > 0 {
+                transfer_checked_with_hook(
+                    CpiContext::new_with_signer(
+                        ctx.accounts.token_program.to_account_info(),
+                        TransferChecked {
+                            from: vault.to_account_info(),
+                            to: ctx.accounts.fee_treasury.to_account_info(),
+                            authority: ctx.accounts.mint_authority_pda.to_account_info(),
+                            mint: ctx.accounts.cashino_mint.to_account_info(),
+                        },
+                        signer_seeds,
+                    )
+                    .with_remaining_accounts(ctx.remaining_accounts.to_vec()),
+                    forwarded,
+                    ctx.accounts.cashino_mint.decimals,
+                )?;
+            }
+            (amount, burned)
+        }
+        None => {
+            ctx.accounts.fee_treasury.reload()?;
+            let amount = ctx
+                .accounts
+                .fee_treasury
+                .amount
+                .checked_sub(destination_balance_before)
+                .ok_or(ErrorCode::CalculationError)?;
+            (amount, 0)
+        }
+    };
+
+    let platform_config = &mut ctx.accounts.platform_config;
+    platform_config.transfer_fees_collected = platform_config
+        .transfer_fees_collected
+        .checked_add(amount)
+        .ok_or(ErrorCode::CalculationError)?;
+    platform_config.fees_burned = platform_config
+        .fees_burned
+        .checked_add(burned)
+        .ok_or(ErrorCode::CalculationError)?;
+
+    emit!(TransferFeesCollected {
+        fee_treasury: platform_config.fee_treasury,
+        sources_harvested,
+        amount,
+        burned,
+        total_collected: platform_config.transfer_fees_collected,
+        total_burned: platform_config.fees_burned,
+    });
+    msg!(
+        "Collected {} in transfer fees, {} of it burned; {} collected in total.",
+        amount,
+        burned,
+        platform_config.transfer_fees_collected
+    );
+    msg!("--- CollectTransferFees finished ---");
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::solana_program::program_pack::Pack;
+    use spl_token_2022::state::{Account as TokenAccountState, AccountState};
+
+    fn token_account(mint: Pubkey, owner: Pubkey) -> &'static AccountInfo<'static> {
+        let mut data = vec![0u8; TokenAccountState::LEN];
+        TokenAccountState {
+            mint,
+            owner: Pubkey::new_unique(),
+            state: AccountState::Initialized,
+            ..Default::default()
+        }
+        .pack_into_slice(&mut data);
+        Box::leak(Box::new(AccountInfo::new(
+            Box::leak(Box::new(Pubkey::new_unique())),
+            false,
+            true,
+            Box::leak(Box::new(1)),
+            Box::leak(data.into_boxed_slice()),
+            Box::leak(Box::new(owner)),
+            false,
+            0,
+        )))
+    }
+
+    #[test]
+    fn fees_are_harvested_from_cashino_token_accounts_only() {
+        let mint = Pubkey::new_unique();
+        assert!(is_fee_source(token_account(mint, spl_token_2022::ID), &mint));
+        assert!(!is_fee_source(token_account(Pubkey::new_unique(), spl_token_2022::ID), &mint));
+        // Same layout, but not owned by Token-2022.
+        assert!(!is_fee_source(token_account(mint, anchor_spl::token::ID), &mint));
+        assert!(!is_fee_source(token_account(mint, crate::ID), &mint));
+    }
+}
@@ -1,4 +1,4 @@
-use crate::MINT_AUTHORITY_SEED;
+use crate::{MetadataField, MintInitConfig, MAX_TOKEN_METADATA_ADDITIONAL_FIELDS, MINT_AUTHORITY_SEED};
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::rent::Rent;
 use anchor_lang::system_program::{create_account, CreateAccount};
@@ -8,14 +8,21 @@ use anchor_spl::{
         spl_token_2022::{
             extension::{transfer_fee::TransferFeeConfig, ExtensionType},
             pod::PodMint,
-            state::Mint as MintState,
+            state::{AccountState, Mint as MintState},
             ID as spl_token_2022_program_id,
         },
         InitializeMint2,
     },
     token_interface::{
-        spl_pod::optional_keys::OptionalNonZeroPubkey, transfer_fee_initialize, Mint, Token2022,
-        TransferFeeInitialize,
+        default_account_state_initialize, interest_bearing_mint_initialize,
+        metadata_pointer_initialize, mint_close_authority_initialize,
+        permanent_delegate_initialize, spl_pod::optional_keys::OptionalNonZeroPubkey,
+        spl_token_metadata_interface::state::{Field, TokenMetadata},
+        token_metadata_initialize, token_metadata_update_field, transfer_fee_initialize,
+        transfer_hook_initialize, DefaultAccountStateInitialize, InterestBearingMintInitialize,
+        MetadataPointerInitialize, MintCloseAuthorityInitialize, PermanentDelegateInitialize,
+        Token2022, TokenMetadataInitialize, TokenMetadataUpdateField, TransferFeeInitialize,
+        TransferHookInitialize,
     },
 };
 
@@ -42,10 +49,40 @@ pub struct InitializeToken2022<'info> {
     pub system_program: Program<'info, System>,
 }
 
+/// Fixed-size extensions the mint is created with for `config`.
+fn mint_extension_types(config: &MintInitConfig) -> Vec<ExtensionType> {
+    let mut extension_types = vec![
+        ExtensionType::TransferFeeConfig,
+        ExtensionType::MetadataPointer,
+    ];
+    if config.interest_rate.is_some() {
+        extension_types.push(ExtensionType::InterestBearingConfig);
+    }
+    if config.permanent_delegate.is_some() {
+        extension_types.push(ExtensionType::PermanentDelegate);
+    }
+    if config.default_account_state_frozen {
+        extension_types.push(ExtensionType::DefaultAccountState);
+    }
+    if config.close_authority.is_some() {
+        extension_types.push(ExtensionType::MintCloseAuthority);
+    }
+    if config.transfer_hook_program_id.is_some() {
+        extension_types.push(ExtensionType::TransferHook);
+    }
+    extension_types
+}
+
+#[allow(clippy::too_many_arguments)]
 pub fn process_initialize(
     ctx: Context<InitializeToken2022>,
     transfer_fee_basis_points: u16,
     maximum_fee: u64,
+    name: String,
+    symbol: String,
+    uri: String,
+    additional_metadata: Vec<MetadataField>,
+    config: MintInitConfig,
 ) -> Result<()> {
     msg!("--- Instruction: InitializeToken2022 ---");
     msg!(
@@ -53,6 +90,14 @@ pub fn process_initialize(
         transfer_fee_basis_points
     );
     msg!("Input Parameter - Maximum Fee: {}", maximum_fee);
+    msg!(
+        "Input Parameter - Metadata: name '{}', symbol '{}', uri '{}', {} additional fields",
+        name,
+        symbol,
+        uri,
+        additional_metadata.len()
+    );
+    msg!("Input Parameter - Mint Config: {:?}", config);
     msg!("Payer: {}", ctx.accounts.payer.key());
     msg!(
         "Mint Account address (to be created with keypair): {}",
@@ -79,16 +124,50 @@ pub fn process_initialize(
         return err!(ErrorCode::FeeCalculationFailed);
     }
 
-    let mint_size =
-        ExtensionType::try_calculate_account_len::<PodMint>(&[ExtensionType::TransferFeeConfig])?;
+    require!(
+        additional_metadata.len() <= MAX_TOKEN_METADATA_ADDITIONAL_FIELDS
+            && additional_metadata.iter().all(|field| !field.key.is_empty()),
+        ErrorCode::InvalidTokenMetadata
+    );
+    require!(
+        !config.default_account_state_frozen || config.freeze_authority,
+        ErrorCode::InvalidMintConfig
+    );
+
+    // The account is created with room for the fixed-size extensions only. TokenMetadata is
+    // variable length and Token-2022 reallocs the mint when it is written, so rent for the
+    // final size has to be funded up front.
+    let extension_types = mint_extension_types(&config);
+    let mint_size = ExtensionType::try_calculate_account_len::<PodMint>(&extension_types)?;
     msg!(
-        "Calculated Mint Account Size (with TransferFeeConfig extension): {}",
+        "Calculated Mint Account Size (with extensions {:?}): {}",
+        extension_types,
         mint_size
     );
 
-    let lamports = Rent::get()?.minimum_balance(mint_size);
+    let token_metadata = TokenMetadata {
+        update_authority: OptionalNonZeroPubkey::try_from(Some(
+            ctx.accounts.mint_authority_pda.key(),
+        ))?,
+        mint: ctx.accounts.mint_account.key(),
+        name: name.clone(),
+        symbol: symbol.clone(),
+        uri: uri.clone(),
+        additional_metadata: additional_metadata
+            .iter()
+            .map(|field| (field.key.clone(), field.value.clone()))
+            .collect(),
+    };
+    let metadata_size = token_metadata.tlv_size_of()?;
+    msg!("Calculated TokenMetadata TLV Size: {}", metadata_size);
+
+    let lamports = Rent::get()?.minimum_balance(
+        mint_size
+            .checked_add(metadata_size)
+            .ok_or(ErrorCode::CalculationError)?,
+    );
     msg!(
-        "Calculated Minimum Lamports for Rent Exemption: {}",
+        "Calculated Minimum Lamports for Rent Exemption (including metadata): {}",
         lamports
     );
 
@@ -110,6 +189,23 @@ pub fn process_initialize(
         ctx.accounts.mint_account.key()
     );
 
+    msg!(
+        "Calling CPI: metadata_pointer_initialize (for mint {})",
+        ctx.accounts.mint_account.key()
+    );
+    metadata_pointer_initialize(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            MetadataPointerInitialize {
+                token_program_id: ctx.accounts.token_program.to_account_info(),
+                mint: ctx.accounts.mint_account.to_account_info(),
+            },
+        ),
+        Some(ctx.accounts.mint_authority_pda.key()),
+        Some(ctx.accounts.mint_account.key()),
+    )?;
+    msg!("CPI successful: Metadata pointer set to the mint itself.");
+
     let transfer_fee_config_authority = Some(ctx.accounts.mint_authority_pda.key());
     let withdraw_withheld_authority = Some(ctx.accounts.mint_authority_pda.key());
     msg!(
@@ -143,20 +239,100 @@ pub fn process_initialize(
         ctx.accounts.mint_account.key()
     );
 
-    let decimals_to_set: u8 = 2;
+    if let Some(rate) = config.interest_rate {
+        msg!("Calling CPI: interest_bearing_mint_initialize (rate {} bps)", rate);
+        interest_bearing_mint_initialize(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                InterestBearingMintInitialize {
+                    token_program_id: ctx.accounts.token_program.to_account_info(),
+                    mint: ctx.accounts.mint_account.to_account_info(),
+                },
+            ),
+            Some(ctx.accounts.mint_authority_pda.key()),
+            rate,
+        )?;
+    }
+
+    if let Some(permanent_delegate) = config.permanent_delegate {
+        msg!(
+            "Calling CPI: permanent_delegate_initialize (delegate {})",
+            permanent_delegate
+        );
+        permanent_delegate_initialize(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                PermanentDelegateInitialize {
+                    token_program_id: ctx.accounts.token_program.to_account_info(),
+                    mint: ctx.accounts.mint_account.to_account_info(),
+                },
+            ),
+            &permanent_delegate,
+        )?;
+    }
+
+    if config.default_account_state_frozen {
+        msg!("Calling CPI: default_account_state_initialize (Frozen)");
+        default_account_state_initialize(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                DefaultAccountStateInitialize {
+                    token_program_id: ctx.accounts.token_program.to_account_info(),
+                    mint: ctx.accounts.mint_account.to_account_info(),
+                },
+            ),
+            &AccountState::Frozen,
+        )?;
+    }
+
+    if let Some(close_authority) = config.close_authority {
+        msg!(
+            "Calling CPI: mint_close_authority_initialize (authority {})",
+            close_authority
+        );
+        mint_close_authority_initialize(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                MintCloseAuthorityInitialize {
+                    token_program_id: ctx.accounts.token_program.to_account_info(),
+                    mint: ctx.accounts.mint_account.to_account_info(),
+                },
+            ),
+            Some(&close_authority),
+        )?;
+    }
+
+    if let Some(transfer_hook_program_id) = config.transfer_hook_program_id {
+        msg!(
+            "Calling CPI: transfer_hook_initialize (program {})",
+            transfer_hook_program_id
+        );
+        transfer_hook_initialize(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                TransferHookInitialize {
+                    token_program_id: ctx.accounts.token_program.to_account_info(),
+                    mint: ctx.accounts.mint_account.to_account_info(),
+                },
+            ),
+            Some(ctx.accounts.mint_authority_pda.key()),
+            Some(transfer_hook_program_id),
+        )?;
+    }
+
+    let freeze_authority = config
+        .freeze_authority
+        .then(|| ctx.accounts.mint_authority_pda.key());
     msg!(
         "Calling CPI: initialize_mint2 (for mint {})",
         ctx.accounts.mint_account.key()
     );
-    msg!("  Decimals to set: {}", decimals_to_set);
+    msg!("  Decimals to set: {}", config.decimals);
     msg!(
         "  Mint Authority to set: {}",
         ctx.accounts.mint_authority_pda.key()
     );
-    msg!(
-        "  Freeze Authority to set: {}",
-        ctx.accounts.mint_authority_pda.key()
-    );
+    msg!("  Freeze Authority to set: {:?}", freeze_authority);
 
     initialize_mint2(
         CpiContext::new(
@@ -165,15 +341,61 @@ pub fn process_initialize(
                 mint: ctx.accounts.mint_account.to_account_info(),
             },
         ),
-        decimals_to_set,
+        config.decimals,
         &ctx.accounts.mint_authority_pda.key(),
-        Some(&ctx.accounts.mint_authority_pda.key()),
+        freeze_authority.as_ref(),
     )?;
     msg!(
-        "CPI successful: Mint {} initialized with mint authority {} and freeze authority {}.",
+        "CPI successful: Mint {} initialized with mint authority {} and freeze authority {:?}.",
         ctx.accounts.mint_account.key(),
         ctx.accounts.mint_authority_pda.key(),
-        ctx.accounts.mint_authority_pda.key()
+        freeze_authority
+    );
+
+    let bump = ctx.bumps.mint_authority_pda;
+    let pda_signer_seeds: &[&[u8]] = &[MINT_AUTHORITY_SEED, &[bump]];
+    let signer_seeds = &[pda_signer_seeds];
+
+    msg!(
+        "Calling CPI: token_metadata_initialize (for mint {})",
+        ctx.accounts.mint_account.key()
+    );
+    token_metadata_initialize(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TokenMetadataInitialize {
+                token_program_id: ctx.accounts.token_program.to_account_info(),
+                metadata: ctx.accounts.mint_account.to_account_info(),
+                update_authority: ctx.accounts.mint_authority_pda.to_account_info(),
+                mint_authority: ctx.accounts.mint_authority_pda.to_account_info(),
+                mint: ctx.accounts.mint_account.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        name,
+        symbol,
+        uri,
+    )?;
+
+    for field in additional_metadata {
+        msg!("Calling CPI: token_metadata_update_field (key '{}')", field.key);
+        token_metadata_update_field(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                TokenMetadataUpdateField {
+                    token_program_id: ctx.accounts.token_program.to_account_info(),
+                    metadata: ctx.accounts.mint_account.to_account_info(),
+                    update_authority: ctx.accounts.mint_authority_pda.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            Field::Key(field.key),
+            field.value,
+        )?;
+    }
+    msg!(
+        "CPI successful: Token metadata initialized for mint {}.",
+        ctx.accounts.mint_account.key()
     );
     msg!("--- InitializeToken2022 finished ---");
     Ok(())
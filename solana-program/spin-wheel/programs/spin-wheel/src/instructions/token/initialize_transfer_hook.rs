@@ -0,0 +1,78 @@
+use crate::error::ErrorCode;
+use crate::{PlatformConfig, MINT_AUTHORITY_SEED};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+use cashino_transfer_hook::{
+    cpi::accounts::InitializeExtraAccountMetaList, program::CashinoTransferHook, TransferRules,
+};
+
+#[derive(Accounts)]
+pub struct InitializeTransferHook<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"platform_config"],
+        bump,
+        constraint = platform_config.authority == authority.key() @ ErrorCode::UnauthorizedAccess,
+    )]
+    pub platform_config: Box<Account<'info, PlatformConfig>>,
+
+    /// CHECK: The mint_authority_pda, which the hook program accepts as the mint's issuer.
+    #[account(
+        seeds = [MINT_AUTHORITY_SEED],
+        bump
+    )]
+    pub mint_authority_pda: AccountInfo<'info>,
+
+    pub mint_account: InterfaceAccount<'info, Mint>,
+
+    /// CHECK: Created by the transfer hook program.
+    #[account(mut)]
+    pub extra_account_meta_list: UncheckedAccount<'info>,
+
+    /// CHECK: Created by the transfer hook program.
+    #[account(mut)]
+    pub hook_config: UncheckedAccount<'info>,
+
+    pub transfer_hook_program: Program<'info, CashinoTransferHook>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Writes the hook's extra-account-meta list for a mint created with
+/// `transfer_hook_program_id` set to the CASHINO hook.
+pub fn process_initialize_transfer_hook(
+    ctx: Context<InitializeTransferHook>,
+    rules: TransferRules,
+) -> Result<()> {
+    msg!("--- Instruction: InitializeTransferHook (PDA Signed) ---");
+    msg!("Mint: {}", ctx.accounts.mint_account.key());
+    msg!("Rules: {:?}", rules);
+
+    let bump = ctx.bumps.mint_authority_pda;
+    let pda_signer_seeds: &[&[u8]] = &[MINT_AUTHORITY_SEED, &[bump]];
+    let signer_seeds = &[pda_signer_seeds];
+
+    cashino_transfer_hook::cpi::initialize_extra_account_meta_list(
+        CpiContext::new_with_signer(
+            ctx.accounts.transfer_hook_program.to_account_info(),
+            InitializeExtraAccountMetaList {
+                payer: ctx.accounts.authority.to_account_info(),
+                authority: ctx.accounts.mint_authority_pda.to_account_info(),
+                mint: ctx.accounts.mint_account.to_account_info(),
+                extra_account_meta_list: ctx.accounts.extra_account_meta_list.to_account_info(),
+                hook_config: ctx.accounts.hook_config.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        rules,
+    )?;
+
+    msg!(
+        "Extra account metas initialized at {}.",
+        ctx.accounts.extra_account_meta_list.key()
+    );
+    msg!("--- InitializeTransferHook finished ---");
+    Ok(())
+}
@@ -1,5 +1,5 @@
 use crate::ErrorCode;
-use crate::MINT_AUTHORITY_SEED;
+use crate::{PlatformConfig, CASHINO_MAX_SUPPLY_UNITS, MINT_AUTHORITY_SEED};
 use anchor_lang::prelude::*;
 use anchor_spl::{
     token_2022::mint_to as spl_mint_to,
@@ -8,6 +8,17 @@ use anchor_spl::{
 
 #[derive(Accounts)]
 pub struct MintTokensToAccount<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"platform_config"],
+        bump,
+        constraint = platform_config.authority == authority.key() @ ErrorCode::UnauthorizedAccess,
+        constraint = platform_config.is_initialized @ ErrorCode::UnauthorizedAccess
+    )]
+    pub platform_config: Box<Account<'info, PlatformConfig>>,
+
     /// CHECK: This is the PDA, derived from seeds. We will verify its address and use it to sign.
     #[account(
         seeds = [MINT_AUTHORITY_SEED],
@@ -26,6 +37,13 @@ pub struct MintTokensToAccount<'info> {
 
 pub fn process_mint_tokens(ctx: Context<MintTokensToAccount>, amount: u64) -> Result<()> {
     msg!("--- Instruction: MintTokensToAccount (Public Entry) ---");
+    // LP shares are only minted against deposits in deposit_house_liquidity.
+    let (house_lp_mint, _) = Pubkey::find_program_address(&[b"house_lp_mint"], ctx.program_id);
+    require_keys_neq!(
+        ctx.accounts.mint_account.key(),
+        house_lp_mint,
+        ErrorCode::UnauthorizedAccess
+    );
     internal_perform_mint(
         &ctx.accounts.mint_authority_pda,
         &ctx.accounts.mint_account,
@@ -33,12 +51,19 @@ pub fn process_mint_tokens(ctx: Context<MintTokensToAccount>, amount: u64) -> Re
         &ctx.accounts.token_program,
         ctx.bumps.mint_authority_pda,
         amount,
+        Some((
+            CASHINO_MAX_SUPPLY_UNITS,
+            &mut ctx.accounts.platform_config.cashino_minted,
+        )),
         ctx.program_id,
     )?;
     msg!("--- MintTokensToAccount (Public Entry) finished ---");
     Ok(())
 }
 
+/// `supply_cap` pairs a cap with the running total of CASHINO minted, which the mint is
+/// checked against and then advances. Pass `None` for mints that are not capped.
+#[allow(clippy::too_many_arguments)]
 pub fn internal_perform_mint<'info>(
     mint_authority_pda_info: &AccountInfo<'info>,
     mint_account_interface: &InterfaceAccount<'info, Mint>,
@@ -46,6 +71,7 @@ pub fn internal_perform_mint<'info>(
     token_program_interface: &Program<'info, Token2022>,
     mint_authority_pda_bump: u8,
     amount: u64,
+    supply_cap: Option<(u64, &mut u64)>,
     expected_program_id_for_pda_check: &Pubkey,
 ) -> Result<()> {
     msg!("--- internal_perform_mint called ---");
@@ -85,6 +111,15 @@ pub fn internal_perform_mint<'info>(
 
     msg!("PDA check passed for internal_perform_mint.");
 
+    if let Some((max_supply, minted)) = supply_cap {
+        let new_minted = minted
+            .checked_add(amount)
+            .ok_or(ErrorCode::CalculationError)?;
+        msg!("Minted after this mint: {} (cap {})", new_minted, max_supply);
+        require!(new_minted <= max_supply, ErrorCode::MaxSupplyExceeded);
+        *minted = new_minted;
+    }
+
     let pda_signer_seeds_set: &[&[u8]] = &[MINT_AUTHORITY_SEED, &[mint_authority_pda_bump]];
     let all_signer_seeds = &[pda_signer_seeds_set][..];
 
@@ -7,11 +7,17 @@ pub use mint_tokens::*;
 pub mod transfer;
 pub use transfer::*;
 
-pub mod harvest;
-pub use harvest::*;
-
-pub mod withdraw;
-pub use withdraw::*;
-
 pub mod update_fee;
 pub use update_fee::*;
+
+pub mod update_token_metadata;
+pub use update_token_metadata::*;
+
+pub mod initialize_transfer_hook;
+pub use initialize_transfer_hook::*;
+
+pub mod update_transfer_hook_rules;
+pub use update_transfer_hook_rules::*;
+
+pub mod collect_transfer_fees;
+pub use collect_transfer_fees::*;
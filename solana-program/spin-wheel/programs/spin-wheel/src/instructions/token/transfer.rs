@@ -1,3 +1,4 @@
+use crate::token_transfer::transfer_checked_with_hook;
 use anchor_lang::prelude::*;
 use anchor_spl::{
     associated_token::AssociatedToken,
@@ -7,9 +8,7 @@ use anchor_spl::{
         },
         state::Mint as MintState,
     },
-    token_interface::{
-        transfer_checked_with_fee, Mint, Token2022, TokenAccount, TransferCheckedWithFee,
-    },
+    token_interface::{Mint, Token2022, TokenAccount, TransferChecked},
 };
 
 #[derive(Accounts)]
@@ -40,7 +39,10 @@ pub struct Transfer<'info> {
     pub system_program: Program<'info, System>,
 }
 
-pub fn process_transfer(ctx: Context<Transfer>, amount: u64) -> Result<()> {
+pub fn process_transfer<'info>(
+    ctx: Context<'_, '_, 'info, 'info, Transfer<'info>>,
+    amount: u64,
+) -> Result<()> {
     msg!("--- Instruction: Transfer ---");
     msg!("Sender (authority): {}", ctx.accounts.sender.key());
     msg!("Recipient SystemAccount (authority for dest ATA): {}", ctx.accounts.recipient.key());
@@ -53,21 +55,24 @@ pub fn process_transfer(ctx: Context<Transfer>, amount: u64) -> Result<()> {
     msg!("Amount to transfer (input): {}", amount);
     let mint = &ctx.accounts.mint_account.to_account_info();
     msg!("Processing mint: {}", mint.key());
-    let mint_data = mint.data.borrow();
-    msg!("Attempting to unpack mint data with extensions...");
-    let mint_with_extension = StateWithExtensions::<MintState>::unpack(&mint_data)?;
-    msg!("Mint data unpacked.");
-    msg!("Attempting to get TransferFeeConfig extension...");
-    let extension_data = mint_with_extension.get_extension::<TransferFeeConfig>()?;
-    msg!("TransferFeeConfig extension retrieved.");
-    let epoch = Clock::get()?.epoch;
-    msg!("Current epoch for fee calculation: {}", epoch);
-    let fee = extension_data.calculate_epoch_fee(epoch, amount).unwrap();
+    // The mint borrow ends before the CPI, which reads the mint again to resolve its hook.
+    let fee = {
+        let mint_data = mint.data.borrow();
+        msg!("Attempting to unpack mint data with extensions...");
+        let mint_with_extension = StateWithExtensions::<MintState>::unpack(&mint_data)?;
+        msg!("Mint data unpacked.");
+        msg!("Attempting to get TransferFeeConfig extension...");
+        let extension_data = mint_with_extension.get_extension::<TransferFeeConfig>()?;
+        msg!("TransferFeeConfig extension retrieved.");
+        let epoch = Clock::get()?.epoch;
+        msg!("Current epoch for fee calculation: {}", epoch);
+        extension_data.calculate_epoch_fee(epoch, amount).unwrap()
+    };
     msg!("Calculated fee: {}", fee);
     let decimals = ctx.accounts.mint_account.decimals;
     msg!("Mint decimals: {}", decimals);
 
-    msg!("Preparing for CPI: transfer_checked_with_fee");
+    msg!("Preparing for CPI: transfer_checked");
     msg!("  CPI Source: {}", ctx.accounts.sender_token_account.key());
     msg!("  CPI Mint: {}", ctx.accounts.mint_account.key());
     msg!("  CPI Destination: {}", ctx.accounts.recipient_token_account.key());
@@ -75,22 +80,21 @@ pub fn process_transfer(ctx: Context<Transfer>, amount: u64) -> Result<()> {
     msg!("  CPI Amount (gross): {}", amount);
     msg!("  CPI Decimals: {}", decimals);
     msg!("  CPI Fee: {}", fee);
-    transfer_checked_with_fee(
+    transfer_checked_with_hook(
         CpiContext::new(
             ctx.accounts.token_program.to_account_info(),
-            TransferCheckedWithFee {
-                token_program_id: ctx.accounts.token_program.to_account_info(),
-                source: ctx.accounts.sender_token_account.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.sender_token_account.to_account_info(),
                 mint: ctx.accounts.mint_account.to_account_info(),
-                destination: ctx.accounts.recipient_token_account.to_account_info(),
+                to: ctx.accounts.recipient_token_account.to_account_info(),
                 authority: ctx.accounts.sender.to_account_info(),
             },
-        ),
+        )
+        .with_remaining_accounts(ctx.remaining_accounts.to_vec()),
         amount,
         decimals,
-        fee,
     )?;
-    msg!("CPI transfer_checked_with_fee successful.");
+    msg!("CPI transfer_checked successful.");
     msg!("transfer amount {}", amount);
     msg!("fee amount {}", fee);
     msg!("--- Transfer finished ---");
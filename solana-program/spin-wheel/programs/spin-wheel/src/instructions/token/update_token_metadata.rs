@@ -0,0 +1,114 @@
+use crate::error::ErrorCode;
+use crate::{PlatformConfig, MINT_AUTHORITY_SEED};
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_spl::token_2022::spl_token_2022::{
+    extension::{BaseStateWithExtensions, StateWithExtensions},
+    state::Mint as MintState,
+};
+use anchor_spl::token_interface::{
+    spl_token_metadata_interface::state::{Field, TokenMetadata},
+    token_metadata_update_field, Mint, Token2022, TokenMetadataUpdateField,
+};
+
+#[derive(Accounts)]
+pub struct UpdateTokenMetadata<'info> {
+    /// Pays for any extra rent when the metadata grows.
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"platform_config"],
+        bump,
+        constraint = platform_config.authority == authority.key() @ ErrorCode::UnauthorizedAccess,
+    )]
+    pub platform_config: Box<Account<'info, PlatformConfig>>,
+
+    /// CHECK: The mint_authority_pda, which is the metadata update authority.
+    #[account(
+        seeds = [MINT_AUTHORITY_SEED],
+        bump
+    )]
+    pub mint_authority_pda: AccountInfo<'info>,
+
+    #[account(mut)]
+    pub mint_account: InterfaceAccount<'info, Mint>,
+
+    pub token_program: Program<'info, Token2022>,
+    pub system_program: Program<'info, System>,
+}
+
+/// `field` is `name`, `symbol` or `uri`; any other value sets that additional metadata key.
+pub fn process_update_token_metadata(
+    ctx: Context<UpdateTokenMetadata>,
+    field: String,
+    value: String,
+) -> Result<()> {
+    msg!("--- Instruction: UpdateTokenMetadata (PDA Signed) ---");
+    msg!(
+        "Mint Account to update: {}",
+        ctx.accounts.mint_account.key()
+    );
+    msg!("Field: '{}', new value: '{}'", field, value);
+
+    require!(!field.is_empty(), ErrorCode::InvalidTokenMetadata);
+    let field = match field.as_str() {
+        "name" => Field::Name,
+        "symbol" => Field::Symbol,
+        "uri" => Field::Uri,
+        _ => Field::Key(field),
+    };
+
+    let mint_info = ctx.accounts.mint_account.to_account_info();
+    let new_len = {
+        let data = mint_info.try_borrow_data()?;
+        let mint_state = StateWithExtensions::<MintState>::unpack(&data)?;
+        let mut metadata = mint_state.get_variable_len_extension::<TokenMetadata>()?;
+        let current_size = metadata.tlv_size_of()?;
+        metadata.update(field.clone(), value.clone());
+        data.len()
+            .checked_sub(current_size)
+            .and_then(|len| len.checked_add(metadata.tlv_size_of().ok()?))
+            .ok_or(ErrorCode::CalculationError)?
+    };
+
+    // Token-2022 reallocs the mint on update but does not fund it.
+    let rent_shortfall = Rent::get()?
+        .minimum_balance(new_len)
+        .saturating_sub(mint_info.lamports());
+    if rent_shortfall > 0 {
+        msg!("Topping up mint rent by {} lamports.", rent_shortfall);
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.authority.to_account_info(),
+                    to: mint_info.clone(),
+                },
+            ),
+            rent_shortfall,
+        )?;
+    }
+
+    let bump = ctx.bumps.mint_authority_pda;
+    let pda_signer_seeds: &[&[u8]] = &[MINT_AUTHORITY_SEED, &[bump]];
+    let signer_seeds = &[pda_signer_seeds];
+
+    token_metadata_update_field(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TokenMetadataUpdateField {
+                token_program_id: ctx.accounts.token_program.to_account_info(),
+                metadata: mint_info,
+                update_authority: ctx.accounts.mint_authority_pda.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        field,
+        value,
+    )?;
+
+    msg!("token_metadata_update_field CPI successful.");
+    msg!("--- UpdateTokenMetadata finished ---");
+    Ok(())
+}
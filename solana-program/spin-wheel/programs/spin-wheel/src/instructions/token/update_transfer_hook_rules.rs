@@ -0,0 +1,59 @@
+use crate::error::ErrorCode;
+use crate::{PlatformConfig, MINT_AUTHORITY_SEED};
+use anchor_lang::prelude::*;
+use cashino_transfer_hook::{
+    cpi::accounts::UpdateTransferRules, program::CashinoTransferHook, TransferRules,
+};
+
+#[derive(Accounts)]
+pub struct UpdateTransferHookRules<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"platform_config"],
+        bump,
+        constraint = platform_config.authority == authority.key() @ ErrorCode::UnauthorizedAccess,
+    )]
+    pub platform_config: Box<Account<'info, PlatformConfig>>,
+
+    /// CHECK: The mint_authority_pda, which is the hook config authority.
+    #[account(
+        seeds = [MINT_AUTHORITY_SEED],
+        bump
+    )]
+    pub mint_authority_pda: AccountInfo<'info>,
+
+    /// CHECK: Validated by the transfer hook program.
+    #[account(mut)]
+    pub hook_config: UncheckedAccount<'info>,
+
+    pub transfer_hook_program: Program<'info, CashinoTransferHook>,
+}
+
+pub fn process_update_transfer_hook_rules(
+    ctx: Context<UpdateTransferHookRules>,
+    rules: TransferRules,
+) -> Result<()> {
+    msg!("--- Instruction: UpdateTransferHookRules (PDA Signed) ---");
+    msg!("Hook config: {}", ctx.accounts.hook_config.key());
+    msg!("New rules: {:?}", rules);
+
+    let bump = ctx.bumps.mint_authority_pda;
+    let pda_signer_seeds: &[&[u8]] = &[MINT_AUTHORITY_SEED, &[bump]];
+    let signer_seeds = &[pda_signer_seeds];
+
+    cashino_transfer_hook::cpi::update_transfer_rules(
+        CpiContext::new_with_signer(
+            ctx.accounts.transfer_hook_program.to_account_info(),
+            UpdateTransferRules {
+                authority: ctx.accounts.mint_authority_pda.to_account_info(),
+                hook_config: ctx.accounts.hook_config.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        rules,
+    )?;
+
+    msg!("--- UpdateTransferHookRules finished ---");
+    Ok(())
+}
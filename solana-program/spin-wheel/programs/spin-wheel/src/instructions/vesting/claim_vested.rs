@@ -0,0 +1,113 @@
+use crate::token_transfer::transfer_checked_with_hook;
+use crate::{ErrorCode, VestedClaimed, VestingEscrow};
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{spl_token_2022, Mint, Token2022, TokenAccount, TransferChecked},
+};
+
+#[derive(Accounts)]
+#[instruction(vesting_id: u64)]
+pub struct ClaimVested<'info> {
+    #[account(mut)]
+    pub beneficiary: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"vesting", beneficiary.key().as_ref(), &vesting_id.to_le_bytes()],
+        bump = vesting_escrow.bump,
+        has_one = beneficiary @ ErrorCode::UnauthorizedAccess,
+    )]
+    pub vesting_escrow: Box<Account<'info, VestingEscrow>>,
+
+    #[account(
+        mut,
+        associated_token::mint = cashino_mint,
+        associated_token::authority = vesting_escrow,
+        associated_token::token_program = token_program
+    )]
+    pub vesting_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(address = vesting_escrow.mint @ ErrorCode::InvalidMintAccount)]
+    pub cashino_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        init_if_needed,
+        payer = beneficiary,
+        associated_token::mint = cashino_mint,
+        associated_token::authority = beneficiary,
+        associated_token::token_program = token_program
+    )]
+    pub beneficiary_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(address = spl_token_2022::ID @ ErrorCode::InvalidTokenProgram)]
+    pub token_program: Program<'info, Token2022>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn process_claim_vested<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ClaimVested<'info>>,
+    vesting_id: u64,
+) -> Result<()> {
+    msg!("--- Instruction: ClaimVested ---");
+    msg!(
+        "Beneficiary: {}, vesting ID: {}",
+        ctx.accounts.beneficiary.key(),
+        vesting_id
+    );
+
+    let clock = Clock::get()?;
+    let claimable = ctx
+        .accounts
+        .vesting_escrow
+        .claimable_amount(clock.unix_timestamp)?;
+    require!(claimable > 0, ErrorCode::NothingVested);
+    msg!("Claimable: {} $CASHINO", claimable);
+
+    let beneficiary_key = ctx.accounts.beneficiary.key();
+    let vesting_id_bytes = vesting_id.to_le_bytes();
+    let escrow_signer_seeds: &[&[u8]] = &[
+        b"vesting".as_ref(),
+        beneficiary_key.as_ref(),
+        &vesting_id_bytes,
+        &[ctx.accounts.vesting_escrow.bump],
+    ];
+
+    transfer_checked_with_hook(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.vesting_vault.to_account_info(),
+                to: ctx.accounts.beneficiary_token_account.to_account_info(),
+                authority: ctx.accounts.vesting_escrow.to_account_info(),
+                mint: ctx.accounts.cashino_mint.to_account_info(),
+            },
+            &[escrow_signer_seeds],
+        )
+        .with_remaining_accounts(ctx.remaining_accounts.to_vec()),
+        claimable,
+        ctx.accounts.cashino_mint.decimals,
+    )?;
+
+    let vesting_escrow = &mut ctx.accounts.vesting_escrow;
+    vesting_escrow.claimed_amount = vesting_escrow
+        .claimed_amount
+        .checked_add(claimable)
+        .ok_or(ErrorCode::CalculationError)?;
+
+    emit!(VestedClaimed {
+        vesting_escrow: vesting_escrow.key(),
+        beneficiary: beneficiary_key,
+        amount: claimable,
+        total_claimed: vesting_escrow.claimed_amount,
+    });
+    msg!(
+        "Claimed {} $CASHINO; {} of {} claimed in total.",
+        claimable,
+        vesting_escrow.claimed_amount,
+        vesting_escrow.total_amount
+    );
+    msg!("--- ClaimVested finished ---");
+    Ok(())
+}
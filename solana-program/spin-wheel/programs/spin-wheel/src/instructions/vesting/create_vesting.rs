@@ -0,0 +1,195 @@
+use crate::instructions::token::mint_tokens::internal_perform_mint;
+use crate::token_transfer::transfer_checked_with_hook;
+use crate::{
+    ErrorCode, PlatformConfig, VestingCreated, VestingEscrow, VestingFunding,
+    CASHINO_MAX_SUPPLY_UNITS, MINT_AUTHORITY_SEED,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{spl_token_2022, Mint, Token2022, TokenAccount, TransferChecked},
+};
+
+#[derive(Accounts)]
+#[instruction(vesting_id: u64)]
+pub struct CreateVesting<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"platform_config"],
+        bump,
+        constraint = platform_config.authority == authority.key() @ ErrorCode::UnauthorizedAccess,
+    )]
+    pub platform_config: Box<Account<'info, PlatformConfig>>,
+
+    /// CHECK: Wallet the tokens vest to. Only used as a seed and recorded on the escrow.
+    pub beneficiary: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + std::mem::size_of::<VestingEscrow>(),
+        seeds = [b"vesting", beneficiary.key().as_ref(), &vesting_id.to_le_bytes()],
+        bump
+    )]
+    pub vesting_escrow: Box<Account<'info, VestingEscrow>>,
+
+    /// Anyone can create the escrow's ATA ahead of time, so an existing one is taken over.
+    /// Tokens already in it are not counted toward the vesting amount.
+    #[account(
+        init_if_needed,
+        payer = authority,
+        associated_token::mint = cashino_mint,
+        associated_token::authority = vesting_escrow,
+        associated_token::token_program = token_program
+    )]
+    pub vesting_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        address = platform_config.cashino_mint @ ErrorCode::InvalidMintAccount
+    )]
+    pub cashino_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Unvested tokens go back here if the escrow is revoked.
+    #[account(
+        token::mint = cashino_mint,
+        token::token_program = token_program
+    )]
+    pub treasury: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Required for `VestingFunding::Transfer`.
+    #[account(
+        mut,
+        token::mint = cashino_mint,
+        token::authority = authority,
+        token::token_program = token_program
+    )]
+    pub funding_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    /// CHECK: The mint_authority_pda, which mints for `VestingFunding::Mint`.
+    #[account(
+        seeds = [MINT_AUTHORITY_SEED],
+        bump
+    )]
+    pub mint_authority_pda: AccountInfo<'info>,
+
+    #[account(address = spl_token_2022::ID @ ErrorCode::InvalidTokenProgram)]
+    pub token_program: Program<'info, Token2022>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn process_create_vesting<'info>(
+    ctx: Context<'_, '_, 'info, 'info, CreateVesting<'info>>,
+    vesting_id: u64,
+    amount: u64,
+    start_time: i64,
+    cliff_time: i64,
+    end_time: i64,
+    revocable: bool,
+    funding: VestingFunding,
+) -> Result<()> {
+    msg!("--- Instruction: CreateVesting ---");
+    msg!("Authority: {}", ctx.accounts.authority.key());
+    msg!(
+        "Beneficiary: {}, vesting ID: {}",
+        ctx.accounts.beneficiary.key(),
+        vesting_id
+    );
+    msg!(
+        "Amount: {}, start: {}, cliff: {}, end: {}, revocable: {}, funding: {:?}",
+        amount,
+        start_time,
+        cliff_time,
+        end_time,
+        revocable,
+        funding
+    );
+
+    require!(amount > 0, ErrorCode::InvalidVestingSchedule);
+    require!(
+        start_time <= cliff_time && cliff_time <= end_time && start_time < end_time,
+        ErrorCode::InvalidVestingSchedule
+    );
+
+    let vault_balance_before = ctx.accounts.vesting_vault.amount;
+    match funding {
+        VestingFunding::Mint => {
+            internal_perform_mint(
+                &ctx.accounts.mint_authority_pda,
+                &ctx.accounts.cashino_mint,
+                &ctx.accounts.vesting_vault,
+                &ctx.accounts.token_program,
+                ctx.bumps.mint_authority_pda,
+                amount,
+                Some((
+                    CASHINO_MAX_SUPPLY_UNITS,
+                    &mut ctx.accounts.platform_config.cashino_minted,
+                )),
+                ctx.program_id,
+            )?;
+        }
+        VestingFunding::Transfer => {
+            let funding_token_account = ctx
+                .accounts
+                .funding_token_account
+                .as_ref()
+                .ok_or(ErrorCode::MissingVestingFundingAccount)?;
+            transfer_checked_with_hook(
+                CpiContext::new(
+                    ctx.accounts.token_program.to_account_info(),
+                    TransferChecked {
+                        from: funding_token_account.to_account_info(),
+                        to: ctx.accounts.vesting_vault.to_account_info(),
+                        authority: ctx.accounts.authority.to_account_info(),
+                        mint: ctx.accounts.cashino_mint.to_account_info(),
+                    },
+                )
+                .with_remaining_accounts(ctx.remaining_accounts.to_vec()),
+                amount,
+                ctx.accounts.cashino_mint.decimals,
+            )?;
+        }
+    }
+
+    // $CASHINO carries a transfer fee, so only what reached the vault vests.
+    ctx.accounts.vesting_vault.reload()?;
+    let amount_received = ctx
+        .accounts
+        .vesting_vault
+        .amount
+        .checked_sub(vault_balance_before)
+        .ok_or(ErrorCode::CalculationError)?;
+    msg!("Vault received {} $CASHINO.", amount_received);
+
+    let vesting_escrow = &mut ctx.accounts.vesting_escrow;
+    vesting_escrow.beneficiary = ctx.accounts.beneficiary.key();
+    vesting_escrow.vesting_id = vesting_id;
+    vesting_escrow.mint = ctx.accounts.cashino_mint.key();
+    vesting_escrow.total_amount = amount_received;
+    vesting_escrow.claimed_amount = 0;
+    vesting_escrow.start_time = start_time;
+    vesting_escrow.cliff_time = cliff_time;
+    vesting_escrow.end_time = end_time;
+    vesting_escrow.revocable = revocable;
+    vesting_escrow.revoked_at = 0;
+    vesting_escrow.treasury = ctx.accounts.treasury.key();
+    vesting_escrow.bump = ctx.bumps.vesting_escrow;
+
+    emit!(VestingCreated {
+        vesting_escrow: vesting_escrow.key(),
+        beneficiary: vesting_escrow.beneficiary,
+        vesting_id,
+        amount: amount_received,
+        start_time,
+        cliff_time,
+        end_time,
+        revocable,
+    });
+    msg!("--- CreateVesting finished ---");
+    Ok(())
+}
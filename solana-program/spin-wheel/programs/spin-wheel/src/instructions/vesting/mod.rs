@@ -0,0 +1,8 @@
+pub mod create_vesting;
+pub use create_vesting::*;
+
+pub mod claim_vested;
+pub use claim_vested::*;
+
+pub mod revoke_vesting;
+pub use revoke_vesting::*;
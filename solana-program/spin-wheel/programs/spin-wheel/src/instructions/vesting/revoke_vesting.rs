@@ -0,0 +1,105 @@
+use crate::token_transfer::transfer_checked_with_hook;
+use crate::{ErrorCode, PlatformConfig, VestingEscrow, VestingRevoked};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{spl_token_2022, Mint, Token2022, TokenAccount, TransferChecked};
+
+#[derive(Accounts)]
+#[instruction(beneficiary: Pubkey, vesting_id: u64)]
+pub struct RevokeVesting<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"platform_config"],
+        bump,
+        constraint = platform_config.authority == authority.key() @ ErrorCode::UnauthorizedAccess,
+    )]
+    pub platform_config: Box<Account<'info, PlatformConfig>>,
+
+    #[account(
+        mut,
+        seeds = [b"vesting", beneficiary.as_ref(), &vesting_id.to_le_bytes()],
+        bump = vesting_escrow.bump,
+        constraint = vesting_escrow.revocable @ ErrorCode::VestingNotRevocable,
+        constraint = vesting_escrow.revoked_at == 0 @ ErrorCode::VestingAlreadyRevoked,
+    )]
+    pub vesting_escrow: Box<Account<'info, VestingEscrow>>,
+
+    #[account(
+        mut,
+        associated_token::mint = cashino_mint,
+        associated_token::authority = vesting_escrow,
+        associated_token::token_program = token_program
+    )]
+    pub vesting_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(address = vesting_escrow.mint @ ErrorCode::InvalidMintAccount)]
+    pub cashino_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        address = vesting_escrow.treasury @ ErrorCode::InvalidVestingTreasury
+    )]
+    pub treasury: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(address = spl_token_2022::ID @ ErrorCode::InvalidTokenProgram)]
+    pub token_program: Program<'info, Token2022>,
+}
+
+/// Stops vesting now. What has vested stays claimable by the beneficiary; the rest goes back
+/// to the treasury recorded at creation.
+pub fn process_revoke_vesting<'info>(
+    ctx: Context<'_, '_, 'info, 'info, RevokeVesting<'info>>,
+    beneficiary: Pubkey,
+    vesting_id: u64,
+) -> Result<()> {
+    msg!("--- Instruction: RevokeVesting ---");
+    msg!("Authority: {}", ctx.accounts.authority.key());
+    msg!("Beneficiary: {}, vesting ID: {}", beneficiary, vesting_id);
+
+    let clock = Clock::get()?;
+    let vesting_escrow = &ctx.accounts.vesting_escrow;
+    let vested = vesting_escrow.vested_amount(clock.unix_timestamp)?;
+    let unvested = vesting_escrow
+        .total_amount
+        .checked_sub(vested)
+        .ok_or(ErrorCode::CalculationError)?;
+    msg!("Vested: {}, returning unvested: {}", vested, unvested);
+
+    if unvested > 0 {
+        let vesting_id_bytes = vesting_id.to_le_bytes();
+        let escrow_signer_seeds: &[&[u8]] = &[
+            b"vesting".as_ref(),
+            beneficiary.as_ref(),
+            &vesting_id_bytes,
+            &[vesting_escrow.bump],
+        ];
+
+        transfer_checked_with_hook(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.vesting_vault.to_account_info(),
+                    to: ctx.accounts.treasury.to_account_info(),
+                    authority: vesting_escrow.to_account_info(),
+                    mint: ctx.accounts.cashino_mint.to_account_info(),
+                },
+                &[escrow_signer_seeds],
+            )
+            .with_remaining_accounts(ctx.remaining_accounts.to_vec()),
+            unvested,
+            ctx.accounts.cashino_mint.decimals,
+        )?;
+    }
+
+    let vesting_escrow = &mut ctx.accounts.vesting_escrow;
+    vesting_escrow.revoked_at = clock.unix_timestamp;
+
+    emit!(VestingRevoked {
+        vesting_escrow: vesting_escrow.key(),
+        beneficiary,
+        returned_amount: unvested,
+        revoked_at: vesting_escrow.revoked_at,
+    });
+    msg!("--- RevokeVesting finished ---");
+    Ok(())
+}
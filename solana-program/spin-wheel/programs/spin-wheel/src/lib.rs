@@ -8,6 +8,11 @@ declare_id!("AUSCcZQqFbidkXRMrPzNRy9irum2StmJX1Fdit3ejoXs");
 mod error;
 pub use error::ErrorCode;
 
+mod accounting;
+mod token_transfer;
+mod migrations;
+pub use migrations::{GAME_STATE_VERSION, USER_PLATFORM_ESCROW_VERSION};
+
 pub const MINT_AUTHORITY_SEED: &[u8] = b"mint_authority";
 pub const INITIAL_GAME_HOUSE_FEE_BASIS_POINTS: u16 = 10;
 pub const MAX_GAME_HOUSE_FEE_BASIS_POINTS: u16 = 500;
@@ -21,329 +26,3028 @@ const MIN_ROUND_DURATION: i64 = 1;
 const MAX_ROUND_DURATION: i64 = 300;
 const SEED_BYTES_LENGTH: usize = 32;
 pub const CASHINO_REWARD_PER_ROUND_UNITS: u64 = 1_000_000;
-pub const WITHDRAWAL_FEE_LAMPORTS: u64 = 10_000_000;
+/// Hard cap on CASHINO supply in base units, whatever the mint's decimals (1B tokens at the default 2).
+pub const CASHINO_MAX_SUPPLY_UNITS: u64 = 100_000_000_000;
+pub const DEFAULT_WITHDRAWAL_MIN_FEE_LAMPORTS: u64 = 10_000_000;
+pub const MAX_WITHDRAWAL_FEE_BASIS_POINTS: u16 = 500;
+pub const MAX_FEE_DISCOUNT_TIERS: usize = 4;
+/// Spare bytes at the end of versioned accounts so later fields can be added without a realloc.
+pub const ACCOUNT_RESERVED_BYTES: usize = 128;
+/// Serialized size of `TableLeaderboards`, carved out of `GameState`'s reserved bytes in version 2.
+pub const TABLE_LEADERBOARDS_LEN: usize = 2 * 8 + 2;
+/// Serialized size of `EmissionSchedule`, carved out of `GameState`'s reserved bytes in version 3.
+pub const EMISSION_SCHEDULE_LEN: usize = 7 * 8 + 2;
+/// Serialized size of `BurnConfig`, carved out of `GameState`'s reserved bytes in version 4.
+pub const BURN_CONFIG_LEN: usize = 1 + 2 * 8;
+pub const GAME_STATE_RESERVED_BYTES: usize =
+    ACCOUNT_RESERVED_BYTES - TABLE_LEADERBOARDS_LEN - EMISSION_SCHEDULE_LEN - BURN_CONFIG_LEN;
+pub const STAKING_ACC_PRECISION: u128 = 1_000_000_000_000;
+pub const MAX_UNSTAKE_COOLDOWN: i64 = 30 * 24 * 60 * 60;
+/// LP deposits and withdrawals wait at least this long, enough for a fixed-odds round to run
+/// from its last bet to its settlement.
+pub const MIN_LP_WITHDRAWAL_DELAY: i64 = 2 * MAX_ROUND_DURATION;
+pub const MAX_LP_WITHDRAWAL_DELAY: i64 = 30 * 24 * 60 * 60;
+pub const HOUSE_LP_MINT_DECIMALS: u8 = 9;
+pub const MAX_FEE_RECIPIENTS: usize = 4;
+pub const MAX_REFERRAL_SHARE_BASIS_POINTS: u16 = 5_000;
+pub const MAX_JACKPOT_CONTRIBUTION_BASIS_POINTS: u16 = 1_000;
+pub const MAX_PAYOUT_TIERS: usize = 5;
+pub const MAX_WHEEL_SEGMENTS: usize = 8;
+const SINGLE_WINNER_PAYOUT_TIERS: [u16; 1] = [10_000];
+pub const SECONDS_PER_DAY: i64 = 24 * 60 * 60;
+pub const SECONDS_PER_WEEK: i64 = 7 * SECONDS_PER_DAY;
+pub const GAMING_LIMIT_LOOSENING_COOLDOWN: i64 = SECONDS_PER_DAY;
+pub const MAX_WITHDRAWAL_REVIEW_DELAY: i64 = 7 * SECONDS_PER_DAY;
+/// Instant withdrawals within this long of the first one in a window count together against
+/// the review threshold.
+pub const WITHDRAWAL_REVIEW_WINDOW: i64 = SECONDS_PER_DAY;
+/// Shortest claim window a table can set before its unclaimed rewards become burnable.
+pub const MIN_UNCLAIMED_REWARD_CLAIM_WINDOW: i64 = 7 * SECONDS_PER_DAY;
+/// Rounds kept per table. Sized so the history account stays under the 10 KiB limit on
+/// accounts the program can create.
+pub const ROUND_HISTORY_CAPACITY: usize = 64;
+pub const LEADERBOARD_SIZE: usize = 10;
+pub const MAX_RAKEBACK_TIERS: usize = 4;
+pub const MAX_RAKEBACK_BASIS_POINTS: u16 = 5_000;
+pub const MAX_TOKEN_METADATA_ADDITIONAL_FIELDS: usize = 8;
 
 pub type SeedArray = [u8; SEED_BYTES_LENGTH];
 
 #[account]
-#[derive(Default, Debug)]
+#[derive(Debug)]
 pub struct UserPlatformEscrow {
+    /// Layout version, see `migrations`.
+    pub version: u8,
     pub user_authority: Pubkey,
     pub balance: u64,
     pub bump: u8,
+    /// Self-imposed responsible-gaming limits currently in force.
+    pub limits: GamingLimits,
+    /// Loosened limits that replace `limits` once `pending_limits_effective_at` has passed.
+    pub pending_limits: GamingLimits,
+    /// Zero when no loosening is queued.
+    pub pending_limits_effective_at: i64,
+    /// Betting and deposits, in SOL or tokens, are rejected until this timestamp.
+    pub self_excluded_until: i64,
+    pub deposit_day: i64,
+    pub daily_deposited: u64,
+    pub deposit_week: i64,
+    pub weekly_deposited: u64,
+    pub wager_day: i64,
+    pub daily_wagered: u64,
+    pub daily_won: u64,
+    pub withdrawal_window_start: i64,
+    /// Lamports withdrawn instantly since `withdrawal_window_start`.
+    pub window_withdrawn: u64,
+    /// Set when the risk admin blocks a withdrawal; only the authority can clear it.
+    pub withdrawals_frozen: bool,
+    pub reserved: [u8; ACCOUNT_RESERVED_BYTES],
 }
 
-#[account]
-#[derive(Debug)]
-pub struct GameState {
-    pub authority: Pubkey,
-    pub house_wallet: Pubkey,
-    pub house_fee_basis_points: u16,
-    pub round_counter: u64,
-    pub is_initialized: bool,
-    pub cashino_mint: Pubkey,
-}
-
-impl Default for GameState {
+impl Default for UserPlatformEscrow {
     fn default() -> Self {
         Self {
-            authority: Pubkey::default(),
-            house_wallet: Pubkey::default(),
-            house_fee_basis_points: INITIAL_GAME_HOUSE_FEE_BASIS_POINTS,
-            round_counter: 0,
-            is_initialized: false,
-            cashino_mint: Pubkey::default(),
+            version: USER_PLATFORM_ESCROW_VERSION,
+            user_authority: Pubkey::default(),
+            balance: 0,
+            bump: 0,
+            limits: GamingLimits::default(),
+            pending_limits: GamingLimits::default(),
+            pending_limits_effective_at: 0,
+            self_excluded_until: 0,
+            deposit_day: 0,
+            daily_deposited: 0,
+            deposit_week: 0,
+            weekly_deposited: 0,
+            wager_day: 0,
+            daily_wagered: 0,
+            daily_won: 0,
+            withdrawal_window_start: 0,
+            window_withdrawn: 0,
+            withdrawals_frozen: false,
+            reserved: [0; ACCOUNT_RESERVED_BYTES],
         }
     }
 }
 
-#[derive(Copy, Clone, AnchorSerialize, AnchorDeserialize, Default, Debug, Pod, Zeroable)]
-#[repr(C)]
-pub struct PlayerData {
-    pub pubkey: Pubkey,
-    pub amount: u64,
+/// Lamport limits chosen by the user. Zero means no limit.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, Debug, PartialEq)]
+pub struct GamingLimits {
+    pub daily_deposit_limit: u64,
+    pub weekly_deposit_limit: u64,
+    pub per_round_wager_limit: u64,
+    pub daily_wager_limit: u64,
+    /// Cap on the day's wagers minus the day's winnings.
+    pub daily_loss_limit: u64,
 }
 
-#[account]
-#[derive(Default, Debug)]
-pub struct GamePotSol {}
-
-#[account]
-#[derive(Default, Debug)]
-pub struct RoundCashinoRewardsPot {
-    pub round_id: u64,
-    pub total_minted_for_round: u64,
-}
+impl GamingLimits {
+    fn tighter(current: u64, requested: u64) -> u64 {
+        match (current, requested) {
+            (0, r) => r,
+            (c, 0) => c,
+            (c, r) => c.min(r),
+        }
+    }
 
-#[derive(Copy, Clone, Default, Debug, Pod, Zeroable, AnchorSerialize, AnchorDeserialize)]
-#[repr(C)]
-pub struct PlayerCashinoRewards {
-    pub player: Pubkey,
-    pub sol_bet_amount: u64,
-    pub cashino_reward_amount: u64,
-    pub claimed_val: u8,
-    pub _padding_pcr: [u8; 7],
-}
+    /// The strictest of both sets of limits, field by field.
+    pub fn tightest(&self, other: &GamingLimits) -> GamingLimits {
+        GamingLimits {
+            daily_deposit_limit: Self::tighter(self.daily_deposit_limit, other.daily_deposit_limit),
+            weekly_deposit_limit: Self::tighter(self.weekly_deposit_limit, other.weekly_deposit_limit),
+            per_round_wager_limit: Self::tighter(self.per_round_wager_limit, other.per_round_wager_limit),
+            daily_wager_limit: Self::tighter(self.daily_wager_limit, other.daily_wager_limit),
+            daily_loss_limit: Self::tighter(self.daily_loss_limit, other.daily_loss_limit),
+        }
+    }
 
-#[repr(u8)]
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, Default)]
-pub enum RoundStatus {
-    #[default]
-    Active = 0,
-    AwaitingSolClaim = 1,
-    SolClaimed = 2,
-    RewardPotAccountsCreated = 3,
-    TokensMintedForRewards = 4,
-    RewardsProcessed = 5,
+    fn within(limit: u64, value: u64) -> bool {
+        limit == 0 || value <= limit
+    }
 }
 
-#[account(zero_copy)]
-#[repr(C)]
-#[derive(Debug, Default)]
-pub struct RoundState {
-    pub id: u64,
-    pub start_time: i64,
-    pub end_time: i64,
-    pub seed_commitment: SeedArray,
+impl UserPlatformEscrow {
+    /// Promotes queued limits whose cooldown has passed and restarts the day/week counters.
+    pub fn refresh_gaming_limits(&mut self, now: i64) {
+        if self.pending_limits_effective_at != 0 && now >= self.pending_limits_effective_at {
+            self.limits = self.pending_limits;
+            self.pending_limits = GamingLimits::default();
+            self.pending_limits_effective_at = 0;
+        }
+        let today = now / SECONDS_PER_DAY;
+        if self.deposit_day != today {
+            self.deposit_day = today;
+            self.daily_deposited = 0;
+        }
+        let this_week = now / SECONDS_PER_WEEK;
+        if self.deposit_week != this_week {
+            self.deposit_week = this_week;
+            self.weekly_deposited = 0;
+        }
+        if self.wager_day != today {
+            self.wager_day = today;
+            self.daily_wagered = 0;
+            self.daily_won = 0;
+        }
+    }
 
-    pub has_revealed_seed_val: u8,
-    pub _padding0: [u8; 7],
-    pub revealed_seed: SeedArray,
+    /// Tightened limits apply immediately; loosened ones wait for the cooldown.
+    pub fn request_gaming_limits(&mut self, requested: GamingLimits, now: i64) -> Result<()> {
+        self.refresh_gaming_limits(now);
+        let tightened = self.limits.tightest(&requested);
+        self.limits = tightened;
+        if tightened == requested {
+            self.pending_limits = GamingLimits::default();
+            self.pending_limits_effective_at = 0;
+        } else {
+            self.pending_limits = requested;
+            self.pending_limits_effective_at = now
+                .checked_add(GAMING_LIMIT_LOOSENING_COOLDOWN)
+                .ok_or(ErrorCode::CalculationError)?;
+        }
+        Ok(())
+    }
 
-    pub total_sol_pot: u64,
-    pub player_count: u8,
+    pub fn require_not_self_excluded(&self, now: i64) -> Result<()> {
+        require!(now >= self.self_excluded_until, ErrorCode::SelfExcluded);
+        Ok(())
+    }
 
-    pub _padding1: [u8; 7],
-    pub players: [PlayerData; MAX_PLAYERS],
+    pub fn record_deposit(&mut self, amount: u64, now: i64) -> Result<()> {
+        self.refresh_gaming_limits(now);
+        self.require_not_self_excluded(now)?;
+        let daily = self.daily_deposited.checked_add(amount).ok_or(ErrorCode::CalculationError)?;
+        let weekly = self.weekly_deposited.checked_add(amount).ok_or(ErrorCode::CalculationError)?;
+        require!(
+            GamingLimits::within(self.limits.daily_deposit_limit, daily)
+                && GamingLimits::within(self.limits.weekly_deposit_limit, weekly),
+            ErrorCode::DepositLimitExceeded
+        );
+        self.daily_deposited = daily;
+        self.weekly_deposited = weekly;
+        Ok(())
+    }
 
-    pub player_cashino_rewards: [PlayerCashinoRewards; MAX_PLAYERS],
+    /// `round_total` is the player's total stake in the round including this wager.
+    pub fn record_wager(&mut self, amount: u64, round_total: u64, now: i64) -> Result<()> {
+        self.refresh_gaming_limits(now);
+        self.require_not_self_excluded(now)?;
+        let daily = self.daily_wagered.checked_add(amount).ok_or(ErrorCode::CalculationError)?;
+        require!(
+            GamingLimits::within(self.limits.per_round_wager_limit, round_total)
+                && GamingLimits::within(self.limits.daily_wager_limit, daily),
+            ErrorCode::WagerLimitExceeded
+        );
+        require!(
+            GamingLimits::within(self.limits.daily_loss_limit, daily.saturating_sub(self.daily_won)),
+            ErrorCode::LossLimitExceeded
+        );
+        self.daily_wagered = daily;
+        Ok(())
+    }
 
-    pub total_cashino_minted_for_round: u64,
+    pub fn record_winnings(&mut self, amount: u64, now: i64) -> Result<()> {
+        self.refresh_gaming_limits(now);
+        self.daily_won = self.daily_won.checked_add(amount).ok_or(ErrorCode::CalculationError)?;
+        Ok(())
+    }
 
-    pub status_discriminant: u8,
+    /// Instant withdrawals in the current window plus `amount`. A window closes
+    /// `WITHDRAWAL_REVIEW_WINDOW` after it opened.
+    pub fn withdrawn_in_window_with(&self, amount: u64, now: i64) -> Result<u64> {
+        let window_open = now < self.withdrawal_window_start.saturating_add(WITHDRAWAL_REVIEW_WINDOW);
+        let withdrawn = if window_open { self.window_withdrawn } else { 0 };
+        withdrawn
+            .checked_add(amount)
+            .ok_or(error!(ErrorCode::CalculationError))
+    }
 
-    pub has_winner_val: u8,
-    pub winner_index_val: u8,
+    /// Counts an instant withdrawal against the review threshold. Once the window's total would
+    /// exceed it, withdrawals have to go through `request_sol_withdrawal`.
+    pub fn record_instant_withdrawal(
+        &mut self,
+        amount: u64,
+        review_threshold: u64,
+        now: i64,
+    ) -> Result<()> {
+        require!(!self.withdrawals_frozen, ErrorCode::EscrowWithdrawalsFrozen);
+        let withdrawn = self.withdrawn_in_window_with(amount, now)?;
+        require!(
+            review_threshold == 0 || withdrawn <= review_threshold,
+            ErrorCode::WithdrawalRequiresReview
+        );
+        if now >= self.withdrawal_window_start.saturating_add(WITHDRAWAL_REVIEW_WINDOW) {
+            self.withdrawal_window_start = now;
+        }
+        self.window_withdrawn = withdrawn;
+        Ok(())
+    }
+}
 
-    pub _padding_to_align_house_fee: [u8; 5],
+#[account]
+#[derive(Default, Debug)]
+pub struct UserTokenEscrow {
+    pub user_authority: Pubkey,
+    pub mint: Pubkey,
+    pub balance: u64,
+    pub bump: u8,
+}
 
-    pub house_sol_fee: u64,
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, Debug, PartialEq, Eq)]
+pub struct FeeRecipient {
+    pub wallet: Pubkey,
+    pub share_basis_points: u16,
+}
 
-    pub winner_sol_pubkey: Pubkey,
-    pub winner_sol_amount: u64,
-    pub winner_sol_claimed: u8,
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, Debug, PartialEq, Eq)]
+pub struct FeeDiscountTier {
+    /// CASHINO held or staked (base units) needed to qualify.
+    pub min_cashino_amount: u64,
+    pub discount_basis_points: u16,
+}
 
-    pub _final_padding_for_struct: [u8; 7],
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, Debug)]
+pub struct WithdrawalFeeSchedule {
+    pub min_fee_lamports: u64,
+    /// Charged on the withdrawn amount on top of `min_fee_lamports`.
+    pub fee_basis_points: u16,
+    /// Upper bound on the fee before discounts. Zero means uncapped.
+    pub max_fee_lamports: u64,
+    pub discount_tiers: [FeeDiscountTier; MAX_FEE_DISCOUNT_TIERS],
+    pub discount_tier_count: u8,
+    /// Withdrawals between these timestamps are free. Equal values disable the window.
+    pub fee_free_start: i64,
+    pub fee_free_end: i64,
 }
 
-impl RoundState {
-    pub fn initialize_new(
-        &mut self,
-        id: u64,
-        start_time: i64,
-        end_time: i64,
-        seed_commitment: SeedArray,
-    ) {
-        self.id = id;
-        self.start_time = start_time;
-        self.end_time = end_time;
-        self.seed_commitment = seed_commitment;
-        self.has_revealed_seed_val = 0;
-        self.total_sol_pot = 0;
-        self.player_count = 0;
-        self.total_cashino_minted_for_round = 0;
-        self.status_discriminant = RoundStatus::Active as u8;
-        self.has_winner_val = 0;
-        self.winner_index_val = 0;
-        self.house_sol_fee = 0;
-        self.winner_sol_pubkey = Pubkey::default();
-        self.winner_sol_amount = 0;
-        self.winner_sol_claimed = 0;
+impl WithdrawalFeeSchedule {
+    pub fn default_schedule() -> Self {
+        WithdrawalFeeSchedule {
+            min_fee_lamports: DEFAULT_WITHDRAWAL_MIN_FEE_LAMPORTS,
+            ..Default::default()
+        }
     }
 
-    pub fn get_revealed_seed(&self) -> Option<SeedArray> {
-        if self.has_revealed_seed_val == 1 {
-            Some(self.revealed_seed)
-        } else {
-            None
+    /// Fee for withdrawing `amount` by a user holding `cashino_holding` CASHINO at `now`.
+    pub fn fee_for(&self, amount: u64, cashino_holding: u64, now: i64) -> Result<u64> {
+        if now >= self.fee_free_start && now < self.fee_free_end {
+            return Ok(0);
         }
+        let variable_fee = amount
+            .checked_mul(self.fee_basis_points as u64)
+            .and_then(|v| v.checked_div(10_000))
+            .ok_or(ErrorCode::CalculationError)?;
+        let mut fee = self
+            .min_fee_lamports
+            .checked_add(variable_fee)
+            .ok_or(ErrorCode::CalculationError)?;
+        if self.max_fee_lamports > 0 {
+            fee = fee.min(self.max_fee_lamports);
+        }
+
+        let discount_basis_points = self.discount_tiers[..self.discount_tier_count as usize]
+            .iter()
+            .filter(|tier| cashino_holding >= tier.min_cashino_amount)
+            .map(|tier| tier.discount_basis_points)
+            .max()
+            .unwrap_or(0);
+        let discount = fee
+            .checked_mul(discount_basis_points as u64)
+            .and_then(|v| v.checked_div(10_000))
+            .ok_or(ErrorCode::CalculationError)?;
+        Ok(fee.saturating_sub(discount))
     }
+}
 
-    pub fn set_revealed_seed(&mut self, seed: Option<SeedArray>) {
-        if let Some(s_val) = seed {
-            self.revealed_seed = s_val;
-            self.has_revealed_seed_val = 1;
-        } else {
-            self.has_revealed_seed_val = 0;
-        }
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, Debug, PartialEq, Eq)]
+pub struct RakebackTier {
+    /// Lifetime lamports wagered (`PlayerStats.total_wagered`) needed to qualify.
+    pub min_total_wagered: u64,
+    pub rakeback_basis_points: u16,
+}
+
+/// Share of the house fee returned to the bettors who paid it. Rates are in basis points of
+/// each bettor's pro-rata share of the fee.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, Debug)]
+pub struct RakebackSchedule {
+    pub base_basis_points: u16,
+    /// Loyalty tiers, ordered by `min_total_wagered`.
+    pub tiers: [RakebackTier; MAX_RAKEBACK_TIERS],
+    pub tier_count: u8,
+}
+
+impl RakebackSchedule {
+    /// The best rate the bettor's lifetime volume qualifies for.
+    pub fn rate_for(&self, total_wagered: u64) -> u16 {
+        self.tiers[..self.tier_count as usize]
+            .iter()
+            .filter(|tier| total_wagered >= tier.min_total_wagered)
+            .map(|tier| tier.rakeback_basis_points)
+            .fold(self.base_basis_points, u16::max)
     }
 
-    pub fn get_winner_index(&self) -> Option<u8> {
-        if self.has_winner_val == 1 {
-            Some(self.winner_index_val)
-        } else {
-            None
-        }
+    /// Highest rate any bettor can get.
+    pub fn max_rate(&self) -> u16 {
+        self.tiers[..self.tier_count as usize]
+            .iter()
+            .map(|tier| tier.rakeback_basis_points)
+            .fold(self.base_basis_points, u16::max)
     }
+}
 
-    pub fn set_winner_index(&mut self, index: Option<u8>) {
-        if let Some(i_val) = index {
-            self.winner_index_val = i_val;
-            self.has_winner_val = 1;
-        } else {
-            self.has_winner_val = 0;
-        }
+#[account]
+#[derive(Default, Debug)]
+pub struct PlatformConfig {
+    pub authority: Pubkey,
+    pub house_wallet: Pubkey,
+    pub cashino_mint: Pubkey,
+    pub table_count: u64,
+    pub is_initialized: bool,
+    /// House fees are split across these recipients. With no recipients everything goes to house_wallet.
+    pub fee_recipients: [FeeRecipient; MAX_FEE_RECIPIENTS],
+    pub fee_recipient_count: u8,
+    /// Recipient that receives the rounding remainder of each split.
+    pub fee_dust_recipient_index: u8,
+    /// Share of the house fee attributable to a referred player that accrues to their referrer.
+    pub referral_share_basis_points: u16,
+    /// Can block time-locked withdrawals while their delay is running.
+    pub risk_admin: Pubkey,
+    /// Withdrawals above this many lamports are time-locked. Zero keeps every withdrawal instant.
+    pub withdrawal_review_threshold: u64,
+    pub withdrawal_review_delay: i64,
+    pub withdrawal_fee: WithdrawalFeeSchedule,
+    pub rakeback: RakebackSchedule,
+    /// CASHINO minted by the program so far. Burns do not lower it, so supply caps checked
+    /// against it cannot be refilled by burning.
+    pub cashino_minted: u64,
+    /// CASHINO token account `collect_transfer_fees` withdraws the mint's withheld fees to.
+    /// `Pubkey::default()` until `set_fee_treasury` is called.
+    pub fee_treasury: Pubkey,
+    /// Transfer fees withdrawn from the mint so far, including any burned share, in CASHINO base units.
+    pub transfer_fees_collected: u64,
+    /// Share of each transfer fee withdrawal burned before the rest goes to `fee_treasury`.
+    pub fee_burn_basis_points: u16,
+    pub fees_burned: u64,
+}
+
+impl PlatformConfig {
+    pub fn fee_burn_amount(&self, amount: u64) -> Result<u64> {
+        let burn_amount = (amount as u128)
+            .checked_mul(self.fee_burn_basis_points as u128)
+            .and_then(|v| v.checked_div(10_000))
+            .ok_or(ErrorCode::CalculationError)?;
+        u64::try_from(burn_amount).map_err(|_| error!(ErrorCode::CalculationError))
     }
+}
 
-    pub fn get_status(&self) -> Result<RoundStatus> {
-        match self.status_discriminant {
-            0 => Ok(RoundStatus::Active),
-            1 => Ok(RoundStatus::AwaitingSolClaim),
-            2 => Ok(RoundStatus::SolClaimed),
-            3 => Ok(RoundStatus::RewardPotAccountsCreated),
-            4 => Ok(RoundStatus::TokensMintedForRewards),
-            5 => Ok(RoundStatus::RewardsProcessed),
-            _ => Err(error!(ErrorCode::InvalidStatusDiscriminant)),
+#[account]
+#[derive(Default, Debug)]
+pub struct PendingWithdrawal {
+    pub user: Pubkey,
+    pub amount: u64,
+    /// Withdrawal fee held alongside `amount`, distributed when the withdrawal executes.
+    pub fee: u64,
+    pub requested_at: i64,
+    pub executable_at: i64,
+    pub bump: u8,
+}
+
+/// Lifetime betting statistics for a player, in lamports unless noted.
+#[account]
+#[derive(Default, Debug)]
+pub struct PlayerStats {
+    pub player: Pubkey,
+    pub total_wagered: u64,
+    pub rounds_played: u64,
+    pub rounds_won: u64,
+    pub total_sol_won: u64,
+    /// Winnings minus wagers.
+    pub net_pnl: i64,
+    pub largest_win: u64,
+    /// CASHINO claimed as round rewards, in base units.
+    pub cashino_earned: u64,
+    pub last_played_at: i64,
+    pub bump: u8,
+}
+
+impl PlayerStats {
+    /// `first_bet_in_round` counts the round towards `rounds_played`.
+    pub fn record_wager(&mut self, amount: u64, first_bet_in_round: bool, now: i64) -> Result<()> {
+        self.total_wagered = self.total_wagered.checked_add(amount).ok_or(ErrorCode::CalculationError)?;
+        if first_bet_in_round {
+            self.rounds_played = self.rounds_played.checked_add(1).ok_or(ErrorCode::CalculationError)?;
         }
+        let signed_amount = i64::try_from(amount).map_err(|_| ErrorCode::CalculationError)?;
+        self.net_pnl = self.net_pnl.checked_sub(signed_amount).ok_or(ErrorCode::CalculationError)?;
+        self.last_played_at = now;
+        Ok(())
     }
 
-    pub fn set_status(&mut self, new_status: RoundStatus) {
-        self.status_discriminant = new_status as u8;
+    pub fn record_win(&mut self, amount: u64) -> Result<()> {
+        self.rounds_won = self.rounds_won.checked_add(1).ok_or(ErrorCode::CalculationError)?;
+        self.total_sol_won = self.total_sol_won.checked_add(amount).ok_or(ErrorCode::CalculationError)?;
+        let signed_amount = i64::try_from(amount).map_err(|_| ErrorCode::CalculationError)?;
+        self.net_pnl = self.net_pnl.checked_add(signed_amount).ok_or(ErrorCode::CalculationError)?;
+        self.largest_win = self.largest_win.max(amount);
+        Ok(())
+    }
+
+    pub fn record_cashino_earned(&mut self, amount: u64) -> Result<()> {
+        self.cashino_earned = self.cashino_earned.checked_add(amount).ok_or(ErrorCode::CalculationError)?;
+        Ok(())
     }
 }
 
-#[program]
-pub mod spin_wheel {
-    use super::*;
+#[account]
+#[derive(Default, Debug)]
+pub struct ReferralAccount {
+    pub user: Pubkey,
+    pub referrer: Pubkey,
+    pub registered_at: i64,
+    pub bump: u8,
+}
 
-    pub fn initialize_token_2022(
-        ctx: Context<InitializeToken2022>,
-        transfer_fee_basis_points: u16,
-        maximum_fee: u64,
-    ) -> Result<()> {
-        instructions::initialize::process_initialize(ctx, transfer_fee_basis_points, maximum_fee)
+#[account]
+#[derive(Default, Debug)]
+pub struct ReferrerStats {
+    pub referrer: Pubkey,
+    pub referred_count: u64,
+    /// Lamports held by this account that the referrer has not claimed yet.
+    pub accrued_lamports: u64,
+    pub total_earned_lamports: u64,
+    pub bump: u8,
+}
+
+#[account]
+#[derive(Debug)]
+pub struct GameState {
+    /// Layout version, see `migrations`.
+    pub version: u8,
+    pub authority: Pubkey,
+    pub house_wallet: Pubkey,
+    pub house_fee_basis_points: u16,
+    pub round_counter: u64,
+    pub is_initialized: bool,
+    pub cashino_mint: Pubkey,
+    pub table_id: u64,
+    pub min_bet_amount: u64,
+    pub max_bet_amount: u64,
+    pub round_duration: i64,
+    pub is_retired: bool,
+    /// Mint the table is denominated in. `Pubkey::default()` means native SOL.
+    pub bet_mint: Pubkey,
+    pub payout_mode: PayoutMode,
+    /// Share of the net pot for each place, in basis points. Only used in `PayoutMode::Tiered`.
+    pub payout_tiers: [u16; MAX_PAYOUT_TIERS],
+    pub payout_tier_count: u8,
+    pub game_mode: GameMode,
+    /// Segments of the fixed-odds wheel. Only used in `GameMode::FixedOdds`.
+    pub wheel_segments: [WheelSegment; MAX_WHEEL_SEGMENTS],
+    pub wheel_segment_count: u8,
+    pub leaderboards: TableLeaderboards,
+    pub emission: EmissionSchedule,
+    pub burn: BurnConfig,
+    pub reserved: [u8; GAME_STATE_RESERVED_BYTES],
+}
+
+impl GameState {
+    pub fn is_token_table(&self) -> bool {
+        self.bet_mint != Pubkey::default()
     }
 
-    pub fn mint_tokens_to_account(ctx: Context<MintTokensToAccount>, amount: u64) -> Result<()> {
-        instructions::mint_tokens::process_mint_tokens(ctx, amount)
+    /// Basis-point share for each place, first place first.
+    pub fn active_payout_tiers(&self) -> &[u16] {
+        match self.payout_mode {
+            PayoutMode::SingleWinner => &SINGLE_WINNER_PAYOUT_TIERS,
+            PayoutMode::Tiered => &self.payout_tiers[..self.payout_tier_count as usize],
+        }
     }
 
-    pub fn transfer(ctx: Context<Transfer>, amount: u64) -> Result<()> {
-        instructions::transfer::process_transfer(ctx, amount)
+    pub fn active_wheel_segments(&self) -> &[WheelSegment] {
+        &self.wheel_segments[..self.wheel_segment_count as usize]
     }
+}
+
+/// CASHINO minted into each round's reward pot. An all-zero schedule, as on tables created
+/// before emissions were configurable, emits `CASHINO_REWARD_PER_ROUND_UNITS` per round up to
+/// `CASHINO_MAX_SUPPLY_UNITS`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, Debug, PartialEq, Eq)]
+pub struct EmissionSchedule {
+    pub initial_per_round: u64,
+    /// Emission halves every this many rounds. Zero disables round-based halving.
+    pub halving_interval_rounds: u64,
+    /// Emission halves every this many seconds after `schedule_start`. Zero disables time-based halving.
+    pub halving_interval_seconds: i64,
+    pub schedule_start: i64,
+    /// The table stops emitting once the program has minted this much CASHINO in total.
+    pub max_supply: u64,
+    /// Round pot, in the table's bet units, that earns the full emission. Zero disables volume scaling.
+    pub volume_reference: u64,
+    /// Ceiling on the volume multiplier, in basis points of the halved emission.
+    pub volume_multiplier_cap_basis_points: u16,
+    pub total_emitted: u64,
+}
 
-    pub fn harvest<'info>(ctx: Context<'_, '_, 'info, 'info, Harvest<'info>>) -> Result<()> {
-        instructions::harvest::process_harvest(ctx)
+impl EmissionSchedule {
+    fn effective(&self) -> EmissionSchedule {
+        if self.initial_per_round == 0 {
+            EmissionSchedule {
+                initial_per_round: CASHINO_REWARD_PER_ROUND_UNITS,
+                max_supply: CASHINO_MAX_SUPPLY_UNITS,
+                total_emitted: self.total_emitted,
+                ..Default::default()
+            }
+        } else {
+            *self
+        }
     }
 
-    pub fn withdraw(ctx: Context<Withdraw>) -> Result<()> {
-        instructions::withdraw::process_withdraw(ctx)
+    pub fn halvings_at(&self, round_id: u64, now: i64) -> u64 {
+        let schedule = self.effective();
+        if let Some(halvings) = round_id.checked_div(schedule.halving_interval_rounds) {
+            halvings
+        } else if schedule.halving_interval_seconds > 0 {
+            (now.saturating_sub(schedule.schedule_start).max(0) / schedule.halving_interval_seconds) as u64
+        } else {
+            0
+        }
     }
 
-    pub fn update_fee(
-        ctx: Context<UpdateFee>,
-        transfer_fee_basis_points: u16,
-        maximum_fee: u64,
-    ) -> Result<()> {
-        instructions::update_fee::process_update_fee(ctx, transfer_fee_basis_points, maximum_fee)
+    /// CASHINO to mint for a round with `round_volume` in its pot, before the supply cap.
+    pub fn emission_for_round(&self, round_id: u64, round_volume: u64, now: i64) -> Result<u64> {
+        let schedule = self.effective();
+        let halvings = self.halvings_at(round_id, now);
+        let base = if halvings >= u64::BITS as u64 {
+            0
+        } else {
+            schedule.initial_per_round >> halvings
+        };
+        if schedule.volume_reference == 0 {
+            return Ok(base);
+        }
+        let multiplier_basis_points = (round_volume as u128)
+            .checked_mul(10_000)
+            .and_then(|v| v.checked_div(schedule.volume_reference as u128))
+            .ok_or(ErrorCode::CalculationError)?
+            .min(schedule.volume_multiplier_cap_basis_points as u128);
+        let scaled = (base as u128)
+            .checked_mul(multiplier_basis_points)
+            .and_then(|v| v.checked_div(10_000))
+            .ok_or(ErrorCode::CalculationError)?;
+        u64::try_from(scaled).map_err(|_| error!(ErrorCode::CalculationError))
     }
 
-    pub fn initialize_game_settings(
-        ctx: Context<InitializeGameSettings>,
-        house_fee_basis_points: u16,
-    ) -> Result<()> {
-        instructions::game_initialize::process_initialize_game_settings(ctx, house_fee_basis_points)
+    pub fn supply_cap(&self) -> u64 {
+        self.effective().max_supply
     }
+}
 
-    pub fn start_new_round(
-        ctx: Context<StartNewRound>,
+/// CASHINO the table burns as a counterweight to its emissions. All zero, the default, burns nothing.
+/// Transfer fees are burned platform-wide instead, see `PlatformConfig::fee_burn_basis_points`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, Debug, PartialEq, Eq)]
+pub struct BurnConfig {
+    /// Whether CASHINO left in a round's reward pot after the claim window can be burned.
+    pub burn_unclaimed_rewards: bool,
+    /// Seconds after a round's rewards are processed during which players can still claim them.
+    /// Never shorter than `MIN_UNCLAIMED_REWARD_CLAIM_WINDOW`.
+    pub unclaimed_reward_claim_window: i64,
+    pub rewards_burned: u64,
+}
+
+impl BurnConfig {
+    /// Deadline for claiming rewards processed at `now`, never less than the minimum window.
+    pub fn reward_claim_deadline(&self, now: i64) -> Result<i64> {
+        now.checked_add(
+            self.unclaimed_reward_claim_window
+                .max(MIN_UNCLAIMED_REWARD_CLAIM_WINDOW),
+        )
+        .ok_or(error!(ErrorCode::CalculationError))
+    }
+}
+
+/// Leaderboards the table's bets and wins count toward. Bound by the authority with
+/// `set_table_leaderboards`, so players can't choose whether or where they are ranked.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, Debug, PartialEq, Eq)]
+pub struct TableLeaderboards {
+    pub wagered_leaderboard_id: u64,
+    pub won_leaderboard_id: u64,
+    pub has_wagered_leaderboard: bool,
+    pub has_won_leaderboard: bool,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum GameMode {
+    /// Players bet against each other and the winners split the pot.
+    #[default]
+    Parimutuel,
+    /// Players bet on a wheel segment against the house bankroll.
+    FixedOdds,
+}
+
+#[derive(Copy, Clone, AnchorSerialize, AnchorDeserialize, Default, Debug, PartialEq, Eq, Pod, Zeroable)]
+#[repr(C)]
+pub struct WheelSegment {
+    /// Gross payout per unit staked, in basis points (20_000 = 2x).
+    pub multiplier_basis_points: u32,
+    /// Relative chance of the wheel landing on this segment.
+    pub weight: u16,
+    pub _padding_ws: [u8; 2],
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum PayoutMode {
+    #[default]
+    SingleWinner,
+    /// K distinct winners drawn without replacement, weighted by bet.
+    Tiered,
+}
+
+impl Default for GameState {
+    fn default() -> Self {
+        Self {
+            version: GAME_STATE_VERSION,
+            authority: Pubkey::default(),
+            house_wallet: Pubkey::default(),
+            house_fee_basis_points: INITIAL_GAME_HOUSE_FEE_BASIS_POINTS,
+            round_counter: 0,
+            is_initialized: false,
+            cashino_mint: Pubkey::default(),
+            table_id: 0,
+            min_bet_amount: MIN_BET_AMOUNT,
+            max_bet_amount: MAX_BET_AMOUNT,
+            round_duration: MAX_ROUND_DURATION,
+            is_retired: false,
+            bet_mint: Pubkey::default(),
+            payout_mode: PayoutMode::SingleWinner,
+            payout_tiers: [0; MAX_PAYOUT_TIERS],
+            payout_tier_count: 0,
+            game_mode: GameMode::Parimutuel,
+            wheel_segments: [WheelSegment::default(); MAX_WHEEL_SEGMENTS],
+            wheel_segment_count: 0,
+            leaderboards: TableLeaderboards::default(),
+            emission: EmissionSchedule::default(),
+            burn: BurnConfig::default(),
+            reserved: [0; GAME_STATE_RESERVED_BYTES],
+        }
+    }
+}
+
+#[account]
+#[derive(Default, Debug)]
+pub struct StakingPool {
+    pub authority: Pubkey,
+    pub cashino_mint: Pubkey,
+    pub total_staked: u64,
+    /// Lamports earned per staked base unit, scaled by STAKING_ACC_PRECISION.
+    pub acc_sol_per_share: u128,
+    /// Share of each round's house fee routed to stakers.
+    pub revenue_share_basis_points: u16,
+    /// Seconds an unstake waits before it can be completed. Zero unstakes immediately.
+    pub unstake_cooldown: i64,
+    pub total_sol_distributed: u64,
+    pub bump: u8,
+    pub is_initialized: bool,
+}
+
+impl StakingPool {
+    pub fn accrued_for(&self, staked_amount: u64) -> Result<u128> {
+        (staked_amount as u128)
+            .checked_mul(self.acc_sol_per_share)
+            .and_then(|v| v.checked_div(STAKING_ACC_PRECISION))
+            .ok_or(error!(ErrorCode::CalculationError))
+    }
+
+    /// Portion of a house fee that goes to stakers. Nothing is shared while the pool is empty.
+    pub fn revenue_share_of(&self, house_fee: u64) -> Result<u64> {
+        if self.total_staked == 0 {
+            return Ok(0);
+        }
+        house_fee
+            .checked_mul(self.revenue_share_basis_points as u64)
+            .and_then(|v| v.checked_div(10_000))
+            .ok_or(error!(ErrorCode::CalculationError))
+    }
+
+    /// Books `lamports` already moved into the pool account against the accumulator.
+    pub fn distribute_sol(&mut self, lamports: u64) -> Result<()> {
+        require!(self.total_staked > 0, ErrorCode::NoStakedBalance);
+        let increment = (lamports as u128)
+            .checked_mul(STAKING_ACC_PRECISION)
+            .and_then(|v| v.checked_div(self.total_staked as u128))
+            .ok_or(ErrorCode::CalculationError)?;
+        self.acc_sol_per_share = self
+            .acc_sol_per_share
+            .checked_add(increment)
+            .ok_or(ErrorCode::CalculationError)?;
+        self.total_sol_distributed = self
+            .total_sol_distributed
+            .checked_add(lamports)
+            .ok_or(ErrorCode::CalculationError)?;
+        Ok(())
+    }
+}
+
+#[account]
+#[derive(Default, Debug)]
+pub struct StakerPosition {
+    pub owner: Pubkey,
+    pub staked_amount: u64,
+    pub reward_debt: u128,
+    /// SOL yield settled from the accumulator but not yet claimed.
+    pub pending_sol: u64,
+    pub pending_unstake_amount: u64,
+    pub unstake_available_at: i64,
+    pub bump: u8,
+}
+
+impl StakerPosition {
+    /// Moves yield earned since the last update into `pending_sol`.
+    /// Call before changing `staked_amount`, then call `reset_reward_debt`.
+    pub fn settle_yield(&mut self, pool: &StakingPool) -> Result<()> {
+        let accrued = pool.accrued_for(self.staked_amount)?;
+        let earned = accrued
+            .checked_sub(self.reward_debt)
+            .ok_or(ErrorCode::CalculationError)?;
+        let earned = u64::try_from(earned).map_err(|_| ErrorCode::CalculationError)?;
+        self.pending_sol = self
+            .pending_sol
+            .checked_add(earned)
+            .ok_or(ErrorCode::CalculationError)?;
+        self.reward_debt = accrued;
+        Ok(())
+    }
+
+    pub fn reset_reward_debt(&mut self, pool: &StakingPool) -> Result<()> {
+        self.reward_debt = pool.accrued_for(self.staked_amount)?;
+        Ok(())
+    }
+}
+
+/// CASHINO locked for a beneficiary, released linearly between `cliff_time` and `end_time`.
+/// The balance sits in the escrow's ATA.
+#[account]
+#[derive(Default, Debug)]
+pub struct VestingEscrow {
+    pub beneficiary: Pubkey,
+    pub vesting_id: u64,
+    pub mint: Pubkey,
+    /// CASHINO that reached the escrow's ATA, after any transfer fee.
+    pub total_amount: u64,
+    pub claimed_amount: u64,
+    pub start_time: i64,
+    /// Nothing can be claimed before this. At the cliff, everything vested since `start_time` unlocks.
+    pub cliff_time: i64,
+    pub end_time: i64,
+    pub revocable: bool,
+    /// Zero unless revoked. Vesting stops at this timestamp.
+    pub revoked_at: i64,
+    /// CASHINO token account that unvested tokens return to on revocation.
+    pub treasury: Pubkey,
+    pub bump: u8,
+}
+
+impl VestingEscrow {
+    pub fn vested_amount(&self, now: i64) -> Result<u64> {
+        let now = if self.revoked_at != 0 {
+            now.min(self.revoked_at)
+        } else {
+            now
+        };
+        if now < self.cliff_time {
+            return Ok(0);
+        }
+        if now >= self.end_time {
+            return Ok(self.total_amount);
+        }
+        let elapsed = now.saturating_sub(self.start_time).max(0) as u128;
+        let duration = self.end_time.saturating_sub(self.start_time) as u128;
+        let vested = (self.total_amount as u128)
+            .checked_mul(elapsed)
+            .and_then(|v| v.checked_div(duration))
+            .ok_or(ErrorCode::CalculationError)?;
+        u64::try_from(vested).map_err(|_| error!(ErrorCode::CalculationError))
+    }
+
+    pub fn claimable_amount(&self, now: i64) -> Result<u64> {
+        self.vested_amount(now)?
+            .checked_sub(self.claimed_amount)
+            .ok_or(error!(ErrorCode::CalculationError))
+    }
+}
+
+/// Where `create_vesting` gets the escrowed CASHINO from.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VestingFunding {
+    /// Newly minted by the mint_authority PDA, within `CASHINO_MAX_SUPPLY_UNITS`.
+    Mint,
+    /// Transferred from the authority's own CASHINO token account.
+    Transfer,
+}
+
+#[account]
+#[derive(Default, Debug)]
+pub struct JackpotPool {
+    pub authority: Pubkey,
+    /// Lamports available to the next hit, held on this account above its rent.
+    pub balance: u64,
+    /// Fraction of every SOL round's total pot diverted into the jackpot.
+    pub contribution_basis_points: u16,
+    /// Each round hits the jackpot with probability 1 in `odds`.
+    pub odds: u64,
+    pub total_contributed: u64,
+    pub total_paid_out: u64,
+    pub last_hit_table_id: u64,
+    pub last_hit_round_id: u64,
+    pub last_winner: Pubkey,
+    pub bump: u8,
+    pub is_initialized: bool,
+}
+
+#[account]
+#[derive(Default, Debug)]
+pub struct HouseBankroll {
+    pub authority: Pubkey,
+    /// Lamports held on this account above its rent.
+    pub balance: u64,
+    /// Part of `balance` held back to cover worst-case payouts of open fixed-odds rounds.
+    pub reserved_exposure: u64,
+    /// Lamports of queued LP deposits. Held on this account, but not part of `balance` until
+    /// their shares are minted.
+    pub pending_deposits: u64,
+    pub total_wagered: u64,
+    pub total_paid_out: u64,
+    /// Token-2022 mint of the LP shares, minted by the `mint_authority` PDA.
+    pub lp_mint: Pubkey,
+    /// Seconds between requesting an LP deposit or withdrawal and being able to complete it.
+    pub withdrawal_delay: i64,
+    pub bump: u8,
+    pub is_initialized: bool,
+}
+
+impl HouseBankroll {
+    pub fn available_liquidity(&self) -> u64 {
+        self.balance.saturating_sub(self.reserved_exposure)
+    }
+
+    /// LP shares minted for a deposit at the current share price.
+    pub fn shares_for_deposit(&self, lamports: u64, lp_supply: u64) -> Result<u64> {
+        lp_shares_at_price(lamports, self.balance, lp_supply)
+    }
+
+    /// Shares for a queued deposit: the fewer of what it gets at the price when it was requested
+    /// and at the price now, so waiting on an outcome known at request time gains nothing.
+    pub fn shares_for_queued_deposit(&self, request: &LpDepositRequest, lp_supply: u64) -> Result<u64> {
+        let at_request = lp_shares_at_price(
+            request.lamports,
+            request.balance_at_request,
+            request.lp_supply_at_request,
+        )?;
+        Ok(at_request.min(self.shares_for_deposit(request.lamports, lp_supply)?))
+    }
+
+    /// Lamports redeemed by burning `shares` at the current share price.
+    pub fn lamports_for_shares(&self, shares: u64, lp_supply: u64) -> Result<u64> {
+        require!(lp_supply > 0, ErrorCode::InvalidLpShareAmount);
+        let lamports = (shares as u128)
+            .checked_mul(self.balance as u128)
+            .and_then(|v| v.checked_div(lp_supply as u128))
+            .ok_or(ErrorCode::CalculationError)?;
+        u64::try_from(lamports).map_err(|_| error!(ErrorCode::CalculationError))
+    }
+}
+
+/// Shares `lamports` buys in a pool holding `pool_balance` against `lp_supply` shares.
+/// The first deposit into an empty pool mints shares 1:1 with lamports. A pool that has lost
+/// its whole balance while shares are outstanding takes no deposits, because any price would
+/// hand the new provider's lamports to the old providers or theirs to the new one.
+fn lp_shares_at_price(lamports: u64, pool_balance: u64, lp_supply: u64) -> Result<u64> {
+    if lp_supply == 0 {
+        return Ok(lamports);
+    }
+    require!(pool_balance > 0, ErrorCode::BankrollDepleted);
+    let shares = (lamports as u128)
+        .checked_mul(lp_supply as u128)
+        .and_then(|v| v.checked_div(pool_balance as u128))
+        .ok_or(ErrorCode::CalculationError)?;
+    u64::try_from(shares).map_err(|_| error!(ErrorCode::CalculationError))
+}
+
+/// A deposit waiting out the bankroll's delay. The lamports are already on the bankroll.
+#[account]
+#[derive(Default, Debug)]
+pub struct LpDepositRequest {
+    pub provider: Pubkey,
+    pub lamports: u64,
+    /// Pool balance and LP supply when the deposit was requested, which cap its share price.
+    pub balance_at_request: u64,
+    pub lp_supply_at_request: u64,
+    pub available_at: i64,
+    pub bump: u8,
+}
+
+#[account]
+#[derive(Default, Debug)]
+pub struct LpWithdrawalRequest {
+    pub provider: Pubkey,
+    /// LP shares parked in the withdrawal vault, burned when the request completes.
+    pub shares: u64,
+    pub available_at: i64,
+    pub bump: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum LeaderboardMetric {
+    /// Ranked by lamports wagered during the epoch, updated by `place_sol_bet`.
+    #[default]
+    Wagered,
+    /// Ranked by lamports won during the epoch, updated by `finalize_round`.
+    Won,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, Debug, PartialEq, Eq)]
+pub struct LeaderboardEntry {
+    pub player: Pubkey,
+    pub score: u64,
+    pub claimed: bool,
+}
+
+/// Top `LEADERBOARD_SIZE` players of one epoch, kept sorted by score with the leader first.
+/// A player who drops off the board loses their epoch score and restarts from zero if they
+/// come back, which keeps every update a single pass over the entries.
+#[account]
+#[derive(Default, Debug)]
+pub struct Leaderboard {
+    pub leaderboard_id: u64,
+    pub metric: LeaderboardMetric,
+    pub start_time: i64,
+    pub end_time: i64,
+    pub entries: [LeaderboardEntry; LEADERBOARD_SIZE],
+    pub entry_count: u8,
+    /// Share of `prize_pool` paid to each rank. Shares of ranks left empty stay in the vault.
+    pub prize_shares_basis_points: [u16; LEADERBOARD_SIZE],
+    /// CASHINO (base units) paid into the prize vault.
+    pub prize_pool: u64,
+    pub bump: u8,
+}
+
+impl Leaderboard {
+    pub fn is_active(&self, now: i64) -> bool {
+        now >= self.start_time && now < self.end_time
+    }
+
+    /// Adds `amount` to the player's epoch score and moves them up the board.
+    pub fn record_score(&mut self, player: Pubkey, amount: u64) -> Result<()> {
+        if amount == 0 {
+            return Ok(());
+        }
+        let count = self.entry_count as usize;
+        let mut index = match self.entries[..count].iter().position(|e| e.player == player) {
+            Some(existing) => {
+                self.entries[existing].score = self.entries[existing]
+                    .score
+                    .checked_add(amount)
+                    .ok_or(ErrorCode::CalculationError)?;
+                existing
+            }
+            None if count < LEADERBOARD_SIZE => {
+                self.entry_count += 1;
+                count
+            }
+            None => {
+                let last = LEADERBOARD_SIZE - 1;
+                if amount <= self.entries[last].score {
+                    return Ok(());
+                }
+                last
+            }
+        };
+        if self.entries[index].player != player {
+            self.entries[index] = LeaderboardEntry {
+                player,
+                score: amount,
+                claimed: false,
+            };
+        }
+        while index > 0 && self.entries[index].score > self.entries[index - 1].score {
+            self.entries.swap(index, index - 1);
+            index -= 1;
+        }
+        Ok(())
+    }
+
+    /// CASHINO owed to the player in `rank` (zero-based).
+    pub fn prize_for(&self, rank: usize) -> Result<u64> {
+        let prize = (self.prize_pool as u128)
+            .checked_mul(self.prize_shares_basis_points[rank] as u128)
+            .and_then(|v| v.checked_div(10_000))
+            .ok_or(ErrorCode::CalculationError)?;
+        u64::try_from(prize).map_err(|_| error!(ErrorCode::CalculationError))
+    }
+}
+
+#[event]
+pub struct LeaderboardPrizeClaimed {
+    pub leaderboard_id: u64,
+    pub player: Pubkey,
+    pub rank: u8,
+    pub amount: u64,
+}
+
+#[event]
+pub struct EscrowReconciled {
+    pub user: Pubkey,
+    pub lamports: u64,
+    pub balance: u64,
+    pub rent_minimum: u64,
+    pub surplus: u64,
+    pub deficit: u64,
+    /// Whether the surplus was credited to `balance`.
+    pub repaired: bool,
+}
+
+#[event]
+pub struct RakebackDistributed {
+    pub table_id: u64,
+    pub round_id: u64,
+    pub total_rakeback: u64,
+}
+
+#[event]
+pub struct EmissionMinted {
+    pub table_id: u64,
+    pub round_id: u64,
+    pub amount: u64,
+    pub halvings: u64,
+    pub total_emitted: u64,
+}
+
+#[event]
+pub struct TransferFeesCollected {
+    pub fee_treasury: Pubkey,
+    pub sources_harvested: u32,
+    pub amount: u64,
+    /// Part of `amount` burned rather than sent to the fee treasury.
+    pub burned: u64,
+    pub total_collected: u64,
+    pub total_burned: u64,
+}
+
+#[event]
+pub struct UnclaimedRewardsBurned {
+    pub table_id: u64,
+    pub round_id: u64,
+    pub amount: u64,
+    pub total_burned: u64,
+}
+
+#[event]
+pub struct VestingCreated {
+    pub vesting_escrow: Pubkey,
+    pub beneficiary: Pubkey,
+    pub vesting_id: u64,
+    pub amount: u64,
+    pub start_time: i64,
+    pub cliff_time: i64,
+    pub end_time: i64,
+    pub revocable: bool,
+}
+
+#[event]
+pub struct VestedClaimed {
+    pub vesting_escrow: Pubkey,
+    pub beneficiary: Pubkey,
+    pub amount: u64,
+    pub total_claimed: u64,
+}
+
+#[event]
+pub struct VestingRevoked {
+    pub vesting_escrow: Pubkey,
+    pub beneficiary: Pubkey,
+    /// Unvested CASHINO returned to the treasury.
+    pub returned_amount: u64,
+    pub revoked_at: i64,
+}
+
+#[event]
+pub struct JackpotContributed {
+    pub table_id: u64,
+    pub round_id: u64,
+    pub amount: u64,
+    pub jackpot_balance: u64,
+}
+
+#[event]
+pub struct JackpotHit {
+    pub table_id: u64,
+    pub round_id: u64,
+    pub winner: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct JackpotClaimed {
+    pub table_id: u64,
+    pub round_id: u64,
+    pub winner: Pubkey,
+    pub amount: u64,
+}
+
+#[derive(Copy, Clone, AnchorSerialize, AnchorDeserialize, Default, Debug, Pod, Zeroable)]
+#[repr(C)]
+pub struct PlayerData {
+    pub pubkey: Pubkey,
+    pub amount: u64,
+}
+
+#[account]
+#[derive(Default, Debug)]
+pub struct GamePotSol {}
+
+#[account]
+#[derive(Default, Debug)]
+pub struct RoundCashinoRewardsPot {
+    pub round_id: u64,
+    pub total_minted_for_round: u64,
+}
+
+#[derive(Copy, Clone, Default, Debug, Pod, Zeroable, AnchorSerialize, AnchorDeserialize)]
+#[repr(C)]
+pub struct RoundWinner {
+    pub pubkey: Pubkey,
+    pub amount: u64,
+    pub claimed: u8,
+    pub _padding_rw: [u8; 7],
+}
+
+#[derive(Copy, Clone, Default, Debug, Pod, Zeroable, AnchorSerialize, AnchorDeserialize)]
+#[repr(C)]
+pub struct PlayerCashinoRewards {
+    pub player: Pubkey,
+    pub sol_bet_amount: u64,
+    pub cashino_reward_amount: u64,
+    pub claimed_val: u8,
+    pub _padding_pcr: [u8; 7],
+}
+
+#[repr(u8)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum RoundStatus {
+    #[default]
+    Active = 0,
+    AwaitingSolClaim = 1,
+    SolClaimed = 2,
+    RewardPotAccountsCreated = 3,
+    TokensMintedForRewards = 4,
+    RewardsProcessed = 5,
+}
+
+#[account(zero_copy)]
+#[repr(C)]
+#[derive(Debug, Default)]
+pub struct RoundState {
+    pub id: u64,
+    pub table_id: u64,
+    pub start_time: i64,
+    pub end_time: i64,
+    /// SHA-256 of the seed the authority reveals when the round is settled.
+    pub seed_commitment: SeedArray,
+
+    pub has_revealed_seed_val: u8,
+    pub _padding0: [u8; 7],
+    pub revealed_seed: SeedArray,
+
+    pub total_sol_pot: u64,
+    pub player_count: u8,
+
+    pub _padding1: [u8; 7],
+    pub players: [PlayerData; MAX_PLAYERS],
+
+    pub player_cashino_rewards: [PlayerCashinoRewards; MAX_PLAYERS],
+
+    pub total_cashino_minted_for_round: u64,
+
+    pub status_discriminant: u8,
+
+    pub has_winner_val: u8,
+    pub winner_index_val: u8,
+
+    pub _padding_to_align_house_fee: [u8; 5],
+
+    pub house_sol_fee: u64,
+
+    pub winner_sol_pubkey: Pubkey,
+    pub winner_sol_amount: u64,
+    pub winner_sol_claimed: u8,
+
+    pub _final_padding_for_struct: [u8; 7],
+
+    pub jackpot_contribution: u64,
+    pub jackpot_winner: Pubkey,
+    pub jackpot_amount: u64,
+    pub jackpot_claimed: u8,
+
+    pub _padding_jackpot: [u8; 7],
+
+    /// Winners in place order. `winner_sol_*` mirrors first place.
+    pub winners: [RoundWinner; MAX_PLAYERS],
+    pub winner_count: u8,
+
+    pub _padding_winners: [u8; 7],
+
+    /// `GameMode` snapshot taken when the round started (0 = pari-mutuel, 1 = fixed odds).
+    pub game_mode_val: u8,
+    pub wheel_segment_count: u8,
+    pub has_winning_segment_val: u8,
+    pub winning_segment_val: u8,
+    pub player_segments: [u8; MAX_PLAYERS],
+    pub _padding_fixed_odds: [u8; 2],
+    /// Amount this round currently holds in reserve on the house bankroll.
+    pub bankroll_reserved: u64,
+    /// Slot whose hash is mixed into the spin. Recorded when the seed is revealed, after betting
+    /// has closed, so nobody knows the outcome while bets can still be placed.
+    pub entropy_slot: u64,
+    pub wheel_segments: [WheelSegment; MAX_WHEEL_SEGMENTS],
+    /// Rakeback owed at the snapshotted rates, held back from the house fee at finalization
+    /// until distribute_rakeback pays it out.
+    pub rakeback_reserved: u64,
+    pub rakeback_distributed: u8,
+    pub _padding_rakeback: [u8; 7],
+    /// Each player's lifetime volume as of their last bet in this round.
+    pub player_total_wagered: [u64; MAX_PLAYERS],
+    /// Rakeback rate per player, fixed at finalization from `player_total_wagered`.
+    pub player_rakeback_basis_points: [u16; MAX_PLAYERS],
+    pub _padding_rakeback_rates: [u8; 4],
+    /// Set when the rewards are processed. Unclaimed rewards can be burned from then on.
+    pub reward_claim_deadline: i64,
+}
+
+impl RoundState {
+    pub fn initialize_new(
+        &mut self,
+        id: u64,
+        table_id: u64,
+        start_time: i64,
+        end_time: i64,
         seed_commitment: SeedArray,
+    ) {
+        self.id = id;
+        self.table_id = table_id;
+        self.start_time = start_time;
+        self.end_time = end_time;
+        self.seed_commitment = seed_commitment;
+        self.has_revealed_seed_val = 0;
+        self.total_sol_pot = 0;
+        self.player_count = 0;
+        self.total_cashino_minted_for_round = 0;
+        self.status_discriminant = RoundStatus::Active as u8;
+        self.has_winner_val = 0;
+        self.winner_index_val = 0;
+        self.house_sol_fee = 0;
+        self.winner_sol_pubkey = Pubkey::default();
+        self.winner_sol_amount = 0;
+        self.winner_sol_claimed = 0;
+        self.jackpot_contribution = 0;
+        self.jackpot_winner = Pubkey::default();
+        self.jackpot_amount = 0;
+        self.jackpot_claimed = 0;
+        self.winners = [RoundWinner::default(); MAX_PLAYERS];
+        self.winner_count = 0;
+        self.game_mode_val = GameMode::Parimutuel as u8;
+        self.wheel_segment_count = 0;
+        self.has_winning_segment_val = 0;
+        self.winning_segment_val = 0;
+        self.player_segments = [0; MAX_PLAYERS];
+        self.bankroll_reserved = 0;
+        self.entropy_slot = 0;
+        self.wheel_segments = [WheelSegment::default(); MAX_WHEEL_SEGMENTS];
+        self.rakeback_reserved = 0;
+        self.rakeback_distributed = 0;
+        self.player_total_wagered = [0; MAX_PLAYERS];
+        self.player_rakeback_basis_points = [0; MAX_PLAYERS];
+        self.reward_claim_deadline = 0;
+    }
+
+    /// Fixes the wheel for this round so later table changes cannot alter its odds.
+    pub fn set_fixed_odds_wheel(&mut self, segments: &[WheelSegment]) {
+        self.game_mode_val = GameMode::FixedOdds as u8;
+        self.wheel_segments = [WheelSegment::default(); MAX_WHEEL_SEGMENTS];
+        self.wheel_segments[..segments.len()].copy_from_slice(segments);
+        self.wheel_segment_count = segments.len() as u8;
+    }
+
+    pub fn is_fixed_odds(&self) -> bool {
+        self.game_mode_val == GameMode::FixedOdds as u8
+    }
+
+    /// Records a fixed-odds bet. A player backs a single segment per round.
+    pub fn record_fixed_odds_bet(&mut self, player_key: Pubkey, amount: u64, segment: u8) -> Result<()> {
+        require!(
+            (segment as usize) < self.wheel_segment_count as usize,
+            ErrorCode::InvalidWheelSegment
+        );
+        if let Some(existing) = self.players[..self.player_count as usize]
+            .iter()
+            .position(|p| p.pubkey == player_key)
+        {
+            require!(
+                self.player_segments[existing] == segment,
+                ErrorCode::InvalidWheelSegment
+            );
+        }
+        self.record_bet(player_key, amount)?;
+        let index = self.players[..self.player_count as usize]
+            .iter()
+            .position(|p| p.pubkey == player_key)
+            .ok_or(ErrorCode::GameCalculationError)?;
+        self.player_segments[index] = segment;
+        Ok(())
+    }
+
+    /// The player's total stake in this round, zero if they have not bet.
+    pub fn stake_of(&self, player_key: Pubkey) -> u64 {
+        self.players[..self.player_count as usize]
+            .iter()
+            .find(|p| p.pubkey == player_key)
+            .map_or(0, |p| p.amount)
+    }
+
+    /// Records the player's lifetime volume after a bet, so activity after the round can't
+    /// move the rakeback tier it is paid at.
+    pub fn record_player_volume(&mut self, player_key: Pubkey, total_wagered: u64) {
+        if let Some(i) = self.players[..self.player_count as usize]
+            .iter()
+            .position(|p| p.pubkey == player_key)
+        {
+            self.player_total_wagered[i] = total_wagered;
+        }
+    }
+
+    /// Fixes each player's rakeback rate from the volume recorded with their bets.
+    pub fn snapshot_rakeback_rates(&mut self, schedule: &RakebackSchedule) {
+        for i in 0..self.player_count as usize {
+            self.player_rakeback_basis_points[i] = schedule.rate_for(self.player_total_wagered[i]);
+        }
+    }
+
+    /// Rakeback owed to the player in slot `i`: their stake's share of the house fee at their
+    /// snapshotted rate.
+    pub fn rakeback_owed(&self, i: usize) -> Result<u64> {
+        if self.total_sol_pot == 0 {
+            return Ok(0);
+        }
+        let rakeback = (self.house_sol_fee as u128)
+            .checked_mul(self.players[i].amount as u128)
+            .and_then(|v| v.checked_div(self.total_sol_pot as u128))
+            .and_then(|v| v.checked_mul(self.player_rakeback_basis_points[i] as u128))
+            .and_then(|v| v.checked_div(10_000))
+            .ok_or(ErrorCode::CalculationError)?;
+        u64::try_from(rakeback).map_err(|_| error!(ErrorCode::CalculationError))
+    }
+
+    /// Rakeback owed to every player in the round, which finalize_round holds back.
+    pub fn total_rakeback_owed(&self) -> Result<u64> {
+        (0..self.player_count as usize).try_fold(0u64, |total, i| {
+            total
+                .checked_add(self.rakeback_owed(i)?)
+                .ok_or(error!(ErrorCode::CalculationError))
+        })
+    }
+
+    /// Total gross payout if the wheel lands on `segment`.
+    pub fn fixed_odds_payout_for(&self, segment: u8) -> Result<u64> {
+        let multiplier = self.wheel_segments[segment as usize].multiplier_basis_points as u128;
+        let mut payout: u128 = 0;
+        for (i, player) in self.players[..self.player_count as usize].iter().enumerate() {
+            if self.player_segments[i] == segment {
+                payout = payout
+                    .checked_add((player.amount as u128) * multiplier / 10_000)
+                    .ok_or(ErrorCode::CalculationError)?;
+            }
+        }
+        u64::try_from(payout).map_err(|_| error!(ErrorCode::CalculationError))
+    }
+
+    /// Bankroll needed on top of the stakes already in the pot to pay the worst-case segment.
+    pub fn fixed_odds_required_reserve(&self) -> Result<u64> {
+        let mut worst_case_payout: u64 = 0;
+        for segment in 0..self.wheel_segment_count {
+            worst_case_payout = worst_case_payout.max(self.fixed_odds_payout_for(segment)?);
+        }
+        Ok(worst_case_payout.saturating_sub(self.total_sol_pot))
+    }
+
+    /// Adds `amount` to the player's entry, creating one if this is their first bet of the round.
+    /// Amounts are in the table's bet units (lamports or token base units).
+    pub fn record_bet(&mut self, player_key: Pubkey, amount: u64) -> Result<()> {
+        let mut player_found_and_updated = false;
+        let mut player_index_to_update = 0; // hold the index of the player
+
+        for i in 0..(self.player_count as usize) {
+            if self.players[i].pubkey == player_key {
+                player_index_to_update = i;
+                player_found_and_updated = true;
+                break;
+            }
+        }
+
+        if player_found_and_updated {
+            self.players[player_index_to_update].amount = self.players[player_index_to_update]
+                .amount
+                .checked_add(amount)
+                .ok_or(ErrorCode::CalculationError)?;
+            msg!("Player {} updated existing bet. New total bet for player: {}", player_key, self.players[player_index_to_update].amount);
+        } else {
+            require!(
+                self.player_count < MAX_PLAYERS as u8,
+                ErrorCode::MaxPlayersReached
+            );
+            msg!("New player check: Player count {} is less than MAX_PLAYERS {}.", self.player_count, MAX_PLAYERS);
+
+            let current_player_count_as_index = self.player_count as usize;
+            self.players[current_player_count_as_index] = PlayerData {
+                pubkey: player_key,
+                amount,
+            };
+            self.player_count += 1;
+            msg!("New player {} added with bet amount {}. Player count now: {}", player_key, amount, self.player_count);
+        }
+
+        self.total_sol_pot = self.total_sol_pot
+            .checked_add(amount)
+            .ok_or(ErrorCode::CalculationError)?;
+        msg!("RoundState.total_sol_pot (data field) updated to: {}", self.total_sol_pot);
+        Ok(())
+    }
+
+    /// Marks the claimant's payout as claimed and returns its amount. The round moves to
+    /// SolClaimed once every winner has claimed.
+    pub fn claim_winner_payout(&mut self, claimant: Pubkey) -> Result<u64> {
+        let winner_count = self.winner_count as usize;
+        let winner = self.winners[..winner_count]
+            .iter_mut()
+            .find(|w| w.pubkey == claimant)
+            .ok_or(ErrorCode::NotTheSolWinner)?;
+        require!(winner.claimed == 0, ErrorCode::SolWinningsAlreadyClaimed);
+        winner.claimed = 1;
+        let amount = winner.amount;
+
+        if claimant == self.winner_sol_pubkey {
+            self.winner_sol_claimed = 1;
+        }
+        if self.winners[..winner_count].iter().all(|w| w.claimed == 1) {
+            self.set_status(RoundStatus::SolClaimed);
+            msg!("All {} winner(s) have claimed. Round status updated to SolClaimed.", winner_count);
+        }
+        Ok(amount)
+    }
+
+    pub fn get_revealed_seed(&self) -> Option<SeedArray> {
+        if self.has_revealed_seed_val == 1 {
+            Some(self.revealed_seed)
+        } else {
+            None
+        }
+    }
+
+    pub fn set_revealed_seed(&mut self, seed: Option<SeedArray>) {
+        if let Some(s_val) = seed {
+            self.revealed_seed = s_val;
+            self.has_revealed_seed_val = 1;
+        } else {
+            self.has_revealed_seed_val = 0;
+        }
+    }
+
+    pub fn get_winner_index(&self) -> Option<u8> {
+        if self.has_winner_val == 1 {
+            Some(self.winner_index_val)
+        } else {
+            None
+        }
+    }
+
+    pub fn set_winner_index(&mut self, index: Option<u8>) {
+        if let Some(i_val) = index {
+            self.winner_index_val = i_val;
+            self.has_winner_val = 1;
+        } else {
+            self.has_winner_val = 0;
+        }
+    }
+
+    pub fn get_status(&self) -> Result<RoundStatus> {
+        match self.status_discriminant {
+            0 => Ok(RoundStatus::Active),
+            1 => Ok(RoundStatus::AwaitingSolClaim),
+            2 => Ok(RoundStatus::SolClaimed),
+            3 => Ok(RoundStatus::RewardPotAccountsCreated),
+            4 => Ok(RoundStatus::TokensMintedForRewards),
+            5 => Ok(RoundStatus::RewardsProcessed),
+            _ => Err(error!(ErrorCode::InvalidStatusDiscriminant)),
+        }
+    }
+
+    pub fn set_status(&mut self, new_status: RoundStatus) {
+        self.status_discriminant = new_status as u8;
+    }
+}
+
+#[derive(Copy, Clone, Default, Debug, Pod, Zeroable, AnchorSerialize, AnchorDeserialize)]
+#[repr(C)]
+pub struct RoundHistoryEntry {
+    pub round_id: u64,
+    pub winner: Pubkey,
+    pub total_pot: u64,
+    pub house_fee: u64,
+    /// Hash of the seed revealed at finalization.
+    pub revealed_seed_hash: SeedArray,
+    pub finalized_at: i64,
+    pub player_count: u8,
+    pub _padding_rhe: [u8; 7],
+}
+
+/// Ring buffer of a table's most recently finalized rounds, newest at `head - 1`.
+#[account(zero_copy)]
+#[repr(C)]
+#[derive(Debug)]
+pub struct RoundHistory {
+    pub table_id: u64,
+    /// Slot the next entry is written to.
+    pub head: u32,
+    /// Number of valid entries, at most `ROUND_HISTORY_CAPACITY`.
+    pub len: u32,
+    pub entries: [RoundHistoryEntry; ROUND_HISTORY_CAPACITY],
+}
+
+impl RoundHistory {
+    /// Appends an entry, overwriting the oldest one once the buffer is full.
+    pub fn push(&mut self, entry: RoundHistoryEntry) {
+        self.entries[self.head as usize] = entry;
+        self.head = ((self.head as usize + 1) % ROUND_HISTORY_CAPACITY) as u32;
+        if (self.len as usize) < ROUND_HISTORY_CAPACITY {
+            self.len += 1;
+        }
+    }
+}
+
+/// Extra key/value pair stored in a mint's Token-2022 `TokenMetadata` extension.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct MetadataField {
+    pub key: String,
+    pub value: String,
+}
+
+/// Mint settings for `initialize_token_2022`. TransferFeeConfig and the metadata extensions are
+/// always enabled; every other extension is added only when configured.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MintInitConfig {
+    pub decimals: u8,
+    /// Sets the mint authority PDA as freeze authority.
+    pub freeze_authority: bool,
+    /// InterestBearing rate in basis points, with the mint authority PDA as rate authority.
+    pub interest_rate: Option<i16>,
+    pub permanent_delegate: Option<Pubkey>,
+    /// New token accounts start frozen. Requires `freeze_authority`.
+    pub default_account_state_frozen: bool,
+    pub close_authority: Option<Pubkey>,
+    /// TransferHook program, with the mint authority PDA as hook authority.
+    pub transfer_hook_program_id: Option<Pubkey>,
+}
+
+impl Default for MintInitConfig {
+    /// The original CASHINO settings: 2 decimals, PDA freeze authority, no optional extensions.
+    fn default() -> Self {
+        Self {
+            decimals: 2,
+            freeze_authority: true,
+            interest_rate: None,
+            permanent_delegate: None,
+            default_account_state_frozen: false,
+            close_authority: None,
+            transfer_hook_program_id: None,
+        }
+    }
+}
+
+#[program]
+pub mod spin_wheel {
+    use super::*;
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn initialize_token_2022(
+        ctx: Context<InitializeToken2022>,
+        transfer_fee_basis_points: u16,
+        maximum_fee: u64,
+        name: String,
+        symbol: String,
+        uri: String,
+        additional_metadata: Vec<MetadataField>,
+        config: MintInitConfig,
+    ) -> Result<()> {
+        instructions::initialize::process_initialize(
+            ctx,
+            transfer_fee_basis_points,
+            maximum_fee,
+            name,
+            symbol,
+            uri,
+            additional_metadata,
+            config,
+        )
+    }
+
+    pub fn update_token_metadata(
+        ctx: Context<UpdateTokenMetadata>,
+        field: String,
+        value: String,
+    ) -> Result<()> {
+        instructions::update_token_metadata::process_update_token_metadata(ctx, field, value)
+    }
+
+    pub fn initialize_transfer_hook(
+        ctx: Context<InitializeTransferHook>,
+        rules: cashino_transfer_hook::TransferRules,
+    ) -> Result<()> {
+        instructions::initialize_transfer_hook::process_initialize_transfer_hook(ctx, rules)
+    }
+
+    pub fn update_transfer_hook_rules(
+        ctx: Context<UpdateTransferHookRules>,
+        rules: cashino_transfer_hook::TransferRules,
+    ) -> Result<()> {
+        instructions::update_transfer_hook_rules::process_update_transfer_hook_rules(ctx, rules)
+    }
+
+    pub fn mint_tokens_to_account(ctx: Context<MintTokensToAccount>, amount: u64) -> Result<()> {
+        instructions::mint_tokens::process_mint_tokens(ctx, amount)
+    }
+
+    pub fn transfer<'info>(
+        ctx: Context<'_, '_, 'info, 'info, Transfer<'info>>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::transfer::process_transfer(ctx, amount)
+    }
+
+    pub fn update_fee(
+        ctx: Context<UpdateFee>,
+        transfer_fee_basis_points: u16,
+        maximum_fee: u64,
+    ) -> Result<()> {
+        instructions::update_fee::process_update_fee(ctx, transfer_fee_basis_points, maximum_fee)
+    }
+
+    pub fn initialize_platform(ctx: Context<InitializePlatform>) -> Result<()> {
+        instructions::initialize_platform::process_initialize_platform(ctx)
+    }
+
+    pub fn create_table(
+        ctx: Context<CreateTable>,
+        table_id: u64,
+        house_fee_basis_points: u16,
+        min_bet_amount: u64,
+        max_bet_amount: u64,
         round_duration: i64,
+    ) -> Result<()> {
+        instructions::create_table::process_create_table(
+            ctx,
+            table_id,
+            house_fee_basis_points,
+            min_bet_amount,
+            max_bet_amount,
+            round_duration,
+        )
+    }
+
+    pub fn retire_table(ctx: Context<RetireTable>, table_id: u64) -> Result<()> {
+        instructions::retire_table::process_retire_table(ctx, table_id)
+    }
+
+    pub fn start_new_round(
+        ctx: Context<StartNewRound>,
+        table_id: u64,
+        seed_commitment: SeedArray,
         round_id_for_seed: u64,
     ) -> Result<()> {
-        instructions::start_new_round::process_start_new_round(
+        instructions::start_new_round::process_start_new_round(
+            ctx,
+            table_id,
+            seed_commitment,
+            round_id_for_seed,
+        )
+    }
+
+    pub fn place_sol_bet(
+        ctx: Context<PlaceSolBet>,
+        table_id: u64,
+        round_id_for_pdas: u64,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::place_bet::process_place_sol_bet(ctx, table_id, round_id_for_pdas, amount)
+    }
+
+    pub fn finalize_round<'info>(
+        ctx: Context<'_, '_, 'info, 'info, FinalizeRound<'info>>,
+        table_id: u64,
+        revealed_seed_arg: SeedArray,
+        round_id_for_pdas: u64,
+    ) -> Result<()> {
+        instructions::finalize_round::process_finalize_round(
+            ctx,
+            table_id,
+            revealed_seed_arg,
+            round_id_for_pdas,
+        )
+    }
+
+    pub fn create_reward_pot_accounts(
+        ctx: Context<CreateRewardPotAccounts>,
+        table_id: u64,
+        round_id_for_pdas: u64,
+    ) -> Result<()> {
+        instructions::create_reward_pot_accounts::process_create_reward_pot_accounts(
+            ctx,
+            table_id,
+            round_id_for_pdas,
+        )
+    }
+    pub fn mint_tokens_to_reward_pot(
+        ctx: Context<MintTokensToRewardPot>,
+        table_id: u64,
+        round_id_for_pdas: u64,
+    ) -> Result<()> {
+        instructions::mint_tokens_to_reward_pot::process_mint_tokens_to_reward_pot(
+            ctx,
+            table_id,
+            round_id_for_pdas,
+        )
+    }
+
+    pub fn calculate_reward_entitlements(
+        ctx: Context<CalculateRewardEntitlements>,
+        table_id: u64,
+        round_id_for_pdas: u64,
+    ) -> Result<()> {
+        instructions::calculate_reward_entitlements::process_calculate_reward_entitlements(
+            ctx,
+            table_id,
+            round_id_for_pdas,
+        )
+    }
+
+    pub fn claim_cashino_rewards<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ClaimCashinoRewards<'info>>,
+        table_id: u64,
+        round_id_for_pdas: u64,
+    ) -> Result<()> {
+        instructions::claim_cashino_rewards::process_claim_cashino_rewards(
+            ctx,
+            table_id,
+            round_id_for_pdas,
+        )
+    }
+
+    pub fn deposit_sol(ctx: Context<DepositSol>, amount: u64) -> Result<()> {
+        instructions::deposit_sol::process_deposit_sol(ctx, amount)
+    }
+
+    pub fn migrate_game_state(ctx: Context<MigrateGameState>, table_id: u64) -> Result<()> {
+        instructions::migrate_game_state::process_migrate_game_state(ctx, table_id)
+    }
+
+    pub fn migrate_user_escrow(ctx: Context<MigrateUserEscrow>) -> Result<()> {
+        instructions::migrate_user_escrow::process_migrate_user_escrow(ctx)
+    }
+
+    pub fn reconcile_escrow(ctx: Context<ReconcileEscrow>, repair: bool) -> Result<()> {
+        instructions::reconcile_escrow::process_reconcile_escrow(ctx, repair)
+    }
+
+    pub fn set_gaming_limits(ctx: Context<SetGamingLimits>, limits: GamingLimits) -> Result<()> {
+        instructions::set_gaming_limits::process_set_gaming_limits(ctx, limits)
+    }
+
+    pub fn self_exclude(ctx: Context<SelfExclude>, duration_seconds: i64) -> Result<()> {
+        instructions::self_exclude::process_self_exclude(ctx, duration_seconds)
+    }
+
+    pub fn withdraw_sol_from_platform<'info>(
+        ctx: Context<'_, '_, 'info, 'info, WithdrawSolFromPlatform<'info>>,
+        amount_to_withdraw: u64,
+    ) -> Result<()> {
+        instructions::withdraw_sol_from_platform::process_withdraw_sol_from_platform(
+            ctx,
+            amount_to_withdraw,
+        )
+    }
+
+    pub fn claim_sol_winnings(
+        ctx: Context<ClaimSolWinnings>,
+        table_id: u64,
+        round_id_for_pdas: u64,
+    ) -> Result<()> {
+        instructions::claim_sol_winnings::process_claim_sol_winnings(
+            ctx,
+            table_id,
+            round_id_for_pdas,
+        )
+    }
+
+    pub fn deposit_token<'info>(
+        ctx: Context<'_, '_, 'info, 'info, DepositToken<'info>>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::deposit_token::process_deposit_token(ctx, amount)
+    }
+
+    pub fn withdraw_token_from_platform<'info>(
+        ctx: Context<'_, '_, 'info, 'info, WithdrawTokenFromPlatform<'info>>,
+        amount_to_withdraw: u64,
+    ) -> Result<()> {
+        instructions::withdraw_token_from_platform::process_withdraw_token_from_platform(
+            ctx,
+            amount_to_withdraw,
+        )
+    }
+
+    pub fn create_round_token_vault(
+        ctx: Context<CreateRoundTokenVault>,
+        table_id: u64,
+        round_id_for_pdas: u64,
+    ) -> Result<()> {
+        instructions::create_round_token_vault::process_create_round_token_vault(
+            ctx,
+            table_id,
+            round_id_for_pdas,
+        )
+    }
+
+    pub fn place_token_bet<'info>(
+        ctx: Context<'_, '_, 'info, 'info, PlaceTokenBet<'info>>,
+        table_id: u64,
+        round_id_for_pdas: u64,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::place_token_bet::process_place_token_bet(ctx, table_id, round_id_for_pdas, amount)
+    }
+
+    pub fn finalize_token_round<'info>(
+        ctx: Context<'_, '_, 'info, 'info, FinalizeTokenRound<'info>>,
+        table_id: u64,
+        revealed_seed_arg: SeedArray,
+        round_id_for_pdas: u64,
+    ) -> Result<()> {
+        instructions::finalize_token_round::process_finalize_token_round(
+            ctx,
+            table_id,
+            revealed_seed_arg,
+            round_id_for_pdas,
+        )
+    }
+
+    pub fn claim_token_winnings<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ClaimTokenWinnings<'info>>,
+        table_id: u64,
+        round_id_for_pdas: u64,
+    ) -> Result<()> {
+        instructions::claim_token_winnings::process_claim_token_winnings(
+            ctx,
+            table_id,
+            round_id_for_pdas,
+        )
+    }
+
+    pub fn initialize_staking_pool(
+        ctx: Context<InitializeStakingPool>,
+        revenue_share_basis_points: u16,
+        unstake_cooldown: i64,
+    ) -> Result<()> {
+        instructions::initialize_staking_pool::process_initialize_staking_pool(
+            ctx,
+            revenue_share_basis_points,
+            unstake_cooldown,
+        )
+    }
+
+    pub fn stake_cashino<'info>(ctx: Context<'_, '_, 'info, 'info, StakeCashino<'info>>, amount: u64) -> Result<()> {
+        instructions::stake_cashino::process_stake_cashino(ctx, amount)
+    }
+
+    pub fn unstake_cashino<'info>(ctx: Context<'_, '_, 'info, 'info, UnstakeCashino<'info>>, amount: u64) -> Result<()> {
+        instructions::unstake_cashino::process_unstake_cashino(ctx, amount)
+    }
+
+    pub fn complete_unstake<'info>(ctx: Context<'_, '_, 'info, 'info, CompleteUnstake<'info>>) -> Result<()> {
+        instructions::complete_unstake::process_complete_unstake(ctx)
+    }
+
+    pub fn claim_staking_yield(ctx: Context<ClaimStakingYield>) -> Result<()> {
+        instructions::claim_staking_yield::process_claim_staking_yield(ctx)
+    }
+
+    pub fn set_withdrawal_review_config(
+        ctx: Context<SetWithdrawalReviewConfig>,
+        risk_admin: Pubkey,
+        withdrawal_review_threshold: u64,
+        withdrawal_review_delay: i64,
+    ) -> Result<()> {
+        instructions::set_withdrawal_review_config::process_set_withdrawal_review_config(
+            ctx,
+            risk_admin,
+            withdrawal_review_threshold,
+            withdrawal_review_delay,
+        )
+    }
+
+    pub fn request_sol_withdrawal(ctx: Context<RequestSolWithdrawal>, amount: u64) -> Result<()> {
+        instructions::request_sol_withdrawal::process_request_sol_withdrawal(ctx, amount)
+    }
+
+    pub fn execute_sol_withdrawal<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ExecuteSolWithdrawal<'info>>,
+    ) -> Result<()> {
+        instructions::execute_sol_withdrawal::process_execute_sol_withdrawal(ctx)
+    }
+
+    pub fn cancel_sol_withdrawal(ctx: Context<CancelSolWithdrawal>) -> Result<()> {
+        instructions::cancel_sol_withdrawal::process_cancel_sol_withdrawal(ctx)
+    }
+
+    pub fn block_sol_withdrawal(ctx: Context<BlockSolWithdrawal>) -> Result<()> {
+        instructions::block_sol_withdrawal::process_block_sol_withdrawal(ctx)
+    }
+
+    pub fn unfreeze_escrow_withdrawals(
+        ctx: Context<UnfreezeEscrowWithdrawals>,
+        user: Pubkey,
+    ) -> Result<()> {
+        instructions::unfreeze_escrow_withdrawals::process_unfreeze_escrow_withdrawals(ctx, user)
+    }
+
+    pub fn set_referral_share(
+        ctx: Context<SetReferralShare>,
+        referral_share_basis_points: u16,
+    ) -> Result<()> {
+        instructions::set_referral_share::process_set_referral_share(ctx, referral_share_basis_points)
+    }
+
+    pub fn register_referrer(ctx: Context<RegisterReferrer>, referrer: Pubkey) -> Result<()> {
+        instructions::register_referrer::process_register_referrer(ctx, referrer)
+    }
+
+    pub fn claim_referral_earnings(ctx: Context<ClaimReferralEarnings>) -> Result<()> {
+        instructions::claim_referral_earnings::process_claim_referral_earnings(ctx)
+    }
+
+    pub fn set_table_payout_mode(
+        ctx: Context<SetTablePayoutMode>,
+        table_id: u64,
+        payout_mode: PayoutMode,
+        payout_tiers: Vec<u16>,
+    ) -> Result<()> {
+        instructions::set_table_payout_mode::process_set_table_payout_mode(
             ctx,
-            seed_commitment,
-            round_duration,
-            round_id_for_seed,
+            table_id,
+            payout_mode,
+            payout_tiers,
         )
     }
 
-    pub fn place_sol_bet(
-        ctx: Context<PlaceSolBet>,
-        round_id_for_pdas: u64,
+    pub fn initialize_round_history(ctx: Context<InitializeRoundHistory>, table_id: u64) -> Result<()> {
+        instructions::initialize_round_history::process_initialize_round_history(ctx, table_id)
+    }
+
+    pub fn create_leaderboard(
+        ctx: Context<CreateLeaderboard>,
+        leaderboard_id: u64,
+        metric: LeaderboardMetric,
+        start_time: i64,
+        end_time: i64,
+        prize_shares_basis_points: Vec<u16>,
+    ) -> Result<()> {
+        instructions::create_leaderboard::process_create_leaderboard(
+            ctx,
+            leaderboard_id,
+            metric,
+            start_time,
+            end_time,
+            prize_shares_basis_points,
+        )
+    }
+
+    pub fn set_table_leaderboards(
+        ctx: Context<SetTableLeaderboards>,
+        table_id: u64,
+        wagered_leaderboard_id: Option<u64>,
+        won_leaderboard_id: Option<u64>,
+    ) -> Result<()> {
+        instructions::set_table_leaderboards::process_set_table_leaderboards(
+            ctx,
+            table_id,
+            wagered_leaderboard_id,
+            won_leaderboard_id,
+        )
+    }
+
+    pub fn fund_leaderboard_prizes<'info>(
+        ctx: Context<'_, '_, 'info, 'info, FundLeaderboardPrizes<'info>>,
+        leaderboard_id: u64,
         amount: u64,
     ) -> Result<()> {
-        instructions::place_bet::process_place_sol_bet(ctx, round_id_for_pdas, amount)
+        instructions::fund_leaderboard_prizes::process_fund_leaderboard_prizes(ctx, leaderboard_id, amount)
     }
 
-    pub fn finalize_round(
-        ctx: Context<FinalizeRound>,
-        revealed_seed_arg: SeedArray,
+    pub fn mint_leaderboard_prizes(
+        ctx: Context<MintLeaderboardPrizes>,
+        leaderboard_id: u64,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::mint_leaderboard_prizes::process_mint_leaderboard_prizes(ctx, leaderboard_id, amount)
+    }
+
+    pub fn claim_leaderboard_prize<'info>(ctx: Context<'_, '_, 'info, 'info, ClaimLeaderboardPrize<'info>>, leaderboard_id: u64) -> Result<()> {
+        instructions::claim_leaderboard_prize::process_claim_leaderboard_prize(ctx, leaderboard_id)
+    }
+
+    pub fn initialize_jackpot(
+        ctx: Context<InitializeJackpot>,
+        contribution_basis_points: u16,
+        odds: u64,
+    ) -> Result<()> {
+        instructions::initialize_jackpot::process_initialize_jackpot(ctx, contribution_basis_points, odds)
+    }
+
+    pub fn update_jackpot_config(
+        ctx: Context<UpdateJackpotConfig>,
+        contribution_basis_points: u16,
+        odds: u64,
+    ) -> Result<()> {
+        instructions::update_jackpot_config::process_update_jackpot_config(
+            ctx,
+            contribution_basis_points,
+            odds,
+        )
+    }
+
+    pub fn claim_jackpot(
+        ctx: Context<ClaimJackpot>,
+        table_id: u64,
         round_id_for_pdas: u64,
     ) -> Result<()> {
-        instructions::finalize_round::process_finalize_round(
+        instructions::claim_jackpot::process_claim_jackpot(ctx, table_id, round_id_for_pdas)
+    }
+
+    pub fn initialize_house_bankroll(
+        ctx: Context<InitializeHouseBankroll>,
+        withdrawal_delay: i64,
+    ) -> Result<()> {
+        instructions::initialize_house_bankroll::process_initialize_house_bankroll(
             ctx,
-            revealed_seed_arg,
-            round_id_for_pdas,
+            withdrawal_delay,
         )
     }
 
-    pub fn create_reward_pot_accounts(
-        ctx: Context<CreateRewardPotAccounts>,
+    pub fn deposit_house_liquidity(ctx: Context<DepositHouseLiquidity>, amount: u64) -> Result<()> {
+        instructions::deposit_house_liquidity::process_deposit_house_liquidity(ctx, amount)
+    }
+
+    pub fn complete_house_liquidity_deposit(ctx: Context<CompleteHouseLiquidityDeposit>) -> Result<()> {
+        instructions::complete_house_liquidity_deposit::process_complete_house_liquidity_deposit(ctx)
+    }
+
+    pub fn request_house_liquidity_withdrawal(
+        ctx: Context<RequestHouseLiquidityWithdrawal>,
+        shares: u64,
+    ) -> Result<()> {
+        instructions::request_house_liquidity_withdrawal::process_request_house_liquidity_withdrawal(
+            ctx, shares,
+        )
+    }
+
+    pub fn complete_house_liquidity_withdrawal(
+        ctx: Context<CompleteHouseLiquidityWithdrawal>,
+    ) -> Result<()> {
+        instructions::complete_house_liquidity_withdrawal::process_complete_house_liquidity_withdrawal(ctx)
+    }
+
+    pub fn configure_fixed_odds_wheel(
+        ctx: Context<ConfigureFixedOddsWheel>,
+        table_id: u64,
+        segments: Vec<WheelSegment>,
+    ) -> Result<()> {
+        instructions::configure_fixed_odds_wheel::process_configure_fixed_odds_wheel(
+            ctx, table_id, segments,
+        )
+    }
+
+    pub fn place_fixed_odds_bet(
+        ctx: Context<PlaceFixedOddsBet>,
+        table_id: u64,
         round_id_for_pdas: u64,
+        segment: u8,
+        amount: u64,
     ) -> Result<()> {
-        instructions::create_reward_pot_accounts::process_create_reward_pot_accounts(
+        instructions::place_fixed_odds_bet::process_place_fixed_odds_bet(
             ctx,
+            table_id,
             round_id_for_pdas,
+            segment,
+            amount,
         )
     }
-    pub fn mint_tokens_to_reward_pot(
-        ctx: Context<MintTokensToRewardPot>,
+
+    pub fn reveal_fixed_odds_seed(
+        ctx: Context<RevealFixedOddsSeed>,
+        table_id: u64,
+        revealed_seed_arg: SeedArray,
         round_id_for_pdas: u64,
     ) -> Result<()> {
-        instructions::mint_tokens_to_reward_pot::process_mint_tokens_to_reward_pot(
+        instructions::reveal_fixed_odds_seed::process_reveal_fixed_odds_seed(
             ctx,
+            table_id,
+            revealed_seed_arg,
             round_id_for_pdas,
         )
     }
 
-    pub fn calculate_reward_entitlements(
-        ctx: Context<CalculateRewardEntitlements>,
+    pub fn settle_fixed_odds_round(
+        ctx: Context<SettleFixedOddsRound>,
+        table_id: u64,
         round_id_for_pdas: u64,
     ) -> Result<()> {
-        instructions::calculate_reward_entitlements::process_calculate_reward_entitlements(
+        instructions::settle_fixed_odds_round::process_settle_fixed_odds_round(
             ctx,
+            table_id,
             round_id_for_pdas,
         )
     }
 
-    pub fn claim_cashino_rewards(
-        ctx: Context<ClaimCashinoRewards>,
+    pub fn set_withdrawal_fee_schedule(
+        ctx: Context<SetWithdrawalFeeSchedule>,
+        min_fee_lamports: u64,
+        fee_basis_points: u16,
+        max_fee_lamports: u64,
+        discount_tiers: Vec<FeeDiscountTier>,
+        fee_free_start: i64,
+        fee_free_end: i64,
+    ) -> Result<()> {
+        instructions::set_withdrawal_fee_schedule::process_set_withdrawal_fee_schedule(
+            ctx,
+            min_fee_lamports,
+            fee_basis_points,
+            max_fee_lamports,
+            discount_tiers,
+            fee_free_start,
+            fee_free_end,
+        )
+    }
+
+    pub fn set_rakeback_schedule(
+        ctx: Context<SetRakebackSchedule>,
+        base_basis_points: u16,
+        tiers: Vec<RakebackTier>,
+    ) -> Result<()> {
+        instructions::set_rakeback_schedule::process_set_rakeback_schedule(ctx, base_basis_points, tiers)
+    }
+
+    pub fn distribute_rakeback<'info>(
+        ctx: Context<'_, '_, 'info, 'info, DistributeRakeback<'info>>,
+        table_id: u64,
         round_id_for_pdas: u64,
     ) -> Result<()> {
-        instructions::claim_cashino_rewards::process_claim_cashino_rewards(ctx, round_id_for_pdas)
+        instructions::distribute_rakeback::process_distribute_rakeback(ctx, table_id, round_id_for_pdas)
     }
 
-    pub fn deposit_sol(ctx: Context<DepositSol>, amount: u64) -> Result<()> {
-        instructions::deposit_sol::process_deposit_sol(ctx, amount)
+    #[allow(clippy::too_many_arguments)]
+    pub fn set_emission_schedule(
+        ctx: Context<SetEmissionSchedule>,
+        table_id: u64,
+        initial_per_round: u64,
+        halving_interval_rounds: u64,
+        halving_interval_seconds: i64,
+        max_supply: u64,
+        volume_reference: u64,
+        volume_multiplier_cap_basis_points: u16,
+    ) -> Result<()> {
+        instructions::set_emission_schedule::process_set_emission_schedule(
+            ctx,
+            table_id,
+            initial_per_round,
+            halving_interval_rounds,
+            halving_interval_seconds,
+            max_supply,
+            volume_reference,
+            volume_multiplier_cap_basis_points,
+        )
     }
 
-    pub fn withdraw_sol_from_platform(
-        ctx: Context<WithdrawSolFromPlatform>,
-        amount_to_withdraw: u64,
+    pub fn set_fee_treasury(ctx: Context<SetFeeTreasury>) -> Result<()> {
+        instructions::set_fee_treasury::process_set_fee_treasury(ctx)
+    }
+
+    pub fn collect_transfer_fees<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CollectTransferFees<'info>>,
     ) -> Result<()> {
-        instructions::withdraw_sol_from_platform::process_withdraw_sol_from_platform(
+        instructions::collect_transfer_fees::process_collect_transfer_fees(ctx)
+    }
+
+    pub fn set_fee_burn(ctx: Context<SetFeeBurn>, fee_burn_basis_points: u16) -> Result<()> {
+        instructions::set_fee_burn::process_set_fee_burn(ctx, fee_burn_basis_points)
+    }
+
+    pub fn set_burn_config(
+        ctx: Context<SetBurnConfig>,
+        table_id: u64,
+        burn_unclaimed_rewards: bool,
+        unclaimed_reward_claim_window: i64,
+    ) -> Result<()> {
+        instructions::set_burn_config::process_set_burn_config(
             ctx,
-            amount_to_withdraw,
+            table_id,
+            burn_unclaimed_rewards,
+            unclaimed_reward_claim_window,
         )
     }
 
-    pub fn claim_sol_winnings(
-        ctx: Context<ClaimSolWinnings>,
+    pub fn burn_unclaimed_rewards(
+        ctx: Context<BurnUnclaimedRewards>,
+        table_id: u64,
         round_id_for_pdas: u64,
     ) -> Result<()> {
-        instructions::claim_sol_winnings::process_claim_sol_winnings(ctx, round_id_for_pdas)
+        instructions::burn_unclaimed_rewards::process_burn_unclaimed_rewards(
+            ctx,
+            table_id,
+            round_id_for_pdas,
+        )
+    }
+
+    pub fn set_fee_split(
+        ctx: Context<SetFeeSplit>,
+        recipients: Vec<FeeRecipient>,
+        dust_recipient_index: u8,
+    ) -> Result<()> {
+        instructions::set_fee_split::process_set_fee_split(ctx, recipients, dust_recipient_index)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_vesting<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CreateVesting<'info>>,
+        vesting_id: u64,
+        amount: u64,
+        start_time: i64,
+        cliff_time: i64,
+        end_time: i64,
+        revocable: bool,
+        funding: VestingFunding,
+    ) -> Result<()> {
+        instructions::create_vesting::process_create_vesting(
+            ctx, vesting_id, amount, start_time, cliff_time, end_time, revocable, funding,
+        )
+    }
+
+    pub fn claim_vested<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ClaimVested<'info>>,
+        vesting_id: u64,
+    ) -> Result<()> {
+        instructions::claim_vested::process_claim_vested(ctx, vesting_id)
+    }
+
+    pub fn revoke_vesting<'info>(
+        ctx: Context<'_, '_, 'info, 'info, RevokeVesting<'info>>,
+        beneficiary: Pubkey,
+        vesting_id: u64,
+    ) -> Result<()> {
+        instructions::revoke_vesting::process_revoke_vesting(ctx, beneficiary, vesting_id)
     }
 
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bankroll(balance: u64, reserved_exposure: u64) -> HouseBankroll {
+        HouseBankroll {
+            balance,
+            reserved_exposure,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn first_deposit_mints_shares_one_to_one() {
+        assert_eq!(bankroll(0, 0).shares_for_deposit(5_000, 0).unwrap(), 5_000);
+    }
+
+    #[test]
+    fn emptied_pool_with_outstanding_shares_takes_no_deposits() {
+        assert_eq!(
+            bankroll(0, 0).shares_for_deposit(5_000, 1_000).unwrap_err(),
+            error!(ErrorCode::BankrollDepleted)
+        );
+    }
+
+    #[test]
+    fn queued_deposits_get_the_worse_of_the_two_prices() {
+        // Requested at 2 lamports per share.
+        let request = LpDepositRequest {
+            lamports: 1_000,
+            balance_at_request: 2_000,
+            lp_supply_at_request: 1_000,
+            ..Default::default()
+        };
+        // A house win known at request time lifts the price to 4; the provider pays it.
+        assert_eq!(bankroll(4_000, 0).shares_for_queued_deposit(&request, 1_000).unwrap(), 250);
+        // A loss in the meantime doesn't make the shares cheaper than at request time.
+        assert_eq!(bankroll(1_000, 0).shares_for_queued_deposit(&request, 1_000).unwrap(), 500);
+    }
+
+    #[test]
+    fn deposits_mint_shares_at_the_current_price() {
+        // 2 lamports per share after the pool has earned fees.
+        let pool = bankroll(2_000, 0);
+        assert_eq!(pool.shares_for_deposit(500, 1_000).unwrap(), 250);
+        // Rounds down in the pool's favour.
+        assert_eq!(pool.shares_for_deposit(3, 1_000).unwrap(), 1);
+    }
+
+    #[test]
+    fn redemptions_pay_out_at_the_current_price() {
+        let pool = bankroll(2_000, 0);
+        assert_eq!(pool.lamports_for_shares(250, 1_000).unwrap(), 500);
+        assert_eq!(pool.lamports_for_shares(1_000, 1_000).unwrap(), 2_000);
+        assert_eq!(
+            pool.lamports_for_shares(1, 0).unwrap_err(),
+            error!(ErrorCode::InvalidLpShareAmount)
+        );
+    }
+
+    #[test]
+    fn reserved_exposure_is_not_available() {
+        assert_eq!(bankroll(2_000, 500).available_liquidity(), 1_500);
+        assert_eq!(bankroll(100, 500).available_liquidity(), 0);
+    }
+
+    fn escrow_with_limits(limits: GamingLimits) -> UserPlatformEscrow {
+        UserPlatformEscrow {
+            limits,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn tightest_ignores_unset_limits() {
+        let current = GamingLimits {
+            daily_deposit_limit: 1_000,
+            daily_wager_limit: 500,
+            ..Default::default()
+        };
+        let requested = GamingLimits {
+            daily_deposit_limit: 2_000,
+            daily_wager_limit: 200,
+            daily_loss_limit: 100,
+            ..Default::default()
+        };
+        assert_eq!(
+            current.tightest(&requested),
+            GamingLimits {
+                daily_deposit_limit: 1_000,
+                daily_wager_limit: 200,
+                daily_loss_limit: 100,
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn wagers_are_capped_per_round_and_per_day() {
+        let now = 10 * SECONDS_PER_DAY;
+        let mut escrow = escrow_with_limits(GamingLimits {
+            per_round_wager_limit: 300,
+            daily_wager_limit: 500,
+            ..Default::default()
+        });
+        assert_eq!(
+            escrow.record_wager(100, 301, now).unwrap_err(),
+            error!(ErrorCode::WagerLimitExceeded)
+        );
+        escrow.record_wager(300, 300, now).unwrap();
+        escrow.record_wager(200, 200, now).unwrap();
+        assert_eq!(
+            escrow.record_wager(1, 1, now).unwrap_err(),
+            error!(ErrorCode::WagerLimitExceeded)
+        );
+        assert_eq!(escrow.daily_wagered, 500);
+    }
+
+    #[test]
+    fn loss_limit_counts_wagers_net_of_winnings() {
+        let now = 10 * SECONDS_PER_DAY;
+        let mut escrow = escrow_with_limits(GamingLimits {
+            daily_loss_limit: 100,
+            ..Default::default()
+        });
+        escrow.record_wager(100, 100, now).unwrap();
+        assert_eq!(
+            escrow.record_wager(1, 1, now).unwrap_err(),
+            error!(ErrorCode::LossLimitExceeded)
+        );
+        escrow.record_winnings(50, now).unwrap();
+        escrow.record_wager(50, 50, now).unwrap();
+    }
+
+    #[test]
+    fn daily_counters_reset_at_the_day_boundary() {
+        let day = 10 * SECONDS_PER_DAY;
+        let mut escrow = escrow_with_limits(GamingLimits {
+            daily_deposit_limit: 100,
+            daily_wager_limit: 100,
+            ..Default::default()
+        });
+        escrow.record_deposit(100, day).unwrap();
+        escrow.record_wager(100, 100, day + SECONDS_PER_DAY - 1).unwrap();
+        assert_eq!(
+            escrow.record_deposit(1, day + SECONDS_PER_DAY - 1).unwrap_err(),
+            error!(ErrorCode::DepositLimitExceeded)
+        );
+
+        escrow.record_deposit(100, day + SECONDS_PER_DAY).unwrap();
+        escrow.record_wager(100, 100, day + SECONDS_PER_DAY).unwrap();
+        assert_eq!(escrow.daily_deposited, 100);
+        assert_eq!(escrow.daily_wagered, 100);
+    }
+
+    #[test]
+    fn weekly_deposit_limit_spans_days() {
+        let week = 10 * SECONDS_PER_WEEK;
+        let mut escrow = escrow_with_limits(GamingLimits {
+            weekly_deposit_limit: 150,
+            ..Default::default()
+        });
+        escrow.record_deposit(100, week).unwrap();
+        assert_eq!(
+            escrow.record_deposit(100, week + SECONDS_PER_DAY).unwrap_err(),
+            error!(ErrorCode::DepositLimitExceeded)
+        );
+        escrow.record_deposit(100, week + SECONDS_PER_WEEK).unwrap();
+    }
+
+    #[test]
+    fn self_excluded_players_cannot_wager_or_deposit() {
+        let now = 10 * SECONDS_PER_DAY;
+        let mut escrow = UserPlatformEscrow {
+            self_excluded_until: now + 1,
+            ..Default::default()
+        };
+        assert_eq!(
+            escrow.record_wager(1, 1, now).unwrap_err(),
+            error!(ErrorCode::SelfExcluded)
+        );
+        assert_eq!(
+            escrow.record_deposit(1, now).unwrap_err(),
+            error!(ErrorCode::SelfExcluded)
+        );
+        escrow.record_wager(1, 1, now + 1).unwrap();
+        escrow.record_deposit(1, now + 1).unwrap();
+    }
+
+    #[test]
+    fn loosened_limits_wait_for_the_cooldown() {
+        let now = 10 * SECONDS_PER_DAY;
+        let strict = GamingLimits {
+            daily_wager_limit: 100,
+            ..Default::default()
+        };
+        let loose = GamingLimits {
+            daily_wager_limit: 1_000,
+            ..Default::default()
+        };
+        let mut escrow = escrow_with_limits(strict);
+
+        escrow.request_gaming_limits(loose, now).unwrap();
+        assert_eq!(escrow.limits, strict);
+        assert_eq!(
+            escrow.pending_limits_effective_at,
+            now + GAMING_LIMIT_LOOSENING_COOLDOWN
+        );
+
+        escrow.refresh_gaming_limits(now + GAMING_LIMIT_LOOSENING_COOLDOWN);
+        assert_eq!(escrow.limits, loose);
+        assert_eq!(escrow.pending_limits_effective_at, 0);
+
+        escrow.request_gaming_limits(strict, now + GAMING_LIMIT_LOOSENING_COOLDOWN).unwrap();
+        assert_eq!(escrow.limits, strict);
+        assert_eq!(escrow.pending_limits_effective_at, 0);
+    }
+
+    fn fee_schedule() -> WithdrawalFeeSchedule {
+        let mut schedule = WithdrawalFeeSchedule {
+            min_fee_lamports: 1_000,
+            fee_basis_points: 100,
+            max_fee_lamports: 50_000,
+            ..Default::default()
+        };
+        schedule.discount_tiers[0] = FeeDiscountTier {
+            min_cashino_amount: 100,
+            discount_basis_points: 2_500,
+        };
+        schedule.discount_tiers[1] = FeeDiscountTier {
+            min_cashino_amount: 1_000,
+            discount_basis_points: 10_000,
+        };
+        schedule.discount_tier_count = 2;
+        schedule
+    }
+
+    #[test]
+    fn default_withdrawal_fee_is_the_flat_minimum() {
+        let schedule = WithdrawalFeeSchedule::default_schedule();
+        assert_eq!(
+            schedule.fee_for(1_000_000_000, 0, 0).unwrap(),
+            DEFAULT_WITHDRAWAL_MIN_FEE_LAMPORTS
+        );
+    }
+
+    #[test]
+    fn withdrawal_fee_adds_the_variable_part_up_to_the_cap() {
+        let schedule = fee_schedule();
+        assert_eq!(schedule.fee_for(0, 0, 0).unwrap(), 1_000);
+        assert_eq!(schedule.fee_for(100_000, 0, 0).unwrap(), 2_000);
+        assert_eq!(schedule.fee_for(100_000_000, 0, 0).unwrap(), 50_000);
+        assert_eq!(
+            schedule.fee_for(u64::MAX, 0, 0).unwrap_err(),
+            error!(ErrorCode::CalculationError)
+        );
+    }
+
+    #[test]
+    fn withdrawal_fee_applies_the_best_qualifying_discount() {
+        let schedule = fee_schedule();
+        assert_eq!(schedule.fee_for(100_000, 99, 0).unwrap(), 2_000);
+        assert_eq!(schedule.fee_for(100_000, 100, 0).unwrap(), 1_500);
+        assert_eq!(schedule.fee_for(100_000, 1_000, 0).unwrap(), 0);
+
+        // Tiers past `discount_tier_count` are ignored.
+        let mut one_tier = schedule;
+        one_tier.discount_tier_count = 1;
+        assert_eq!(one_tier.fee_for(100_000, 1_000, 0).unwrap(), 1_500);
+    }
+
+    #[test]
+    fn withdrawals_in_the_fee_free_window_are_free() {
+        let mut schedule = fee_schedule();
+        schedule.fee_free_start = 100;
+        schedule.fee_free_end = 200;
+        assert_eq!(schedule.fee_for(100_000, 0, 99).unwrap(), 2_000);
+        assert_eq!(schedule.fee_for(100_000, 0, 100).unwrap(), 0);
+        assert_eq!(schedule.fee_for(100_000, 0, 199).unwrap(), 0);
+        assert_eq!(schedule.fee_for(100_000, 0, 200).unwrap(), 2_000);
+    }
+
+    fn stake(pool: &mut StakingPool, position: &mut StakerPosition, amount: u64) {
+        position.settle_yield(pool).unwrap();
+        position.staked_amount += amount;
+        pool.total_staked += amount;
+        position.reset_reward_debt(pool).unwrap();
+    }
+
+    #[test]
+    fn nothing_is_shared_while_the_pool_is_empty() {
+        let mut pool = StakingPool {
+            revenue_share_basis_points: 2_000,
+            ..Default::default()
+        };
+        assert_eq!(pool.revenue_share_of(1_000).unwrap(), 0);
+        assert_eq!(
+            pool.distribute_sol(1_000).unwrap_err(),
+            error!(ErrorCode::NoStakedBalance)
+        );
+
+        pool.total_staked = 1;
+        assert_eq!(pool.revenue_share_of(1_000).unwrap(), 200);
+    }
+
+    #[test]
+    fn yield_is_split_pro_rata_to_stake() {
+        let mut pool = StakingPool::default();
+        let mut alice = StakerPosition::default();
+        let mut bob = StakerPosition::default();
+        stake(&mut pool, &mut alice, 300);
+        stake(&mut pool, &mut bob, 100);
+
+        pool.distribute_sol(4_000).unwrap();
+        alice.settle_yield(&pool).unwrap();
+        bob.settle_yield(&pool).unwrap();
+        assert_eq!(alice.pending_sol, 3_000);
+        assert_eq!(bob.pending_sol, 1_000);
+        assert_eq!(pool.total_sol_distributed, 4_000);
+
+        // Settling again without new yield pays nothing twice.
+        alice.settle_yield(&pool).unwrap();
+        assert_eq!(alice.pending_sol, 3_000);
+    }
+
+    #[test]
+    fn late_stakers_do_not_earn_earlier_yield() {
+        let mut pool = StakingPool::default();
+        let mut alice = StakerPosition::default();
+        let mut bob = StakerPosition::default();
+        stake(&mut pool, &mut alice, 100);
+        pool.distribute_sol(1_000).unwrap();
+
+        stake(&mut pool, &mut bob, 100);
+        pool.distribute_sol(1_000).unwrap();
+
+        alice.settle_yield(&pool).unwrap();
+        bob.settle_yield(&pool).unwrap();
+        assert_eq!(alice.pending_sol, 1_500);
+        assert_eq!(bob.pending_sol, 500);
+    }
+
+    #[test]
+    fn single_winner_tables_pay_first_place_everything() {
+        let mut game_state = GameState {
+            payout_tiers: [7_000, 2_000, 1_000, 0, 0],
+            payout_tier_count: 3,
+            ..Default::default()
+        };
+        assert_eq!(game_state.active_payout_tiers(), &[10_000]);
+
+        game_state.payout_mode = PayoutMode::Tiered;
+        assert_eq!(game_state.active_payout_tiers(), &[7_000, 2_000, 1_000]);
+    }
+
+    #[test]
+    fn each_winner_claims_once_and_the_last_claim_closes_the_round() {
+        let first = Pubkey::new_unique();
+        let second = Pubkey::new_unique();
+        let mut round = RoundState::zeroed();
+        round.winners[0] = RoundWinner {
+            pubkey: first,
+            amount: 700,
+            ..Default::default()
+        };
+        round.winners[1] = RoundWinner {
+            pubkey: second,
+            amount: 300,
+            ..Default::default()
+        };
+        round.winner_count = 2;
+        round.winner_sol_pubkey = first;
+        round.set_status(RoundStatus::AwaitingSolClaim);
+
+        assert_eq!(
+            round.claim_winner_payout(Pubkey::new_unique()).unwrap_err(),
+            error!(ErrorCode::NotTheSolWinner)
+        );
+        assert_eq!(round.claim_winner_payout(second).unwrap(), 300);
+        assert_eq!(
+            round.claim_winner_payout(second).unwrap_err(),
+            error!(ErrorCode::SolWinningsAlreadyClaimed)
+        );
+        assert_eq!(round.get_status().unwrap(), RoundStatus::AwaitingSolClaim);
+
+        assert_eq!(round.claim_winner_payout(first).unwrap(), 700);
+        assert_eq!(round.winner_sol_claimed, 1);
+        assert_eq!(round.get_status().unwrap(), RoundStatus::SolClaimed);
+    }
+
+    fn scores(board: &Leaderboard) -> Vec<u64> {
+        board.entries[..board.entry_count as usize]
+            .iter()
+            .map(|e| e.score)
+            .collect()
+    }
+
+    #[test]
+    fn leaderboard_keeps_entries_sorted_by_score() {
+        let mut board = Leaderboard::default();
+        let (a, b, c) = (Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique());
+        board.record_score(a, 100).unwrap();
+        board.record_score(b, 300).unwrap();
+        board.record_score(c, 200).unwrap();
+        board.record_score(c, 0).unwrap();
+        assert_eq!(scores(&board), vec![300, 200, 100]);
+
+        // Repeat players accumulate and move up past lower scores.
+        board.record_score(a, 250).unwrap();
+        assert_eq!(scores(&board), vec![350, 300, 200]);
+        assert_eq!(board.entries[0].player, a);
+        assert_eq!(board.entries[1].player, b);
+    }
+
+    #[test]
+    fn full_leaderboard_replaces_the_lowest_entry_only_when_beaten() {
+        let mut board = Leaderboard::default();
+        for score in 1..=LEADERBOARD_SIZE as u64 {
+            board.record_score(Pubkey::new_unique(), score * 10).unwrap();
+        }
+        let newcomer = Pubkey::new_unique();
+
+        // A tie with the last place does not displace it.
+        board.record_score(newcomer, 10).unwrap();
+        assert!(board.entries.iter().all(|e| e.player != newcomer));
+
+        board.record_score(newcomer, 15).unwrap();
+        assert_eq!(board.entry_count as usize, LEADERBOARD_SIZE);
+        assert_eq!(board.entries[LEADERBOARD_SIZE - 1].player, newcomer);
+        assert_eq!(board.entries[LEADERBOARD_SIZE - 1].score, 15);
+    }
+
+    #[test]
+    fn leaderboard_is_active_between_start_and_end() {
+        let board = Leaderboard {
+            start_time: 100,
+            end_time: 200,
+            ..Default::default()
+        };
+        assert!(!board.is_active(99));
+        assert!(board.is_active(100));
+        assert!(board.is_active(199));
+        assert!(!board.is_active(200));
+    }
+
+    #[test]
+    fn leaderboard_prizes_follow_rank_shares() {
+        let mut board = Leaderboard {
+            prize_pool: 1_001,
+            ..Default::default()
+        };
+        board.prize_shares_basis_points[0] = 6_000;
+        board.prize_shares_basis_points[1] = 4_000;
+        assert_eq!(board.prize_for(0).unwrap(), 600);
+        assert_eq!(board.prize_for(1).unwrap(), 400);
+        assert_eq!(board.prize_for(2).unwrap(), 0);
+    }
+
+    fn rakeback_schedule() -> RakebackSchedule {
+        let mut schedule = RakebackSchedule {
+            base_basis_points: 500,
+            ..Default::default()
+        };
+        schedule.tiers[0] = RakebackTier {
+            min_total_wagered: 1_000,
+            rakeback_basis_points: 1_000,
+        };
+        schedule.tiers[1] = RakebackTier {
+            min_total_wagered: 10_000,
+            rakeback_basis_points: 2_000,
+        };
+        schedule.tier_count = 2;
+        schedule
+    }
+
+    #[test]
+    fn rakeback_rate_uses_the_highest_qualifying_tier() {
+        let schedule = rakeback_schedule();
+        assert_eq!(schedule.rate_for(0), 500);
+        assert_eq!(schedule.rate_for(999), 500);
+        assert_eq!(schedule.rate_for(1_000), 1_000);
+        assert_eq!(schedule.rate_for(10_000), 2_000);
+        assert_eq!(schedule.rate_for(u64::MAX), 2_000);
+        assert_eq!(schedule.max_rate(), 2_000);
+    }
+
+    #[test]
+    fn rakeback_ignores_tiers_past_the_count_and_below_the_base() {
+        let mut schedule = rakeback_schedule();
+        schedule.tier_count = 1;
+        assert_eq!(schedule.rate_for(10_000), 1_000);
+        assert_eq!(schedule.max_rate(), 1_000);
+
+        schedule.base_basis_points = 1_500;
+        assert_eq!(schedule.rate_for(1_000), 1_500);
+        assert_eq!(schedule.max_rate(), 1_500);
+
+        assert_eq!(RakebackSchedule::default().max_rate(), 0);
+    }
+
+    #[test]
+    fn rakeback_rates_are_fixed_from_volume_recorded_at_bet_time() {
+        let schedule = rakeback_schedule();
+        let (low, high) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let mut round = RoundState::zeroed();
+        round.players[0] = PlayerData { pubkey: low, amount: 10 };
+        round.players[1] = PlayerData { pubkey: high, amount: 10 };
+        round.player_count = 2;
+
+        round.record_player_volume(low, 500);
+        round.record_player_volume(high, 20_000);
+        round.record_player_volume(Pubkey::new_unique(), 20_000);
+        round.snapshot_rakeback_rates(&schedule);
+        assert_eq!(round.player_rakeback_basis_points[..2], [500, 2_000]);
+    }
+
+    #[test]
+    fn rakeback_reserve_covers_only_the_snapshotted_rates() {
+        let mut round = RoundState::zeroed();
+        round.players[0] = PlayerData { pubkey: Pubkey::new_unique(), amount: 300_000 };
+        round.players[1] = PlayerData { pubkey: Pubkey::new_unique(), amount: 100_000 };
+        round.player_count = 2;
+        round.total_sol_pot = 400_000;
+        round.house_sol_fee = 20_000;
+        round.record_player_volume(round.players[0].pubkey, 500);
+        round.record_player_volume(round.players[1].pubkey, 20_000);
+        round.snapshot_rakeback_rates(&rakeback_schedule());
+
+        // 5% of 15_000 and 20% of 5_000; the top tier's rate on the whole fee would be 4_000.
+        assert_eq!(round.rakeback_owed(0).unwrap(), 750);
+        assert_eq!(round.rakeback_owed(1).unwrap(), 1_000);
+        assert_eq!(round.total_rakeback_owed().unwrap(), 1_750);
+    }
+
+    #[test]
+    fn unset_emission_schedule_falls_back_to_the_legacy_flat_reward() {
+        let schedule = EmissionSchedule::default();
+        assert_eq!(schedule.halvings_at(1_000_000, i64::MAX), 0);
+        assert_eq!(
+            schedule.emission_for_round(1_000_000, 0, 0).unwrap(),
+            CASHINO_REWARD_PER_ROUND_UNITS
+        );
+        assert_eq!(schedule.supply_cap(), CASHINO_MAX_SUPPLY_UNITS);
+    }
+
+    #[test]
+    fn emission_halves_every_interval_of_rounds() {
+        let schedule = EmissionSchedule {
+            initial_per_round: 1_000,
+            halving_interval_rounds: 10,
+            ..Default::default()
+        };
+        assert_eq!(schedule.emission_for_round(9, 0, 0).unwrap(), 1_000);
+        assert_eq!(schedule.emission_for_round(10, 0, 0).unwrap(), 500);
+        assert_eq!(schedule.emission_for_round(25, 0, 0).unwrap(), 250);
+    }
+
+    #[test]
+    fn emission_halves_every_interval_of_time_after_the_start() {
+        let schedule = EmissionSchedule {
+            initial_per_round: 1_000,
+            halving_interval_seconds: 100,
+            schedule_start: 1_000,
+            ..Default::default()
+        };
+        assert_eq!(schedule.halvings_at(0, 0), 0);
+        assert_eq!(schedule.halvings_at(0, 1_099), 0);
+        assert_eq!(schedule.halvings_at(0, 1_100), 1);
+        assert_eq!(schedule.emission_for_round(0, 0, 1_300).unwrap(), 125);
+    }
+
+    #[test]
+    fn emission_reaches_zero_instead_of_overflowing_the_shift() {
+        let schedule = EmissionSchedule {
+            initial_per_round: u64::MAX,
+            halving_interval_rounds: 1,
+            ..Default::default()
+        };
+        assert_eq!(schedule.emission_for_round(63, 0, 0).unwrap(), 1);
+        assert_eq!(schedule.emission_for_round(64, 0, 0).unwrap(), 0);
+        assert_eq!(schedule.emission_for_round(u64::MAX, 0, 0).unwrap(), 0);
+
+        let by_time = EmissionSchedule {
+            initial_per_round: 1_000,
+            halving_interval_seconds: 1,
+            schedule_start: i64::MIN,
+            ..Default::default()
+        };
+        assert_eq!(by_time.emission_for_round(0, 0, i64::MAX).unwrap(), 0);
+    }
+
+    #[test]
+    fn emission_scales_with_round_volume_up_to_the_cap() {
+        let schedule = EmissionSchedule {
+            initial_per_round: 1_000,
+            volume_reference: 10_000,
+            volume_multiplier_cap_basis_points: 20_000,
+            ..Default::default()
+        };
+        assert_eq!(schedule.emission_for_round(0, 0, 0).unwrap(), 0);
+        assert_eq!(schedule.emission_for_round(0, 5_000, 0).unwrap(), 500);
+        assert_eq!(schedule.emission_for_round(0, 10_000, 0).unwrap(), 1_000);
+        assert_eq!(schedule.emission_for_round(0, u64::MAX, 0).unwrap(), 2_000);
+    }
+
+    fn vesting(total_amount: u64) -> VestingEscrow {
+        VestingEscrow {
+            total_amount,
+            start_time: 1_000,
+            cliff_time: 1_250,
+            end_time: 2_000,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn nothing_vests_before_the_cliff() {
+        let escrow = vesting(1_000);
+        assert_eq!(escrow.vested_amount(0).unwrap(), 0);
+        assert_eq!(escrow.vested_amount(1_249).unwrap(), 0);
+    }
+
+    #[test]
+    fn the_cliff_unlocks_everything_vested_since_the_start() {
+        let escrow = vesting(1_000);
+        assert_eq!(escrow.vested_amount(1_250).unwrap(), 250);
+        assert_eq!(escrow.vested_amount(1_500).unwrap(), 500);
+        assert_eq!(escrow.vested_amount(1_999).unwrap(), 999);
+    }
+
+    #[test]
+    fn everything_is_vested_from_the_end_time() {
+        let escrow = vesting(1_000);
+        assert_eq!(escrow.vested_amount(2_000).unwrap(), 1_000);
+        assert_eq!(escrow.vested_amount(i64::MAX).unwrap(), 1_000);
+
+        // Rounding never leaves dust locked at the end.
+        let odd = vesting(7);
+        assert_eq!(odd.vested_amount(1_999).unwrap(), 6);
+        assert_eq!(odd.vested_amount(2_000).unwrap(), 7);
+    }
+
+    #[test]
+    fn revocation_stops_vesting_at_the_revocation_time() {
+        let mut escrow = vesting(1_000);
+        escrow.revoked_at = 1_500;
+        assert_eq!(escrow.vested_amount(1_400).unwrap(), 400);
+        assert_eq!(escrow.vested_amount(1_500).unwrap(), 500);
+        assert_eq!(escrow.vested_amount(3_000).unwrap(), 500);
+
+        escrow.revoked_at = 1_100;
+        assert_eq!(escrow.vested_amount(3_000).unwrap(), 0);
+    }
+
+    #[test]
+    fn claimable_excludes_what_was_already_claimed() {
+        let mut escrow = vesting(1_000);
+        escrow.claimed_amount = 300;
+        assert_eq!(escrow.claimable_amount(1_500).unwrap(), 200);
+        assert_eq!(escrow.claimable_amount(2_000).unwrap(), 700);
+    }
+
+    #[test]
+    fn fee_burn_takes_its_share_of_the_collected_fees() {
+        let platform_config = |fee_burn_basis_points| PlatformConfig {
+            fee_burn_basis_points,
+            ..Default::default()
+        };
+        assert_eq!(platform_config(0).fee_burn_amount(1_000).unwrap(), 0);
+        assert_eq!(platform_config(2_500).fee_burn_amount(1_001).unwrap(), 250);
+        assert_eq!(platform_config(10_000).fee_burn_amount(u64::MAX).unwrap(), u64::MAX);
+    }
+
+    #[test]
+    fn unclaimed_rewards_stay_claimable_for_at_least_the_minimum_window() {
+        let now = 1_000;
+        let burn_config = |unclaimed_reward_claim_window| BurnConfig {
+            unclaimed_reward_claim_window,
+            ..Default::default()
+        };
+        assert_eq!(
+            burn_config(0).reward_claim_deadline(now).unwrap(),
+            now + MIN_UNCLAIMED_REWARD_CLAIM_WINDOW
+        );
+        let longer = MIN_UNCLAIMED_REWARD_CLAIM_WINDOW + 1;
+        assert_eq!(burn_config(longer).reward_claim_deadline(now).unwrap(), now + longer);
+    }
+}
@@ -0,0 +1,377 @@
+//! Layout versions for `GameState` and `UserPlatformEscrow`, and the helpers that move
+//! older accounts onto the current layout.
+//!
+//! Version 0 is the original, unversioned layout: no `version` byte and no reserved space.
+//! V0 accounts are shorter than any versioned layout, so they are recognised by their length.
+//! Every later layout stores its version in the first byte after the discriminator and ends
+//! in reserved bytes, which are always written as zeros.
+//!
+//! Fields added later are carved out of the reserved bytes, and every carve bumps the version:
+//!
+//! - `GameState`: v1 is the first versioned layout, v2 adds `leaderboards`, v3 `emission`
+//!   and v4 `burn`.
+//! - `UserPlatformEscrow`: v1 is the first versioned layout.
+//!
+//! A carved field reads as its default while its bytes are still zero, so an account on an
+//! older versioned layout decodes as the current one and migrating it only records the version.
+
+use crate::{ErrorCode, GameState, UserPlatformEscrow};
+use anchor_lang::prelude::*;
+use anchor_lang::{system_program, Discriminator};
+
+pub const GAME_STATE_VERSION: u8 = 4;
+pub const USER_PLATFORM_ESCROW_VERSION: u8 = 1;
+
+pub const GAME_STATE_SPACE: usize = 8 + std::mem::size_of::<GameState>();
+pub const USER_PLATFORM_ESCROW_SPACE: usize = 8 + std::mem::size_of::<UserPlatformEscrow>();
+
+/// `GameState` as the single-table program stored it.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct GameStateV0 {
+    pub authority: Pubkey,
+    pub house_wallet: Pubkey,
+    pub house_fee_basis_points: u16,
+    pub round_counter: u64,
+    pub is_initialized: bool,
+    pub cashino_mint: Pubkey,
+}
+
+/// Serialized size of `GameStateV0`. The accounts themselves were allocated with
+/// `8 + size_of`, so they can be a few bytes longer.
+pub const GAME_STATE_V0_LEN: usize = 32 + 32 + 2 + 8 + 1 + 32;
+
+impl From<GameStateV0> for GameState {
+    fn from(v0: GameStateV0) -> Self {
+        GameState {
+            authority: v0.authority,
+            house_wallet: v0.house_wallet,
+            house_fee_basis_points: v0.house_fee_basis_points,
+            round_counter: v0.round_counter,
+            is_initialized: v0.is_initialized,
+            cashino_mint: v0.cashino_mint,
+            ..GameState::default()
+        }
+    }
+}
+
+/// `UserPlatformEscrow` as the original program stored it.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct UserPlatformEscrowV0 {
+    pub user_authority: Pubkey,
+    pub balance: u64,
+    pub bump: u8,
+}
+
+/// Serialized size of `UserPlatformEscrowV0`.
+pub const USER_PLATFORM_ESCROW_V0_LEN: usize = 32 + 8 + 1;
+
+impl From<UserPlatformEscrowV0> for UserPlatformEscrow {
+    fn from(v0: UserPlatformEscrowV0) -> Self {
+        UserPlatformEscrow {
+            user_authority: v0.user_authority,
+            balance: v0.balance,
+            bump: v0.bump,
+            ..UserPlatformEscrow::default()
+        }
+    }
+}
+
+/// Reads the layout version of an account that carries `discriminator`. `versioned_len` is
+/// the serialized size of the versioned layouts, which carving reserved bytes doesn't change.
+fn layout_version(
+    data: &[u8],
+    discriminator: &[u8],
+    v0_len: usize,
+    versioned_len: usize,
+) -> Result<u8> {
+    require!(
+        data.len() >= 8 && &data[..8] == discriminator,
+        ErrorCode::UnsupportedAccountVersion
+    );
+    if data.len() < 8 + versioned_len {
+        require!(data.len() >= 8 + v0_len, ErrorCode::UnsupportedAccountVersion);
+        return Ok(0);
+    }
+    Ok(data[8])
+}
+
+fn serialized_len<T: AnchorSerialize>(value: &T) -> Result<usize> {
+    Ok(value.try_to_vec()?.len())
+}
+
+/// Decodes the `GameState` of table `table_id`, stored in any older layout, into the current
+/// one. V0 accounts predate table ids; theirs is taken from the PDA they were found at.
+pub fn upgrade_game_state(data: &[u8], table_id: u64) -> Result<GameState> {
+    let versioned_len = serialized_len(&GameState::default())?;
+    match layout_version(data, &GameState::DISCRIMINATOR, GAME_STATE_V0_LEN, versioned_len)? {
+        0 => {
+            let v0 = GameStateV0::deserialize(&mut &data[8..])
+                .map_err(|_| error!(ErrorCode::UnsupportedAccountVersion))?;
+            Ok(GameState {
+                table_id,
+                ..v0.into()
+            })
+        }
+        version if (1..GAME_STATE_VERSION).contains(&version) => {
+            let mut game_state = GameState::try_deserialize(&mut &data[..])
+                .map_err(|_| error!(ErrorCode::UnsupportedAccountVersion))?;
+            require!(game_state.table_id == table_id, ErrorCode::InvalidTableId);
+            msg!("Upgrading GameState from version {}.", version);
+            game_state.version = GAME_STATE_VERSION;
+            Ok(game_state)
+        }
+        GAME_STATE_VERSION => err!(ErrorCode::AccountAlreadyMigrated),
+        _ => err!(ErrorCode::UnsupportedAccountVersion),
+    }
+}
+
+/// Decodes a `UserPlatformEscrow` stored in any older layout into the current one.
+pub fn upgrade_user_platform_escrow(data: &[u8]) -> Result<UserPlatformEscrow> {
+    let versioned_len = serialized_len(&UserPlatformEscrow::default())?;
+    match layout_version(
+        data,
+        &UserPlatformEscrow::DISCRIMINATOR,
+        USER_PLATFORM_ESCROW_V0_LEN,
+        versioned_len,
+    )? {
+        0 => {
+            let v0 = UserPlatformEscrowV0::deserialize(&mut &data[8..])
+                .map_err(|_| error!(ErrorCode::UnsupportedAccountVersion))?;
+            Ok(v0.into())
+        }
+        USER_PLATFORM_ESCROW_VERSION => err!(ErrorCode::AccountAlreadyMigrated),
+        _ => err!(ErrorCode::UnsupportedAccountVersion),
+    }
+}
+
+/// Reallocs `account` to `new_space`, tops up its rent from `payer` and writes `value` over it.
+pub(crate) fn rewrite_account<'info, T: AccountSerialize>(
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program_info: &AccountInfo<'info>,
+    new_space: usize,
+    value: &T,
+) -> Result<()> {
+    let rent_needed = Rent::get()?.minimum_balance(new_space);
+    let top_up = rent_needed.saturating_sub(Rent::get()?.minimum_balance(account.data_len()));
+    if top_up > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system_program_info.clone(),
+                system_program::Transfer {
+                    from: payer.clone(),
+                    to: account.clone(),
+                },
+            ),
+            top_up,
+        )?;
+        msg!("Topped up {} lamports of rent for the new layout.", top_up);
+    }
+
+    account.realloc(new_space, true)?;
+    let mut data = account.try_borrow_mut_data()?;
+    value.try_serialize(&mut &mut data[..])?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        BurnConfig, EmissionSchedule, GamingLimits, TableLeaderboards, ACCOUNT_RESERVED_BYTES,
+        BURN_CONFIG_LEN, EMISSION_SCHEDULE_LEN, GAME_STATE_RESERVED_BYTES, TABLE_LEADERBOARDS_LEN,
+    };
+
+    /// Size the original program allocated for its accounts: `8 + size_of`, padding included.
+    const GAME_STATE_V0_SPACE: usize = 8 + std::mem::size_of::<GameStateV0>();
+    const USER_PLATFORM_ESCROW_V0_SPACE: usize = 8 + std::mem::size_of::<UserPlatformEscrowV0>();
+
+    fn v0_bytes<T: AnchorSerialize>(discriminator: &[u8], value: &T, space: usize) -> Vec<u8> {
+        let mut data = discriminator.to_vec();
+        value.serialize(&mut data).unwrap();
+        assert!(data.len() <= space);
+        data.resize(space, 0);
+        data
+    }
+
+    fn current_bytes<T: AccountSerialize>(value: &T, space: usize) -> Vec<u8> {
+        let mut data = Vec::new();
+        value.try_serialize(&mut data).unwrap();
+        assert!(data.len() <= space);
+        data.resize(space, 0);
+        data
+    }
+
+    fn sample_game_state_v0() -> GameStateV0 {
+        GameStateV0 {
+            authority: Pubkey::new_unique(),
+            house_wallet: Pubkey::new_unique(),
+            house_fee_basis_points: 250,
+            round_counter: 42,
+            is_initialized: true,
+            cashino_mint: Pubkey::new_unique(),
+        }
+    }
+
+    #[test]
+    fn v0_lengths_match_the_serialized_layouts() {
+        assert_eq!(sample_game_state_v0().try_to_vec().unwrap().len(), GAME_STATE_V0_LEN);
+        let escrow = UserPlatformEscrowV0 {
+            user_authority: Pubkey::new_unique(),
+            balance: 1,
+            bump: 255,
+        };
+        assert_eq!(escrow.try_to_vec().unwrap().len(), USER_PLATFORM_ESCROW_V0_LEN);
+        // The padded allocation still falls short of every versioned layout.
+        const { assert!(GAME_STATE_V0_SPACE >= 8 + GAME_STATE_V0_LEN) };
+        assert!(GAME_STATE_V0_SPACE < 8 + GameState::default().try_to_vec().unwrap().len());
+    }
+
+    #[test]
+    fn carved_fields_fill_the_reserved_bytes_and_are_zero_by_default() {
+        assert_eq!(TableLeaderboards::default().try_to_vec().unwrap().len(), TABLE_LEADERBOARDS_LEN);
+        assert_eq!(EmissionSchedule::default().try_to_vec().unwrap().len(), EMISSION_SCHEDULE_LEN);
+        assert_eq!(BurnConfig::default().try_to_vec().unwrap().len(), BURN_CONFIG_LEN);
+        assert_eq!(
+            TABLE_LEADERBOARDS_LEN + EMISSION_SCHEDULE_LEN + BURN_CONFIG_LEN + GAME_STATE_RESERVED_BYTES,
+            ACCOUNT_RESERVED_BYTES
+        );
+
+        // Older versions hold zeros where the carved fields now sit, which must decode as defaults.
+        let data = GameState::default().try_to_vec().unwrap();
+        assert!(data[data.len() - ACCOUNT_RESERVED_BYTES..].iter().all(|&b| b == 0));
+    }
+
+    #[test]
+    fn migrates_v0_game_state() {
+        let v0 = sample_game_state_v0();
+        for space in [8 + GAME_STATE_V0_LEN, GAME_STATE_V0_SPACE] {
+            let data = v0_bytes(&GameState::DISCRIMINATOR, &v0, space);
+            assert!(GameState::try_deserialize(&mut &data[..]).is_err());
+
+            let migrated = upgrade_game_state(&data, 7).unwrap();
+            assert_eq!(migrated.version, GAME_STATE_VERSION);
+            assert_eq!(migrated.authority, v0.authority);
+            assert_eq!(migrated.house_wallet, v0.house_wallet);
+            assert_eq!(migrated.house_fee_basis_points, 250);
+            assert_eq!(migrated.round_counter, 42);
+            assert!(migrated.is_initialized);
+            assert_eq!(migrated.cashino_mint, v0.cashino_mint);
+            assert_eq!(migrated.table_id, 7);
+            assert_eq!(migrated.min_bet_amount, GameState::default().min_bet_amount);
+            assert_eq!(migrated.emission, EmissionSchedule::default());
+            assert_eq!(migrated.reserved, [0; GAME_STATE_RESERVED_BYTES]);
+
+            // The migrated account round-trips through the current layout.
+            let rewritten = current_bytes(&migrated, GAME_STATE_SPACE);
+            let reloaded = GameState::try_deserialize(&mut &rewritten[..]).unwrap();
+            assert_eq!(reloaded.round_counter, 42);
+            assert_eq!(reloaded.version, GAME_STATE_VERSION);
+        }
+    }
+
+    #[test]
+    fn older_versioned_game_state_only_records_the_new_version() {
+        let game_state = GameState {
+            version: 1,
+            authority: Pubkey::new_unique(),
+            round_counter: 42,
+            table_id: 7,
+            is_initialized: true,
+            ..GameState::default()
+        };
+        let data = current_bytes(&game_state, GAME_STATE_SPACE);
+
+        let migrated = upgrade_game_state(&data, 7).unwrap();
+        assert_eq!(migrated.version, GAME_STATE_VERSION);
+        assert_eq!(migrated.authority, game_state.authority);
+        assert_eq!(migrated.round_counter, 42);
+        assert_eq!(migrated.leaderboards, TableLeaderboards::default());
+        assert_eq!(migrated.emission, EmissionSchedule::default());
+        assert_eq!(migrated.burn, BurnConfig::default());
+
+        assert_eq!(
+            upgrade_game_state(&data, 8).unwrap_err(),
+            error!(ErrorCode::InvalidTableId)
+        );
+    }
+
+    #[test]
+    fn rejects_current_game_state() {
+        let data = current_bytes(&GameState::default(), GAME_STATE_SPACE);
+        assert_eq!(
+            upgrade_game_state(&data, 0).unwrap_err(),
+            error!(ErrorCode::AccountAlreadyMigrated)
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_game_state_version() {
+        let mut data = current_bytes(&GameState::default(), GAME_STATE_SPACE);
+        data[8] = GAME_STATE_VERSION + 1;
+        assert_eq!(
+            upgrade_game_state(&data, 0).unwrap_err(),
+            error!(ErrorCode::UnsupportedAccountVersion)
+        );
+    }
+
+    #[test]
+    fn rejects_wrong_discriminator_and_truncated_accounts() {
+        let data = v0_bytes(
+            &UserPlatformEscrow::DISCRIMINATOR,
+            &sample_game_state_v0(),
+            GAME_STATE_V0_SPACE,
+        );
+        assert_eq!(
+            upgrade_game_state(&data, 0).unwrap_err(),
+            error!(ErrorCode::UnsupportedAccountVersion)
+        );
+
+        let mut data = v0_bytes(
+            &GameState::DISCRIMINATOR,
+            &sample_game_state_v0(),
+            GAME_STATE_V0_SPACE,
+        );
+        data.truncate(8 + GAME_STATE_V0_LEN - 1);
+        assert_eq!(
+            upgrade_game_state(&data, 0).unwrap_err(),
+            error!(ErrorCode::UnsupportedAccountVersion)
+        );
+    }
+
+    #[test]
+    fn migrates_v0_user_platform_escrow() {
+        let v0 = UserPlatformEscrowV0 {
+            user_authority: Pubkey::new_unique(),
+            balance: 5_000_000,
+            bump: 254,
+        };
+        for space in [8 + USER_PLATFORM_ESCROW_V0_LEN, USER_PLATFORM_ESCROW_V0_SPACE] {
+            let data = v0_bytes(&UserPlatformEscrow::DISCRIMINATOR, &v0, space);
+            assert!(UserPlatformEscrow::try_deserialize(&mut &data[..]).is_err());
+
+            let migrated = upgrade_user_platform_escrow(&data).unwrap();
+            assert_eq!(migrated.version, USER_PLATFORM_ESCROW_VERSION);
+            assert_eq!(migrated.user_authority, v0.user_authority);
+            assert_eq!(migrated.balance, 5_000_000);
+            assert_eq!(migrated.bump, 254);
+            assert_eq!(migrated.limits, GamingLimits::default());
+            assert_eq!(migrated.self_excluded_until, 0);
+            assert!(!migrated.withdrawals_frozen);
+
+            let rewritten = current_bytes(&migrated, USER_PLATFORM_ESCROW_SPACE);
+            let reloaded = UserPlatformEscrow::try_deserialize(&mut &rewritten[..]).unwrap();
+            assert_eq!(reloaded.balance, 5_000_000);
+            assert_eq!(reloaded.version, USER_PLATFORM_ESCROW_VERSION);
+        }
+    }
+
+    #[test]
+    fn rejects_current_user_platform_escrow() {
+        let data = current_bytes(&UserPlatformEscrow::default(), USER_PLATFORM_ESCROW_SPACE);
+        assert_eq!(
+            upgrade_user_platform_escrow(&data).unwrap_err(),
+            error!(ErrorCode::AccountAlreadyMigrated)
+        );
+    }
+}
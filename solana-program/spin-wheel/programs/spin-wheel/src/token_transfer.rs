@@ -0,0 +1,41 @@
+//! CASHINO token transfers that stay valid once the mint has a TransferHook extension.
+//!
+//! Token-2022 only invokes the hook if the caller forwards the hook program, its
+//! extra-account-meta list and the accounts that list resolves to. Instructions that move
+//! CASHINO take those from `remaining_accounts`; for mints without a hook they are ignored.
+
+use anchor_lang::prelude::*;
+use anchor_spl::token_2022::spl_token_2022::{
+    self,
+    extension::{transfer_hook, StateWithExtensions},
+    state::Mint as MintState,
+};
+use anchor_spl::token_interface::TransferChecked;
+
+/// Drop-in for `token_interface::transfer_checked` that forwards `ctx.remaining_accounts`
+/// to the mint's transfer hook.
+pub(crate) fn transfer_checked_with_hook<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, TransferChecked<'info>>,
+    amount: u64,
+    decimals: u8,
+) -> Result<()> {
+    spl_token_2022::onchain::invoke_transfer_checked(
+        ctx.program.key,
+        ctx.accounts.from,
+        ctx.accounts.mint,
+        ctx.accounts.to,
+        ctx.accounts.authority,
+        &ctx.remaining_accounts,
+        amount,
+        decimals,
+        ctx.signer_seeds,
+    )
+    .map_err(Into::into)
+}
+
+/// Program id set in the mint's TransferHook extension, if any.
+pub(crate) fn transfer_hook_program_id(mint: &AccountInfo) -> Result<Option<Pubkey>> {
+    let data = mint.try_borrow_data()?;
+    let mint_state = StateWithExtensions::<MintState>::unpack(&data)?;
+    Ok(transfer_hook::get_program_id(&mint_state))
+}
@@ -1,7 +1,7 @@
 import * as anchor from "@coral-xyz/anchor";
 import { Program, BN } from "@coral-xyz/anchor";
 import { SpinWheel } from "../target/types/spin_wheel";
-import { TestState, expectError } from "./state";
+import { TestState, expectError, DEFAULT_MINT_CONFIG } from "./state";
 import { Keypair, PublicKey, SystemProgram, LAMPORTS_PER_SOL } from "@solana/web3.js";
 import {
     TOKEN_2022_PROGRAM_ID,
@@ -47,7 +47,7 @@ describe("Spin Wheel - Error Path Tests", () => {
 
             await expectError(
                 program.methods
-                    .initializeToken2022(invalidFeeBasisPoints, validMaximumFee)
+                    .initializeToken2022(invalidFeeBasisPoints, validMaximumFee, "Cashino", "CASHINO", "", [], DEFAULT_MINT_CONFIG)
                     .accounts({
                         payer: testState.wallet.publicKey,
                         mintAccount: mintKeypairForTest.publicKey,
@@ -72,7 +72,7 @@ describe("Spin Wheel - Error Path Tests", () => {
 
             await expectError(
                 program.methods
-                    .initializeToken2022(validFeeBasisPoints, invalidMaximumFee)
+                    .initializeToken2022(validFeeBasisPoints, invalidMaximumFee, "Cashino", "CASHINO", "", [], DEFAULT_MINT_CONFIG)
                     .accounts({
                         payer: testState.wallet.publicKey,
                         mintAccount: mintKeypairForTest.publicKey,
@@ -101,7 +101,7 @@ describe("Spin Wheel - Error Path Tests", () => {
 
             await expectError(
                 program.methods
-                    .initializeToken2022(validFeeBasisPoints, validMaximumFee)
+                    .initializeToken2022(validFeeBasisPoints, validMaximumFee, "Cashino", "CASHINO", "", [], DEFAULT_MINT_CONFIG)
                     .accounts({
                         payer: testState.wallet.publicKey,
                         mintAccount: mintKeypairForTest.publicKey,
@@ -124,7 +124,7 @@ describe("Spin Wheel - Error Path Tests", () => {
 
             await expectError(
                 program.methods
-                    .initializeToken2022(validFeeBasisPoints, validMaximumFee)
+                    .initializeToken2022(validFeeBasisPoints, validMaximumFee, "Cashino", "CASHINO", "", [], DEFAULT_MINT_CONFIG)
                     .accounts({
                         payer: testState.wallet.publicKey,
                         mintAccount: mintKeypairForTest.publicKey,
@@ -149,7 +149,7 @@ describe("Spin Wheel - Error Path Tests", () => {
 
             await expectError(
                 program.methods
-                    .initializeToken2022(validFeeBasisPoints, validMaximumFee)
+                    .initializeToken2022(validFeeBasisPoints, validMaximumFee, "Cashino", "CASHINO", "", [], DEFAULT_MINT_CONFIG)
                     .accounts({
                         payer: testState.wallet.publicKey,
                         mintAccount: mintKeypairForTest.publicKey,
@@ -0,0 +1,145 @@
+import * as anchor from "@coral-xyz/anchor";
+import { BN } from "bn.js";
+import { assert } from "chai";
+import { TestState, expectError } from "./state";
+
+// Runs after the game setup suite, which initializes the platform config.
+describe("Spin Wheel - escrow maintenance", () => {
+    let testState: TestState;
+
+    const DEPOSIT = new BN(anchor.web3.LAMPORTS_PER_SOL / 2);
+    const DIRECT_TRANSFER = 25_000_000;
+
+    const user = anchor.web3.Keypair.generate();
+    const outsider = anchor.web3.Keypair.generate();
+    let userEscrowPda: anchor.web3.PublicKey;
+
+    before(async () => {
+        testState = new TestState({ useGloballySharedMint: true });
+        [userEscrowPda] = anchor.web3.PublicKey.findProgramAddressSync(
+            [Buffer.from("user_escrow"), user.publicKey.toBuffer()],
+            testState.program.programId
+        );
+
+        for (const wallet of [user, outsider]) {
+            const signature = await testState.connection.requestAirdrop(wallet.publicKey, 2 * anchor.web3.LAMPORTS_PER_SOL);
+            await testState.confirmTx(signature);
+        }
+        await testState.program.methods
+            .depositSol(DEPOSIT)
+            .accounts({
+                user: user.publicKey,
+                userPlatformEscrow: userEscrowPda,
+                systemProgram: anchor.web3.SystemProgram.programId,
+            })
+            .signers([user])
+            .rpc({ commitment: "confirmed" });
+    });
+
+    it("Leaves a fully backed escrow unchanged", async () => {
+        await testState.program.methods
+            .reconcileEscrow(true)
+            .accounts({ caller: user.publicKey, userPlatformEscrow: userEscrowPda })
+            .signers([user])
+            .rpc({ commitment: "confirmed" });
+
+        const escrow = await testState.program.account.userPlatformEscrow.fetch(userEscrowPda);
+        assert.strictEqual(escrow.balance.toString(), DEPOSIT.toString(), "Balance should still match the deposit");
+    });
+
+    it("Lets anyone report SOL sent straight to the escrow, but only the owner credit it", async () => {
+        const transferTx = new anchor.web3.Transaction().add(
+            anchor.web3.SystemProgram.transfer({
+                fromPubkey: outsider.publicKey,
+                toPubkey: userEscrowPda,
+                lamports: DIRECT_TRANSFER,
+            })
+        );
+        await testState.provider.sendAndConfirm(transferTx, [outsider], { commitment: "confirmed" });
+
+        // Reporting is permissionless and doesn't touch the balance
+        await testState.program.methods
+            .reconcileEscrow(false)
+            .accounts({ caller: outsider.publicKey, userPlatformEscrow: userEscrowPda })
+            .signers([outsider])
+            .rpc({ commitment: "confirmed" });
+        await expectError(
+            testState.program.methods
+                .reconcileEscrow(true)
+                .accounts({ caller: outsider.publicKey, userPlatformEscrow: userEscrowPda })
+                .signers([outsider])
+                .rpc(),
+            "UnauthorizedEscrowAccess"
+        );
+        let escrow = await testState.program.account.userPlatformEscrow.fetch(userEscrowPda);
+        assert.strictEqual(escrow.balance.toString(), DEPOSIT.toString(), "Only the owner can credit the surplus");
+
+        await testState.program.methods
+            .reconcileEscrow(true)
+            .accounts({ caller: user.publicKey, userPlatformEscrow: userEscrowPda })
+            .signers([user])
+            .rpc({ commitment: "confirmed" });
+
+        escrow = await testState.program.account.userPlatformEscrow.fetch(userEscrowPda);
+        assert.strictEqual(
+            escrow.balance.toString(),
+            DEPOSIT.addn(DIRECT_TRANSFER).toString(),
+            "The surplus should be credited to the balance"
+        );
+        const escrowInfo = await testState.connection.getAccountInfo(userEscrowPda);
+        const rentMinimum = await testState.connection.getMinimumBalanceForRentExemption(escrowInfo!.data.length);
+        assert.strictEqual(escrowInfo!.lamports, rentMinimum + escrow.balance.toNumber(), "Every lamport above rent should be backed by balance");
+    });
+
+    it("Refuses to migrate accounts already on the current layout", async () => {
+        const escrowBefore = await testState.connection.getAccountInfo(userEscrowPda);
+        const escrow = await testState.program.account.userPlatformEscrow.fetch(userEscrowPda);
+        assert.strictEqual(escrow.version, 1, "New escrows should be created on the current layout");
+        await expectError(
+            testState.program.methods
+                .migrateUserEscrow()
+                .accounts({
+                    user: user.publicKey,
+                    userPlatformEscrow: userEscrowPda,
+                    systemProgram: anchor.web3.SystemProgram.programId,
+                })
+                .signers([user])
+                .rpc(),
+            "AccountAlreadyMigrated"
+        );
+
+        const gameState = await testState.program.account.gameState.fetch(testState.gameStatePda);
+        assert.strictEqual(gameState.version, 4, "New tables should be created on the current layout");
+        await expectError(
+            testState.program.methods
+                .migrateGameState(testState.TABLE_ID)
+                .accounts({
+                    authority: testState.wallet.publicKey,
+                    platformConfig: testState.platformConfigPda,
+                    gameState: testState.gameStatePda,
+                    systemProgram: anchor.web3.SystemProgram.programId,
+                })
+                .rpc(),
+            "AccountAlreadyMigrated"
+        );
+
+        const escrowAfter = await testState.connection.getAccountInfo(userEscrowPda);
+        assert.isTrue(escrowAfter!.data.equals(escrowBefore!.data), "A refused migration should leave the escrow untouched");
+    });
+
+    it("Only lets the platform authority migrate a table", async () => {
+        await expectError(
+            testState.program.methods
+                .migrateGameState(testState.TABLE_ID)
+                .accounts({
+                    authority: outsider.publicKey,
+                    platformConfig: testState.platformConfigPda,
+                    gameState: testState.gameStatePda,
+                    systemProgram: anchor.web3.SystemProgram.programId,
+                })
+                .signers([outsider])
+                .rpc(),
+            "UnauthorizedAccess"
+        );
+    });
+});
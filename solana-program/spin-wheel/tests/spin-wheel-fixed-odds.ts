@@ -0,0 +1,206 @@
+import * as anchor from "@coral-xyz/anchor";
+import { BN } from "bn.js";
+import { TOKEN_2022_PROGRAM_ID } from "@solana/spl-token";
+import { assert } from "chai";
+import { TestState } from "./state";
+
+// Runs after the game setup suite, which initializes the platform config.
+describe("Spin Wheel - fixed-odds settlement against the bankroll", () => {
+    let testState: TestState;
+
+    const ROUND_DURATION_SECONDS = 5;
+    const BET = new BN(100_000_000);
+    const DEPOSIT = new BN(anchor.web3.LAMPORTS_PER_SOL / 2);
+    const LP_WITHDRAWAL_DELAY_SECONDS = new BN(600);
+    // A 1.5x and a 0.5x segment with equal weight. Betting on the 0.5x segment never needs the
+    // bankroll to cover more than the stake, so the round can run against an unfunded bankroll.
+    const WHEEL = [
+        { multiplierBasisPoints: 15_000, weight: 1, paddingWs: [0, 0] },
+        { multiplierBasisPoints: 5_000, weight: 1, paddingWs: [0, 0] },
+    ];
+    const BET_SEGMENT = 1;
+
+    const player = anchor.web3.Keypair.generate();
+
+    let tableId: anchor.BN;
+    let gameStatePda: anchor.web3.PublicKey;
+    let houseBankrollPda: anchor.web3.PublicKey;
+    let roundIdForSeed: anchor.BN;
+    let roundStatePda: anchor.web3.PublicKey;
+    let gamePotPda: anchor.web3.PublicKey;
+
+    const pda = (...seeds: Buffer[]) =>
+        anchor.web3.PublicKey.findProgramAddressSync(seeds, testState.program.programId)[0];
+    const escrowPda = (user: anchor.web3.PublicKey) => pda(Buffer.from("user_escrow"), user.toBuffer());
+
+    before(async () => {
+        testState = new TestState({ useGloballySharedMint: true });
+
+        const signature = await testState.connection.requestAirdrop(player.publicKey, 2 * anchor.web3.LAMPORTS_PER_SOL);
+        await testState.confirmTx(signature);
+        await testState.program.methods
+            .depositSol(DEPOSIT)
+            .accounts({
+                user: player.publicKey,
+                userPlatformEscrow: escrowPda(player.publicKey),
+                systemProgram: anchor.web3.SystemProgram.programId,
+            })
+            .signers([player])
+            .rpc({ commitment: "confirmed" });
+
+        houseBankrollPda = pda(Buffer.from("house_bankroll"));
+        const houseBankroll = await testState.program.account.houseBankroll.fetchNullable(houseBankrollPda);
+        if (!houseBankroll) {
+            await testState.program.methods
+                .initializeHouseBankroll(LP_WITHDRAWAL_DELAY_SECONDS)
+                .accounts({
+                    authority: testState.wallet.publicKey,
+                    platformConfig: testState.platformConfigPda,
+                    houseBankroll: houseBankrollPda,
+                    mintAuthorityPda: testState.mintAuthorityPda,
+                    lpMint: pda(Buffer.from("house_lp_mint")),
+                    tokenProgram: TOKEN_2022_PROGRAM_ID,
+                    systemProgram: anchor.web3.SystemProgram.programId,
+                })
+                .rpc({ commitment: "confirmed" });
+        }
+
+        const platformConfig = await testState.program.account.platformConfig.fetch(testState.platformConfigPda);
+        tableId = platformConfig.tableCount;
+        gameStatePda = pda(Buffer.from("game_state"), tableId.toBuffer("le", 8));
+    });
+
+    it("Creates a fixed-odds table and starts a round with its wheel", async () => {
+        await testState.program.methods
+            .createTable(tableId, 0, new BN(10_000_000), new BN(10_000_000_000), new BN(ROUND_DURATION_SECONDS))
+            .accounts({
+                authority: testState.wallet.publicKey,
+                platformConfig: testState.platformConfigPda,
+                gameState: gameStatePda,
+                systemProgram: anchor.web3.SystemProgram.programId,
+            })
+            .rpc({ commitment: "confirmed" });
+        await testState.program.methods
+            .configureFixedOddsWheel(tableId, WHEEL)
+            .accounts({
+                authority: testState.wallet.publicKey,
+                platformConfig: testState.platformConfigPda,
+                gameState: gameStatePda,
+            })
+            .rpc({ commitment: "confirmed" });
+
+        const gameState = await testState.program.account.gameState.fetch(gameStatePda);
+        roundIdForSeed = gameState.roundCounter;
+        roundStatePda = pda(Buffer.from("round_state"), tableId.toBuffer("le", 8), roundIdForSeed.toBuffer("le", 8));
+        gamePotPda = pda(Buffer.from("sol_pot"), tableId.toBuffer("le", 8), roundIdForSeed.toBuffer("le", 8));
+
+        await testState.program.methods
+            .startNewRound(tableId, testState.seedCommitmentBuffer, roundIdForSeed)
+            .accounts({
+                authority: testState.wallet.publicKey,
+                gameState: gameStatePda,
+                roundState: roundStatePda,
+                gamePot: gamePotPda,
+                systemProgram: anchor.web3.SystemProgram.programId,
+            })
+            .rpc({ commitment: "confirmed" });
+
+        const roundState = await testState.program.account.roundState.fetch(roundStatePda);
+        assert.strictEqual(roundState.wheelSegmentCount, WHEEL.length, "The round should snapshot the table's wheel");
+    });
+
+    it("Places a bet on a segment without reserving bankroll it doesn't need", async () => {
+        const bankrollBefore = await testState.program.account.houseBankroll.fetch(houseBankrollPda);
+
+        await testState.program.methods
+            .placeFixedOddsBet(tableId, roundIdForSeed, BET_SEGMENT, BET)
+            .accounts({
+                player: player.publicKey,
+                userPlatformEscrow: escrowPda(player.publicKey),
+                gameState: gameStatePda,
+                roundState: roundStatePda,
+                gamePot: gamePotPda,
+                houseBankroll: houseBankrollPda,
+                systemProgram: anchor.web3.SystemProgram.programId,
+            })
+            .signers([player])
+            .rpc({ commitment: "confirmed" });
+
+        const roundState = await testState.program.account.roundState.fetch(roundStatePda);
+        assert.strictEqual(roundState.totalSolPot.toString(), BET.toString(), "The pot should hold the stake");
+        assert.strictEqual(roundState.bankrollReserved.toNumber(), 0, "A 0.5x bet is covered by its own stake");
+        const bankrollAfter = await testState.program.account.houseBankroll.fetch(houseBankrollPda);
+        assert.strictEqual(
+            bankrollAfter.reservedExposure.toString(),
+            bankrollBefore.reservedExposure.toString(),
+            "Nothing should be reserved on the bankroll"
+        );
+
+        console.log(`Waiting ${ROUND_DURATION_SECONDS + 1}s for the round to end...`);
+        await new Promise(resolve => setTimeout(resolve, (ROUND_DURATION_SECONDS + 1) * 1000));
+    });
+
+    it("Reveals the seed and settles the stake between the winner and the bankroll", async () => {
+        await testState.program.methods
+            .revealFixedOddsSeed(tableId, testState.revealedSeedBuffer, roundIdForSeed)
+            .accounts({
+                authority: testState.wallet.publicKey,
+                gameState: gameStatePda,
+                roundState: roundStatePda,
+            })
+            .rpc({ commitment: "confirmed" });
+
+        // The spin uses the hash of the slot the seed was revealed in, which exists once that slot is done
+        await new Promise(resolve => setTimeout(resolve, 2_000));
+
+        const bankrollBefore = await testState.program.account.houseBankroll.fetch(houseBankrollPda);
+        const bankrollLamportsBefore = await testState.connection.getBalance(houseBankrollPda);
+        const potLamportsBefore = await testState.connection.getBalance(gamePotPda);
+
+        await testState.program.methods
+            .settleFixedOddsRound(tableId, roundIdForSeed)
+            .accounts({
+                gameState: gameStatePda,
+                roundState: roundStatePda,
+                gamePotSol: gamePotPda,
+                houseBankroll: houseBankrollPda,
+                slotHashes: anchor.web3.SYSVAR_SLOT_HASHES_PUBKEY,
+                systemProgram: anchor.web3.SystemProgram.programId,
+            })
+            .rpc({ commitment: "confirmed" });
+
+        const roundState = await testState.program.account.roundState.fetch(roundStatePda);
+        assert.strictEqual(roundState.hasWinningSegmentVal, 1, "The wheel should have been spun");
+        assert.strictEqual(roundState.houseSolFee.toNumber(), 0, "Fixed-odds rounds take no house fee");
+
+        // Segment 0 has no bets, so the bankroll keeps the whole stake; on segment 1 the player wins half of it back
+        const payout = roundState.winningSegmentVal === BET_SEGMENT ? BET.muln(5_000).divn(10_000) : new BN(0);
+        if (roundState.winningSegmentVal === BET_SEGMENT) {
+            assert.strictEqual(roundState.statusDiscriminant, 1, "The winner should be able to claim");
+            assert.isTrue(roundState.winnerSolPubkey.equals(player.publicKey), "The bettor should be the winner");
+        } else {
+            assert.strictEqual(roundState.statusDiscriminant, 2, "A round nobody wins is closed");
+            assert.strictEqual(roundState.winnerCount, 0, "Nobody bet on the winning segment");
+        }
+        assert.strictEqual(roundState.winnerSolAmount.toString(), payout.toString(), "Payout should be the stake at the segment's multiplier");
+
+        const kept = BET.sub(payout);
+        const bankrollAfter = await testState.program.account.houseBankroll.fetch(houseBankrollPda);
+        assert.strictEqual(
+            bankrollAfter.balance.sub(bankrollBefore.balance).toString(),
+            kept.toString(),
+            "The bankroll should book the stake it did not pay out"
+        );
+        assert.strictEqual(
+            (await testState.connection.getBalance(houseBankrollPda)) - bankrollLamportsBefore,
+            kept.toNumber(),
+            "The kept stake should move to the bankroll"
+        );
+        assert.strictEqual(
+            potLamportsBefore - (await testState.connection.getBalance(gamePotPda)),
+            kept.toNumber(),
+            "The pot should keep only the payout"
+        );
+        assert.strictEqual(roundState.bankrollReserved.toNumber(), 0, "The round's reserve should be released");
+    });
+});
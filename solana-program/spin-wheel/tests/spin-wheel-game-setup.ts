@@ -36,32 +36,46 @@ describe('Spin Wheel Game Setup', () => {
         console.log("Airdrop to House Wallet confirmed.");
     });
 
-    it("Initializes Game Settings", async () => {
+    it("Uses the platform config set up with the shared mint", async () => {
+        // The token suite initializes the platform before minting through the program.
+        const platformConfigAccount = await testState.program.account.platformConfig.fetch(testState.platformConfigPda);
+        assert.isTrue(platformConfigAccount.authority.equals(testState.wallet.publicKey), "PlatformConfig authority mismatch");
+        assert.isTrue(platformConfigAccount.houseWallet.equals(testState.houseWalletKeypair.publicKey), "PlatformConfig houseWallet mismatch");
+        assert.isTrue(platformConfigAccount.cashinoMint.equals(testState.cashinoMintPublicKey), "PlatformConfig cashinoMint mismatch with shared mint");
+        assert.strictEqual(platformConfigAccount.tableCount.toNumber(), 0, "PlatformConfig tableCount should be 0");
+        assert.strictEqual(platformConfigAccount.isInitialized, true, "PlatformConfig should be initialized");
+    });
+
+    it("Creates a game table", async () => {
         const initialHouseFeeBasisPoints = 10;
+        const minBetAmount = new BN(10_000_000);
+        const maxBetAmount = new BN(10_000_000_000);
+        const roundDuration = new BN(10);
 
-        console.log(`Test: Initializing GameState at PDA: ${testState.gameStatePda.toBase58()}`);
-        console.log(`Test: Authority (Payer): ${testState.wallet.publicKey.toBase58()}`);
-        console.log(`Test: House Wallet to be set: ${testState.houseWalletKeypair.publicKey.toBase58()}`);
+        console.log(`Test: Creating table ${testState.TABLE_ID.toString()} at GameState PDA: ${testState.gameStatePda.toBase58()}`);
         console.log(`Test: House Fee Basis Points to set: ${initialHouseFeeBasisPoints.toString()}`);
-        console.log(`Test: $CASHINO Mint to set: ${testState.cashinoMintPublicKey.toBase58()}`);
 
         const transactionSignature = await testState.program.methods
-            .initializeGameSettings(initialHouseFeeBasisPoints)
+            .createTable(
+                testState.TABLE_ID,
+                initialHouseFeeBasisPoints,
+                minBetAmount,
+                maxBetAmount,
+                roundDuration
+            )
             .accounts({
                 authority: testState.wallet.publicKey,
+                platformConfig: testState.platformConfigPda,
                 gameState: testState.gameStatePda,
-                houseWallet: testState.houseWalletKeypair.publicKey,
-                cashinoTokenMint: testState.cashinoMintPublicKey,
-                token2022Program: TOKEN_2022_PROGRAM_ID,
                 systemProgram: anchor.web3.SystemProgram.programId,
             })
             .rpc({ skipPreflight: true, commitment: "confirmed" });
 
         if (!transactionSignature) {
-            throw new Error("initializeGameSettings RPC returned undefined transaction signature.");
+            throw new Error("createTable RPC returned undefined transaction signature.");
         }
         await testState.confirmTx(transactionSignature);
-        console.log("Transaction for initializeGameSettings confirmed by client.");
+        console.log("Transaction for createTable confirmed by client.");
 
         const gameStateAccount = await testState.program.account.gameState.fetch(testState.gameStatePda);
         console.log("Fetched GameState Account:", {
@@ -71,6 +85,7 @@ describe('Spin Wheel Game Setup', () => {
             roundCounter: gameStateAccount.roundCounter.toString(),
             isInitialized: gameStateAccount.isInitialized,
             cashinoMint: gameStateAccount.cashinoMint.toBase58(),
+            tableId: gameStateAccount.tableId.toString(),
         });
 
         assert.isTrue(gameStateAccount.authority.equals(testState.wallet.publicKey), "GameState authority mismatch");
@@ -79,6 +94,14 @@ describe('Spin Wheel Game Setup', () => {
         assert.isTrue(gameStateAccount.cashinoMint.equals(testState.cashinoMintPublicKey), "GameState cashinoMint mismatch with shared mint");
         assert.strictEqual(gameStateAccount.isInitialized, true, "GameState should be initialized");
         assert.strictEqual(gameStateAccount.roundCounter.toNumber(), 0, "GameState roundCounter should be 0");
+        assert.strictEqual(gameStateAccount.tableId.toString(), testState.TABLE_ID.toString(), "GameState tableId mismatch");
+        assert.strictEqual(gameStateAccount.minBetAmount.toString(), minBetAmount.toString(), "GameState minBetAmount mismatch");
+        assert.strictEqual(gameStateAccount.maxBetAmount.toString(), maxBetAmount.toString(), "GameState maxBetAmount mismatch");
+        assert.strictEqual(gameStateAccount.roundDuration.toNumber(), roundDuration.toNumber(), "GameState roundDuration mismatch");
+        assert.strictEqual(gameStateAccount.isRetired, false, "GameState should not be retired");
+
+        const platformConfigAccount = await testState.program.account.platformConfig.fetch(testState.platformConfigPda);
+        assert.strictEqual(platformConfigAccount.tableCount.toNumber(), 1, "PlatformConfig tableCount should be incremented");
 
         console.log("GameState initialized and verified successfully.");
     });
@@ -211,15 +234,12 @@ describe('Spin Wheel Game Setup', () => {
         console.log(`Test: Derived RoundState PDA for new round: ${testState.roundStatePda!.toBase58()}`);
         console.log(`Test: Derived GamePotSol PDA for new round: ${testState.gamePotSolPda!.toBase58()}`);
 
-        const roundDuration = new BN(10);
-
-        console.log(`Test: Calling startNewRound with roundIdForSeed: ${testState.currentRoundIdForSeed.toString()}`);
-        console.log(`Test: Round Duration: ${roundDuration.toString()}`);
+        console.log(`Test: Calling startNewRound on table ${testState.TABLE_ID.toString()} with roundIdForSeed: ${testState.currentRoundIdForSeed.toString()}`);
 
         const transactionSignature = await testState.program.methods
             .startNewRound(
+                testState.TABLE_ID,
                 testState.seedCommitmentBuffer,
-                roundDuration,
                 testState.currentRoundIdForSeed
             )
             .accounts({
@@ -302,13 +322,14 @@ describe('Spin Wheel Game Setup', () => {
         const initialPlayerCount = roundStateBeforeP1Bet.playerCount;
 
         const tx1Signature = await testState.program.methods
-            .placeSolBet(testState.currentRoundIdForSeed, betAmountPlayer1Lamports)
+            .placeSolBet(testState.TABLE_ID, testState.currentRoundIdForSeed, betAmountPlayer1Lamports)
             .accounts({
                 player: player1.publicKey,
                 userPlatformEscrow: testState.userPlatformEscrowPda!,
                 gameState: testState.gameStatePda,
                 roundState: testState.roundStatePda!,
                 gamePot: testState.gamePotSolPda!,
+                leaderboard: null,
                 systemProgram: anchor.web3.SystemProgram.programId,
             })
             .signers([player1.payer])
@@ -365,13 +386,14 @@ describe('Spin Wheel Game Setup', () => {
 
 
         const tx2Signature = await testState.program.methods
-            .placeSolBet(testState.currentRoundIdForSeed, betAmountPlayer2Lamports)
+            .placeSolBet(testState.TABLE_ID, testState.currentRoundIdForSeed, betAmountPlayer2Lamports)
             .accounts({
                 player: player2.publicKey,
                 userPlatformEscrow: testState.player2PlatformEscrowPda!,
                 gameState: testState.gameStatePda,
                 roundState: testState.roundStatePda!,
                 gamePot: testState.gamePotSolPda!,
+                leaderboard: null,
                 systemProgram: anchor.web3.SystemProgram.programId,
             })
             .signers([player2])
@@ -427,13 +449,14 @@ describe('Spin Wheel Game Setup', () => {
         const player1DataIndex = roundStateBeforeP1Add.players.findIndex(p => p.pubkey.equals(player1.publicKey));
 
         const tx3Signature = await testState.program.methods
-            .placeSolBet(testState.currentRoundIdForSeed, additionalBetP1Lamports)
+            .placeSolBet(testState.TABLE_ID, testState.currentRoundIdForSeed, additionalBetP1Lamports)
             .accounts({
                 player: player1.publicKey,
                 userPlatformEscrow: testState.userPlatformEscrowPda!,
                 gameState: testState.gameStatePda,
                 roundState: testState.roundStatePda!,
                 gamePot: testState.gamePotSolPda!,
+                leaderboard: null,
                 systemProgram: anchor.web3.SystemProgram.programId,
             })
             .signers([player1.payer])
@@ -504,18 +527,39 @@ describe('Spin Wheel Game Setup', () => {
             initialHouseBal,
         });
 
-        const revealBuf = testState.seedCommitmentBuffer;
+        const revealBuf = testState.revealedSeedBuffer;
+
+        // No fee split is set, so the remaining accounts start with every bettor's PlayerStats in round order
+        const playerStatsAccounts = roundStateBeforeFinalize.players
+            .slice(0, roundStateBeforeFinalize.playerCount)
+            .map((player: { pubkey: anchor.web3.PublicKey }) => ({
+                pubkey: anchor.web3.PublicKey.findProgramAddressSync(
+                    [Buffer.from("player_stats"), player.pubkey.toBuffer()],
+                    testState.program.programId
+                )[0],
+                isSigner: false,
+                isWritable: true,
+            }));
 
         const ix = await testState.program.methods
-            .finalizeRound(revealBuf, testState.currentRoundIdForSeed!)
+            .finalizeRound(testState.TABLE_ID, revealBuf, testState.currentRoundIdForSeed!)
             .accounts({
                 authority: testState.wallet.publicKey,
                 gameState: testState.gameStatePda,
+                platformConfig: testState.platformConfigPda,
                 roundState: testState.roundStatePda!,
                 gamePotSol: testState.gamePotSolPda!,
                 houseWallet: testState.houseWalletKeypair.publicKey,
+                // No staking pool is initialized in this suite, so the full fee goes to the house wallet
+                stakingPool: anchor.web3.PublicKey.findProgramAddressSync([Buffer.from("staking_pool")], testState.program.programId)[0],
+                // Never initialized in this suite, so no contribution is taken
+                jackpotPool: anchor.web3.PublicKey.findProgramAddressSync([Buffer.from("jackpot")], testState.program.programId)[0],
+                // Never initialized in this suite, so the round isn't recorded
+                roundHistory: anchor.web3.PublicKey.findProgramAddressSync([Buffer.from("round_history"), testState.TABLE_ID.toBuffer("le", 8)], testState.program.programId)[0],
+                leaderboard: null,
                 systemProgram: anchor.web3.SystemProgram.programId,
             })
+            .remainingAccounts(playerStatsAccounts)
             .instruction();
 
         const tx = new anchor.web3.Transaction()
@@ -662,9 +706,10 @@ describe('Spin Wheel Game Setup', () => {
         });
 
         const claimTx = await testState.program.methods
-            .claimSolWinnings(testState.currentRoundIdForSeed!)
+            .claimSolWinnings(testState.TABLE_ID, testState.currentRoundIdForSeed!)
             .accounts({
                 winnerSigner: winnerPubkey, // The winner's actual pubkey
+                gameState: testState.gameStatePda,
                 userPlatformEscrow: winnerEscrowPda,
                 roundState: testState.roundStatePda!,
                 gamePotSol: testState.gamePotSolPda!,
@@ -743,8 +788,10 @@ describe('Spin Wheel Game Setup', () => {
             .accounts({
                 user: userToWithdraw.publicKey,
                 userPlatformEscrow: userEscrowPda,
-                gameState: testState.gameStatePda,
+                platformConfig: testState.platformConfigPda,
                 houseWallet: testState.houseWalletKeypair.publicKey,
+                cashinoTokenAccount: null,
+                stakerPosition: null,
                 systemProgram: anchor.web3.SystemProgram.programId,
             })
             .signers([userToWithdraw.payer])
@@ -765,7 +812,8 @@ describe('Spin Wheel Game Setup', () => {
             houseWallet: finalHouseWalletBalance,
         });
 
-        const withdrawalFee = testState.WITHDRAWAL_FEE_LAMPORTS;
+        // The platform starts on the default schedule: a flat minimum fee, no basis points
+        const withdrawalFee = testState.DEFAULT_WITHDRAWAL_MIN_FEE_LAMPORTS;
         const totalDebitFromEscrow = amountToWithdraw.add(withdrawalFee);
 
         // User's wallet balance should increase by withdrawAmount (approx, due to tx fees)
@@ -788,7 +836,7 @@ describe('Spin Wheel Game Setup', () => {
             "Escrow account data balance incorrect after withdrawal"
         );
 
-        // House wallet should receive exactly the WITHDRAWAL_FEE_LAMPORTS
+        // House wallet should receive exactly the default minimum withdrawal fee
         assert.strictEqual(
             finalHouseWalletBalance,
             initialHouseWalletBalance + withdrawalFee.toNumber(),
@@ -825,7 +873,7 @@ describe('Spin Wheel Game Setup', () => {
         let txSignature;
         try {
             txSignature = await testState.program.methods
-                .createRewardPotAccounts(testState.currentRoundIdForSeed!)
+                .createRewardPotAccounts(testState.TABLE_ID, testState.currentRoundIdForSeed!)
                 .accounts({
                     authority: testState.wallet.publicKey,
                     gameState: testState.gameStatePda,
@@ -916,10 +964,11 @@ describe('Spin Wheel Game Setup', () => {
         console.log("Pot ATA balance before mint:", before.amount.toString());
 
         const sig = await testState.program.methods
-            .mintTokensToRewardPot(testState.currentRoundIdForSeed!)
+            .mintTokensToRewardPot(testState.TABLE_ID, testState.currentRoundIdForSeed!)
             .accounts({
                 authority: testState.wallet.publicKey,
                 gameState: testState.gameStatePda,
+                platformConfig: testState.platformConfigPda,
                 roundState: testState.roundStatePda!,
                 cashinoTokenMint: testState.cashinoMintPublicKey,
                 cashinoMintAuthorityPda: testState.mintAuthorityPda,
@@ -988,7 +1037,7 @@ describe('Spin Wheel Game Setup', () => {
         assert.strictEqual(roundStateBefore.statusDiscriminant, 4, "Round status should be TokensMintedForRewards (4) before calculating entitlements"); // TokensMintedForRewards
 
         const tx = await testState.program.methods
-            .calculateRewardEntitlements(testState.currentRoundIdForSeed!)
+            .calculateRewardEntitlements(testState.TABLE_ID, testState.currentRoundIdForSeed!)
             .accounts({
                 gameState: testState.gameStatePda,
                 roundState: testState.roundStatePda!
@@ -1091,7 +1140,7 @@ describe('Spin Wheel Game Setup', () => {
         console.log(`Expected reward amount for player: ${rewardAmount.toString()}`);
         console.log("RECHECK ROUND CASHINO REWARDS POT ATA: ", testState.roundCashinoRewardsPotAta.toBase58());
         const tx = await testState.program.methods
-            .claimCashinoRewards(roundId)
+            .claimCashinoRewards(testState.TABLE_ID, roundId)
             .accounts({
                 player: playerToClaim,
                 gameState: testState.gameStatePda,
@@ -1100,6 +1149,10 @@ describe('Spin Wheel Game Setup', () => {
                 roundCashinoRewardsPotAta: testState.roundCashinoRewardsPotAta,
                 cashinoTokenMint: testState.cashinoMintPublicKey,
                 playerCashinoAta: playerCashinoAta,
+                mintAuthorityPda: null,
+                hookConfig: null,
+                walletActivity: null,
+                transferHookProgram: null,
                 tokenProgram: TOKEN_2022_PROGRAM_ID,
                 associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
                 systemProgram: anchor.web3.SystemProgram.programId,
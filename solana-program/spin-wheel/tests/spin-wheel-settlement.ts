@@ -0,0 +1,341 @@
+import * as anchor from "@coral-xyz/anchor";
+import { BN } from "bn.js";
+import { assert } from "chai";
+import { TestState } from "./state";
+
+// Runs after the game setup suite, which initializes the platform config. Every split set up
+// here is cleared again once the suite is done.
+describe("Spin Wheel - house fee settlement", () => {
+    let testState: TestState;
+
+    const HOUSE_FEE_BASIS_POINTS = 500;
+    const ROUND_DURATION_SECONDS = 5;
+    const BET = new BN(100_000_000);
+    const DEPOSIT = new BN(anchor.web3.LAMPORTS_PER_SOL / 2);
+    const REFERRAL_SHARE_BASIS_POINTS = 2_000;
+    const JACKPOT_CONTRIBUTION_BASIS_POINTS = 1_000;
+
+    // Player one registers a referrer; player two never does
+    const playerOne = anchor.web3.Keypair.generate();
+    const playerTwo = anchor.web3.Keypair.generate();
+    const referrer = anchor.web3.Keypair.generate();
+    const feeRecipients = [anchor.web3.Keypair.generate(), anchor.web3.Keypair.generate()];
+
+    let tableId: anchor.BN;
+    let gameStatePda: anchor.web3.PublicKey;
+    let houseWallet: anchor.web3.PublicKey;
+
+    type Round = {
+        id: anchor.BN;
+        roundStatePda: anchor.web3.PublicKey;
+        gamePotPda: anchor.web3.PublicKey;
+    };
+
+    const pda = (...seeds: Buffer[]) =>
+        anchor.web3.PublicKey.findProgramAddressSync(seeds, testState.program.programId)[0];
+    const escrowPda = (user: anchor.web3.PublicKey) => pda(Buffer.from("user_escrow"), user.toBuffer());
+    const referralPda = (user: anchor.web3.PublicKey) => pda(Buffer.from("referral"), user.toBuffer());
+    const referrerStatsPda = (user: anchor.web3.PublicKey) => pda(Buffer.from("referrer_stats"), user.toBuffer());
+
+    const sleep = (seconds: number) => new Promise(resolve => setTimeout(resolve, seconds * 1000));
+
+    async function startRound(): Promise<Round> {
+        const gameState = await testState.program.account.gameState.fetch(gameStatePda);
+        const id = gameState.roundCounter;
+        const round = {
+            id,
+            roundStatePda: pda(Buffer.from("round_state"), tableId.toBuffer("le", 8), id.toBuffer("le", 8)),
+            gamePotPda: pda(Buffer.from("sol_pot"), tableId.toBuffer("le", 8), id.toBuffer("le", 8)),
+        };
+        await testState.program.methods
+            .startNewRound(tableId, testState.seedCommitmentBuffer, id)
+            .accounts({
+                authority: testState.wallet.publicKey,
+                gameState: gameStatePda,
+                roundState: round.roundStatePda,
+                gamePot: round.gamePotPda,
+                systemProgram: anchor.web3.SystemProgram.programId,
+            })
+            .rpc({ commitment: "confirmed" });
+        return round;
+    }
+
+    async function placeBet(player: anchor.web3.Keypair, round: Round) {
+        await testState.program.methods
+            .placeSolBet(tableId, round.id, BET)
+            .accounts({
+                player: player.publicKey,
+                userPlatformEscrow: escrowPda(player.publicKey),
+                gameState: gameStatePda,
+                roundState: round.roundStatePda,
+                gamePot: round.gamePotPda,
+                leaderboard: null,
+                systemProgram: anchor.web3.SystemProgram.programId,
+            })
+            .signers([player])
+            .rpc({ commitment: "confirmed" });
+    }
+
+    // Remaining accounts in the order finalize_round reads them: the fee split recipients, every
+    // bettor's PlayerStats, then a [ReferralAccount, ReferrerStats] pair per bettor while referrals are on.
+    async function settlementAccounts(round: Round): Promise<anchor.web3.AccountMeta[]> {
+        const platformConfig = await testState.program.account.platformConfig.fetch(testState.platformConfigPda);
+        const roundState = await testState.program.account.roundState.fetch(round.roundStatePda);
+        const players: anchor.web3.PublicKey[] = roundState.players
+            .slice(0, roundState.playerCount)
+            .map((player: { pubkey: anchor.web3.PublicKey }) => player.pubkey);
+
+        const accounts: anchor.web3.AccountMeta[] = platformConfig.feeRecipients
+            .slice(0, platformConfig.feeRecipientCount)
+            .map((recipient: { wallet: anchor.web3.PublicKey }) => ({ pubkey: recipient.wallet, isSigner: false, isWritable: true }));
+        for (const player of players) {
+            accounts.push({ pubkey: pda(Buffer.from("player_stats"), player.toBuffer()), isSigner: false, isWritable: true });
+        }
+        if (platformConfig.referralShareBasisPoints > 0) {
+            for (const player of players) {
+                const referral = await testState.program.account.referralAccount.fetchNullable(referralPda(player));
+                accounts.push({ pubkey: referralPda(player), isSigner: false, isWritable: false });
+                accounts.push(
+                    referral
+                        ? { pubkey: referrerStatsPda(referral.referrer), isSigner: false, isWritable: true }
+                        : { pubkey: anchor.web3.SystemProgram.programId, isSigner: false, isWritable: false }
+                );
+            }
+        }
+        return accounts;
+    }
+
+    async function finalize(round: Round) {
+        const ix = await testState.program.methods
+            .finalizeRound(tableId, testState.revealedSeedBuffer, round.id)
+            .accounts({
+                authority: testState.wallet.publicKey,
+                gameState: gameStatePda,
+                platformConfig: testState.platformConfigPda,
+                roundState: round.roundStatePda,
+                gamePotSol: round.gamePotPda,
+                houseWallet,
+                stakingPool: pda(Buffer.from("staking_pool")),
+                jackpotPool: pda(Buffer.from("jackpot")),
+                roundHistory: pda(Buffer.from("round_history"), tableId.toBuffer("le", 8)),
+                leaderboard: null,
+                systemProgram: anchor.web3.SystemProgram.programId,
+            })
+            .remainingAccounts(await settlementAccounts(round))
+            .instruction();
+        const tx = new anchor.web3.Transaction()
+            .add(anchor.web3.ComputeBudgetProgram.setComputeUnitLimit({ units: 400_000 }))
+            .add(ix);
+        await testState.provider.sendAndConfirm(tx, [], { commitment: "confirmed" });
+    }
+
+    before(async () => {
+        testState = new TestState({ useGloballySharedMint: true });
+
+        for (const user of [playerOne, playerTwo, referrer]) {
+            const signature = await testState.connection.requestAirdrop(user.publicKey, 2 * anchor.web3.LAMPORTS_PER_SOL);
+            await testState.confirmTx(signature);
+            await testState.program.methods
+                .depositSol(DEPOSIT)
+                .accounts({
+                    user: user.publicKey,
+                    userPlatformEscrow: escrowPda(user.publicKey),
+                    systemProgram: anchor.web3.SystemProgram.programId,
+                })
+                .signers([user])
+                .rpc({ commitment: "confirmed" });
+        }
+
+        const platformConfig = await testState.program.account.platformConfig.fetch(testState.platformConfigPda);
+        tableId = platformConfig.tableCount;
+        houseWallet = platformConfig.houseWallet;
+        gameStatePda = pda(Buffer.from("game_state"), tableId.toBuffer("le", 8));
+
+        await testState.program.methods
+            .createTable(tableId, HOUSE_FEE_BASIS_POINTS, new BN(10_000_000), new BN(10_000_000_000), new BN(ROUND_DURATION_SECONDS))
+            .accounts({
+                authority: testState.wallet.publicKey,
+                platformConfig: testState.platformConfigPda,
+                gameState: gameStatePda,
+                systemProgram: anchor.web3.SystemProgram.programId,
+            })
+            .rpc({ commitment: "confirmed" });
+    });
+
+    after(async () => {
+        await testState.program.methods
+            .setFeeSplit([], 0)
+            .accounts({ authority: testState.wallet.publicKey, platformConfig: testState.platformConfigPda })
+            .rpc({ commitment: "confirmed" });
+        await testState.program.methods
+            .setReferralShare(0)
+            .accounts({ authority: testState.wallet.publicKey, platformConfig: testState.platformConfigPda })
+            .rpc({ commitment: "confirmed" });
+        await testState.program.methods
+            .updateJackpotConfig(0, new BN(1))
+            .accounts({ authority: testState.wallet.publicKey, jackpotPool: pda(Buffer.from("jackpot")) })
+            .rpc({ commitment: "confirmed" });
+    });
+
+    it("Splits the house fee across the fee recipients", async () => {
+        await testState.program.methods
+            .setFeeSplit(
+                [
+                    { wallet: feeRecipients[0].publicKey, shareBasisPoints: 6_000 },
+                    { wallet: feeRecipients[1].publicKey, shareBasisPoints: 4_000 },
+                ],
+                0
+            )
+            .accounts({ authority: testState.wallet.publicKey, platformConfig: testState.platformConfigPda })
+            .rpc({ commitment: "confirmed" });
+
+        const round = await startRound();
+        await placeBet(playerOne, round);
+        await placeBet(playerTwo, round);
+        await sleep(ROUND_DURATION_SECONDS + 1);
+
+        const houseBefore = await testState.connection.getBalance(houseWallet);
+        const potBefore = await testState.connection.getBalance(round.gamePotPda);
+        await finalize(round);
+
+        const houseFee = BET.muln(2).muln(HOUSE_FEE_BASIS_POINTS).divn(10_000);
+        const firstShare = houseFee.muln(6_000).divn(10_000);
+        const secondShare = houseFee.muln(4_000).divn(10_000);
+        const dust = houseFee.sub(firstShare).sub(secondShare);
+
+        const roundState = await testState.program.account.roundState.fetch(round.roundStatePda);
+        assert.strictEqual(roundState.houseSolFee.toString(), houseFee.toString(), "House fee should be taken from the whole pot");
+        assert.strictEqual(
+            await testState.connection.getBalance(feeRecipients[0].publicKey),
+            firstShare.add(dust).toNumber(),
+            "First recipient should take its share and the dust"
+        );
+        assert.strictEqual(
+            await testState.connection.getBalance(feeRecipients[1].publicKey),
+            secondShare.toNumber(),
+            "Second recipient should take its share"
+        );
+        assert.strictEqual(await testState.connection.getBalance(houseWallet), houseBefore, "House wallet gets nothing while a split is set");
+        assert.strictEqual(
+            await testState.connection.getBalance(round.gamePotPda),
+            potBefore - houseFee.toNumber(),
+            "Only the house fee should leave the pot"
+        );
+    });
+
+    it("Accrues the referrer's share of the fee before the split", async () => {
+        await testState.program.methods
+            .setReferralShare(REFERRAL_SHARE_BASIS_POINTS)
+            .accounts({ authority: testState.wallet.publicKey, platformConfig: testState.platformConfigPda })
+            .rpc({ commitment: "confirmed" });
+        await testState.program.methods
+            .registerReferrer(referrer.publicKey)
+            .accounts({
+                user: playerOne.publicKey,
+                referralAccount: referralPda(playerOne.publicKey),
+                referrerStats: referrerStatsPda(referrer.publicKey),
+                systemProgram: anchor.web3.SystemProgram.programId,
+            })
+            .signers([playerOne])
+            .rpc({ commitment: "confirmed" });
+
+        const round = await startRound();
+        await placeBet(playerOne, round);
+        await placeBet(playerTwo, round);
+        await sleep(ROUND_DURATION_SECONDS + 1);
+
+        const recipientsBefore = await Promise.all(feeRecipients.map(r => testState.connection.getBalance(r.publicKey)));
+        await finalize(round);
+
+        // Half the fee is attributable to the referred player, and the referrer earns a share of that half
+        const houseFee = BET.muln(2).muln(HOUSE_FEE_BASIS_POINTS).divn(10_000);
+        const referralShare = houseFee.divn(2).muln(REFERRAL_SHARE_BASIS_POINTS).divn(10_000);
+
+        const referrerStats = await testState.program.account.referrerStats.fetch(referrerStatsPda(referrer.publicKey));
+        assert.strictEqual(referrerStats.accruedLamports.toString(), referralShare.toString(), "Referrer should accrue their share");
+
+        const recipientsAfter = await Promise.all(feeRecipients.map(r => testState.connection.getBalance(r.publicKey)));
+        const splitTotal = recipientsAfter.reduce((sum, balance, i) => sum + balance - recipientsBefore[i], 0);
+        assert.strictEqual(splitTotal, houseFee.sub(referralShare).toNumber(), "The split should get the fee less the referral share");
+    });
+
+    it("Referrer claims the accrued share into their escrow", async () => {
+        const escrowBefore = await testState.program.account.userPlatformEscrow.fetch(escrowPda(referrer.publicKey));
+        const statsBefore = await testState.program.account.referrerStats.fetch(referrerStatsPda(referrer.publicKey));
+
+        await testState.program.methods
+            .claimReferralEarnings()
+            .accounts({
+                referrer: referrer.publicKey,
+                referrerStats: referrerStatsPda(referrer.publicKey),
+                userPlatformEscrow: escrowPda(referrer.publicKey),
+            })
+            .signers([referrer])
+            .rpc({ commitment: "confirmed" });
+
+        const escrowAfter = await testState.program.account.userPlatformEscrow.fetch(escrowPda(referrer.publicKey));
+        assert.strictEqual(
+            escrowAfter.balance.sub(escrowBefore.balance).toString(),
+            statsBefore.accruedLamports.toString(),
+            "Escrow should be credited with the accrued share"
+        );
+        const statsAfter = await testState.program.account.referrerStats.fetch(referrerStatsPda(referrer.publicKey));
+        assert.strictEqual(statsAfter.accruedLamports.toNumber(), 0, "Nothing should be left to claim");
+    });
+
+    it("Diverts the jackpot contribution and pays the jackpot on a hit", async () => {
+        // Odds of 1 in 1 make every round a hit, so the draw is deterministic
+        await testState.program.methods
+            .initializeJackpot(JACKPOT_CONTRIBUTION_BASIS_POINTS, new BN(1))
+            .accounts({
+                authority: testState.wallet.publicKey,
+                platformConfig: testState.platformConfigPda,
+                jackpotPool: pda(Buffer.from("jackpot")),
+                systemProgram: anchor.web3.SystemProgram.programId,
+            })
+            .rpc({ commitment: "confirmed" });
+
+        const round = await startRound();
+        await placeBet(playerTwo, round);
+        await sleep(ROUND_DURATION_SECONDS + 1);
+        await finalize(round);
+
+        const houseFee = BET.muln(HOUSE_FEE_BASIS_POINTS).divn(10_000);
+        const contribution = BET.muln(JACKPOT_CONTRIBUTION_BASIS_POINTS).divn(10_000);
+        const roundState = await testState.program.account.roundState.fetch(round.roundStatePda);
+        assert.strictEqual(roundState.jackpotContribution.toString(), contribution.toString(), "Contribution should come out of the pot");
+        assert.strictEqual(
+            roundState.winnerSolAmount.toString(),
+            BET.sub(houseFee).sub(contribution).toString(),
+            "Winnings should be the pot less the fee and the contribution"
+        );
+        assert.isTrue(roundState.jackpotWinner.equals(playerTwo.publicKey), "The only bettor should hit the jackpot");
+        assert.strictEqual(roundState.jackpotAmount.toString(), contribution.toString(), "The jackpot should hold this round's contribution");
+
+        const jackpotPool = await testState.program.account.jackpotPool.fetch(pda(Buffer.from("jackpot")));
+        assert.strictEqual(jackpotPool.balance.toNumber(), 0, "A hit should empty the jackpot");
+        assert.strictEqual(jackpotPool.totalPaidOut.toString(), contribution.toString(), "The hit should be counted as paid out");
+
+        const escrowBefore = await testState.program.account.userPlatformEscrow.fetch(escrowPda(playerTwo.publicKey));
+        await testState.program.methods
+            .claimJackpot(tableId, round.id)
+            .accounts({
+                winnerSigner: playerTwo.publicKey,
+                userPlatformEscrow: escrowPda(playerTwo.publicKey),
+                roundState: round.roundStatePda,
+                gamePotSol: round.gamePotPda,
+                systemProgram: anchor.web3.SystemProgram.programId,
+            })
+            .signers([playerTwo])
+            .rpc({ commitment: "confirmed" });
+
+        const escrowAfter = await testState.program.account.userPlatformEscrow.fetch(escrowPda(playerTwo.publicKey));
+        assert.strictEqual(
+            escrowAfter.balance.sub(escrowBefore.balance).toString(),
+            contribution.toString(),
+            "Escrow should be credited with the jackpot"
+        );
+        const roundAfter = await testState.program.account.roundState.fetch(round.roundStatePda);
+        assert.strictEqual(roundAfter.jackpotClaimed, 1, "The jackpot should be marked claimed");
+    });
+});
@@ -0,0 +1,372 @@
+import * as anchor from "@coral-xyz/anchor";
+import type { Program } from "@coral-xyz/anchor";
+import { BN } from "bn.js";
+import {
+    TOKEN_2022_PROGRAM_ID,
+    ASSOCIATED_TOKEN_PROGRAM_ID,
+    ExtensionType,
+    createInitializeMintInstruction,
+    createInitializeTransferHookInstruction,
+    getAccount,
+    getAssociatedTokenAddressSync,
+    getMintLen,
+    getOrCreateAssociatedTokenAccount,
+    mintTo,
+} from "@solana/spl-token";
+import { assert } from "chai";
+import type { CashinoTransferHook } from "../target/types/cashino_transfer_hook";
+import { TestState } from "./state";
+
+// Runs after the game setup suite, which initializes the platform config.
+describe("Spin Wheel - token table with a transfer hook mint", () => {
+    let testState: TestState;
+    let hookProgram: Program<CashinoTransferHook>;
+
+    const mintKeypair = anchor.web3.Keypair.generate();
+    const DECIMALS = 6;
+    const MINTED = new BN(1_000_000_000);
+    const DEPOSIT = new BN(500_000_000);
+    const BET = new BN(200_000_000);
+    const WITHDRAW = new BN(100_000_000);
+    const HOUSE_FEE_BASIS_POINTS = 100;
+    const ROUND_DURATION_SECONDS = 5;
+
+    let tableId: anchor.BN;
+    let gameStatePda: anchor.web3.PublicKey;
+    let roundIdForSeed: anchor.BN;
+    let roundStatePda: anchor.web3.PublicKey;
+    let gamePotPda: anchor.web3.PublicKey;
+    let roundTokenVault: anchor.web3.PublicKey;
+    let houseWallet: anchor.web3.PublicKey;
+
+    let playerTokenAccount: anchor.web3.PublicKey;
+    let userTokenEscrowPda: anchor.web3.PublicKey;
+    let escrowTokenVault: anchor.web3.PublicKey;
+    let playerActivityPda: anchor.web3.PublicKey;
+
+    const mint = () => mintKeypair.publicKey;
+
+    const walletActivityPda = (owner: anchor.web3.PublicKey) =>
+        anchor.web3.PublicKey.findProgramAddressSync(
+            [Buffer.from("wallet_activity"), mint().toBuffer(), owner.toBuffer()],
+            hookProgram.programId
+        )[0];
+
+    // Accounts Token-2022 needs to invoke the hook for a transfer between these owners.
+    const hookAccounts = (sourceOwner: anchor.web3.PublicKey, destinationOwner: anchor.web3.PublicKey) => [
+        {
+            pubkey: anchor.web3.PublicKey.findProgramAddressSync(
+                [Buffer.from("extra-account-metas"), mint().toBuffer()],
+                hookProgram.programId
+            )[0],
+            isSigner: false,
+            isWritable: false,
+        },
+        {
+            pubkey: anchor.web3.PublicKey.findProgramAddressSync(
+                [Buffer.from("hook_config"), mint().toBuffer()],
+                hookProgram.programId
+            )[0],
+            isSigner: false,
+            isWritable: false,
+        },
+        { pubkey: walletActivityPda(sourceOwner), isSigner: false, isWritable: true },
+        { pubkey: walletActivityPda(destinationOwner), isSigner: false, isWritable: true },
+        { pubkey: hookProgram.programId, isSigner: false, isWritable: false },
+    ];
+
+    before(async () => {
+        testState = new TestState({ useGloballySharedMint: true });
+        hookProgram = anchor.workspace.CashinoTransferHook as Program<CashinoTransferHook>;
+        const player = testState.wallet.publicKey;
+
+        // Token-2022 mint whose transfers all go through the CASHINO hook program
+        const mintLen = getMintLen([ExtensionType.TransferHook]);
+        const lamports = await testState.connection.getMinimumBalanceForRentExemption(mintLen);
+        const createMintTx = new anchor.web3.Transaction().add(
+            anchor.web3.SystemProgram.createAccount({
+                fromPubkey: player,
+                newAccountPubkey: mint(),
+                space: mintLen,
+                lamports,
+                programId: TOKEN_2022_PROGRAM_ID,
+            }),
+            createInitializeTransferHookInstruction(mint(), player, hookProgram.programId, TOKEN_2022_PROGRAM_ID),
+            createInitializeMintInstruction(mint(), DECIMALS, player, null, TOKEN_2022_PROGRAM_ID)
+        );
+        await testState.provider.sendAndConfirm(createMintTx, [mintKeypair], { commitment: "confirmed" });
+        console.log(`Hooked mint created: ${mint().toBase58()}`);
+
+        await hookProgram.methods
+            .initializeExtraAccountMetaList({
+                postClaimCooldown: new BN(0),
+                rateLimitWindow: new BN(0),
+                maxTransferPerSlot: new BN(0),
+            })
+            .accounts({
+                payer: player,
+                authority: player,
+                mint: mint(),
+            })
+            .rpc({ commitment: "confirmed" });
+
+        // Tracking the player's wallet lets the test see that the hook ran on each transfer
+        playerActivityPda = walletActivityPda(player);
+        await hookProgram.methods
+            .initializeWalletActivity()
+            .accounts({
+                payer: player,
+                owner: player,
+                mint: mint(),
+            })
+            .rpc({ commitment: "confirmed" });
+
+        const playerAta = await getOrCreateAssociatedTokenAccount(
+            testState.connection,
+            testState.wallet.payer,
+            mint(),
+            player,
+            false,
+            "confirmed",
+            undefined,
+            TOKEN_2022_PROGRAM_ID,
+            ASSOCIATED_TOKEN_PROGRAM_ID
+        );
+        playerTokenAccount = playerAta.address;
+        await mintTo(
+            testState.connection,
+            testState.wallet.payer,
+            mint(),
+            playerTokenAccount,
+            player,
+            BigInt(MINTED.toString()),
+            [],
+            { commitment: "confirmed" },
+            TOKEN_2022_PROGRAM_ID
+        );
+
+        [userTokenEscrowPda] = anchor.web3.PublicKey.findProgramAddressSync(
+            [Buffer.from("user_token_escrow"), mint().toBuffer(), player.toBuffer()],
+            testState.program.programId
+        );
+        escrowTokenVault = getAssociatedTokenAddressSync(
+            mint(),
+            userTokenEscrowPda,
+            true,
+            TOKEN_2022_PROGRAM_ID,
+            ASSOCIATED_TOKEN_PROGRAM_ID
+        );
+
+        const platformConfig = await testState.program.account.platformConfig.fetch(testState.platformConfigPda);
+        tableId = platformConfig.tableCount;
+        houseWallet = platformConfig.houseWallet;
+        [gameStatePda] = anchor.web3.PublicKey.findProgramAddressSync(
+            [Buffer.from("game_state"), tableId.toBuffer("le", 8)],
+            testState.program.programId
+        );
+    });
+
+    it("Creates a table denominated in the hooked mint and starts a round", async () => {
+        await testState.program.methods
+            .createTable(tableId, HOUSE_FEE_BASIS_POINTS, new BN(1_000), new BN(1_000_000_000_000), new BN(ROUND_DURATION_SECONDS))
+            .accounts({
+                authority: testState.wallet.publicKey,
+                platformConfig: testState.platformConfigPda,
+                gameState: gameStatePda,
+                betMint: mint(),
+                systemProgram: anchor.web3.SystemProgram.programId,
+            })
+            .rpc({ commitment: "confirmed" });
+
+        const gameState = await testState.program.account.gameState.fetch(gameStatePda);
+        assert.isTrue(gameState.betMint.equals(mint()), "Table should be denominated in the hooked mint");
+        roundIdForSeed = gameState.roundCounter;
+
+        [roundStatePda] = anchor.web3.PublicKey.findProgramAddressSync(
+            [Buffer.from("round_state"), tableId.toBuffer("le", 8), roundIdForSeed.toBuffer("le", 8)],
+            testState.program.programId
+        );
+        [gamePotPda] = anchor.web3.PublicKey.findProgramAddressSync(
+            [Buffer.from("sol_pot"), tableId.toBuffer("le", 8), roundIdForSeed.toBuffer("le", 8)],
+            testState.program.programId
+        );
+        roundTokenVault = getAssociatedTokenAddressSync(mint(), gamePotPda, true, TOKEN_2022_PROGRAM_ID, ASSOCIATED_TOKEN_PROGRAM_ID);
+
+        await testState.program.methods
+            .startNewRound(tableId, testState.seedCommitmentBuffer, roundIdForSeed)
+            .accounts({
+                authority: testState.wallet.publicKey,
+                gameState: gameStatePda,
+                roundState: roundStatePda,
+                gamePot: gamePotPda,
+                systemProgram: anchor.web3.SystemProgram.programId,
+            })
+            .rpc({ commitment: "confirmed" });
+
+        await testState.program.methods
+            .createRoundTokenVault(tableId, roundIdForSeed)
+            .accounts({
+                authority: testState.wallet.publicKey,
+                gameState: gameStatePda,
+                roundState: roundStatePda,
+                gamePot: gamePotPda,
+                betMint: mint(),
+                roundTokenVault,
+                tokenProgram: TOKEN_2022_PROGRAM_ID,
+                associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+                systemProgram: anchor.web3.SystemProgram.programId,
+            })
+            .rpc({ commitment: "confirmed" });
+    });
+
+    it("Deposits hooked tokens into the platform escrow", async () => {
+        const player = testState.wallet.publicKey;
+        await testState.program.methods
+            .depositToken(DEPOSIT)
+            .accounts({
+                user: player,
+                betMint: mint(),
+                userTokenAccount: playerTokenAccount,
+                userTokenEscrow: userTokenEscrowPda,
+                escrowTokenVault,
+                tokenProgram: TOKEN_2022_PROGRAM_ID,
+                associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+                systemProgram: anchor.web3.SystemProgram.programId,
+            })
+            .remainingAccounts(hookAccounts(player, userTokenEscrowPda))
+            .rpc({ commitment: "confirmed" });
+
+        const vault = await getAccount(testState.connection, escrowTokenVault, "confirmed", TOKEN_2022_PROGRAM_ID);
+        assert.strictEqual(vault.amount.toString(), DEPOSIT.toString(), "Escrow vault should hold the deposit");
+        const escrow = await testState.program.account.userTokenEscrow.fetch(userTokenEscrowPda);
+        assert.strictEqual(escrow.balance.toString(), DEPOSIT.toString(), "Escrow balance should match the deposit");
+
+        const activity = await hookProgram.account.walletActivity.fetch(playerActivityPda);
+        assert.strictEqual(activity.transferCount.toNumber(), 1, "The hook should have recorded the deposit");
+        assert.strictEqual(activity.totalSent.toString(), DEPOSIT.toString(), "The hook should have recorded the amount sent");
+    });
+
+    it("Places a bet in the hooked mint", async () => {
+        const player = testState.wallet.publicKey;
+        await testState.program.methods
+            .placeTokenBet(tableId, roundIdForSeed, BET)
+            .accounts({
+                player,
+                gameState: gameStatePda,
+                betMint: mint(),
+                userTokenEscrow: userTokenEscrowPda,
+                escrowTokenVault,
+                roundState: roundStatePda,
+                gamePot: gamePotPda,
+                roundTokenVault,
+                tokenProgram: TOKEN_2022_PROGRAM_ID,
+            })
+            .remainingAccounts(hookAccounts(userTokenEscrowPda, gamePotPda))
+            .rpc({ commitment: "confirmed" });
+
+        const vault = await getAccount(testState.connection, roundTokenVault, "confirmed", TOKEN_2022_PROGRAM_ID);
+        assert.strictEqual(vault.amount.toString(), BET.toString(), "Round vault should hold the bet");
+        const roundState = await testState.program.account.roundState.fetch(roundStatePda);
+        assert.strictEqual(roundState.totalSolPot.toString(), BET.toString(), "Round pot should count the bet");
+
+        console.log(`Waiting ${ROUND_DURATION_SECONDS + 1}s for the round to end...`);
+        await new Promise(resolve => setTimeout(resolve, (ROUND_DURATION_SECONDS + 1) * 1000));
+    });
+
+    it("Finalizes the round and pays the house fee in the hooked mint", async () => {
+        const houseTokenAccount = getAssociatedTokenAddressSync(mint(), houseWallet, false, TOKEN_2022_PROGRAM_ID, ASSOCIATED_TOKEN_PROGRAM_ID);
+        await testState.program.methods
+            .finalizeTokenRound(tableId, testState.revealedSeedBuffer, roundIdForSeed)
+            .accounts({
+                authority: testState.wallet.publicKey,
+                gameState: gameStatePda,
+                roundState: roundStatePda,
+                gamePot: gamePotPda,
+                betMint: mint(),
+                roundTokenVault,
+                houseWallet,
+                houseTokenAccount,
+                tokenProgram: TOKEN_2022_PROGRAM_ID,
+                associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+                systemProgram: anchor.web3.SystemProgram.programId,
+            })
+            .remainingAccounts(hookAccounts(gamePotPda, houseWallet))
+            .rpc({ commitment: "confirmed" });
+
+        const expectedFee = BET.muln(HOUSE_FEE_BASIS_POINTS).divn(10_000);
+        const houseAccount = await getAccount(testState.connection, houseTokenAccount, "confirmed", TOKEN_2022_PROGRAM_ID);
+        assert.strictEqual(houseAccount.amount.toString(), expectedFee.toString(), "House should receive the fee");
+
+        const roundState = await testState.program.account.roundState.fetch(roundStatePda);
+        assert.strictEqual(roundState.statusDiscriminant, 1, "Round should be awaiting the winner's claim");
+        assert.isTrue(roundState.winnerSolPubkey.equals(testState.wallet.publicKey), "The only bettor should win");
+        assert.strictEqual(roundState.winnerSolAmount.toString(), BET.sub(expectedFee).toString(), "Winnings should be the pot less the fee");
+    });
+
+    it("Claims the winnings into the escrow through the hook", async () => {
+        const player = testState.wallet.publicKey;
+        const roundStateBefore = await testState.program.account.roundState.fetch(roundStatePda);
+        const winnings = roundStateBefore.winnerSolAmount;
+        const vaultBefore = await getAccount(testState.connection, escrowTokenVault, "confirmed", TOKEN_2022_PROGRAM_ID);
+
+        await testState.program.methods
+            .claimTokenWinnings(tableId, roundIdForSeed)
+            .accounts({
+                winnerSigner: player,
+                gameState: gameStatePda,
+                betMint: mint(),
+                userTokenEscrow: userTokenEscrowPda,
+                escrowTokenVault,
+                roundState: roundStatePda,
+                gamePot: gamePotPda,
+                roundTokenVault,
+                tokenProgram: TOKEN_2022_PROGRAM_ID,
+            })
+            .remainingAccounts(hookAccounts(gamePotPda, userTokenEscrowPda))
+            .rpc({ commitment: "confirmed" });
+
+        const vaultAfter = await getAccount(testState.connection, escrowTokenVault, "confirmed", TOKEN_2022_PROGRAM_ID);
+        assert.strictEqual(
+            (vaultAfter.amount - vaultBefore.amount).toString(),
+            winnings.toString(),
+            "Escrow vault should receive the winnings"
+        );
+        const roundStateAfter = await testState.program.account.roundState.fetch(roundStatePda);
+        assert.strictEqual(roundStateAfter.winnerSolClaimed, 1, "Winnings should be marked claimed");
+    });
+
+    it("Withdraws hooked tokens from the platform escrow", async () => {
+        const player = testState.wallet.publicKey;
+        const playerBefore = await getAccount(testState.connection, playerTokenAccount, "confirmed", TOKEN_2022_PROGRAM_ID);
+        const escrowBefore = await testState.program.account.userTokenEscrow.fetch(userTokenEscrowPda);
+
+        await testState.program.methods
+            .withdrawTokenFromPlatform(WITHDRAW)
+            .accounts({
+                user: player,
+                betMint: mint(),
+                userTokenEscrow: userTokenEscrowPda,
+                escrowTokenVault,
+                userTokenAccount: playerTokenAccount,
+                tokenProgram: TOKEN_2022_PROGRAM_ID,
+            })
+            .remainingAccounts(hookAccounts(userTokenEscrowPda, player))
+            .rpc({ commitment: "confirmed" });
+
+        const playerAfter = await getAccount(testState.connection, playerTokenAccount, "confirmed", TOKEN_2022_PROGRAM_ID);
+        assert.strictEqual(
+            (playerAfter.amount - playerBefore.amount).toString(),
+            WITHDRAW.toString(),
+            "Player should receive the withdrawal"
+        );
+        const escrowAfter = await testState.program.account.userTokenEscrow.fetch(userTokenEscrowPda);
+        assert.strictEqual(
+            escrowAfter.balance.toString(),
+            escrowBefore.balance.sub(WITHDRAW).toString(),
+            "Escrow balance should drop by the withdrawal"
+        );
+
+        const activity = await hookProgram.account.walletActivity.fetch(playerActivityPda);
+        assert.strictEqual(activity.totalReceived.toString(), WITHDRAW.toString(), "The hook should have recorded the withdrawal");
+    });
+});
@@ -8,7 +8,7 @@ import {
     getMint
 } from "@solana/spl-token";
 import { assert } from "chai";
-import { TestState } from "./state";
+import { TestState, DEFAULT_MINT_CONFIG } from "./state";
 
 const provider = anchor.AnchorProvider.env();
 anchor.setProvider(provider);
@@ -50,7 +50,7 @@ describe('spin-wheel token tests with PDA mint authority', () => {
         console.log(`Test: Client-derived Mint Authority PDA: ${testState.mintAuthorityPda.toBase58()}`);
 
         const transactionSignature = await testState.program.methods
-            .initializeToken2022(transferFeeBasisPoints, maximumFee)
+            .initializeToken2022(transferFeeBasisPoints, maximumFee, "Cashino", "CASHINO", "", [], DEFAULT_MINT_CONFIG)
             .accounts({
                 payer: testState.wallet.publicKey,
                 mintAccount: testState.mintKeypair.publicKey,
@@ -82,6 +82,27 @@ describe('spin-wheel token tests with PDA mint authority', () => {
         assert.ok(testState.recipientTokenAccountAddress, "Recipient ATA should be set");
     });
 
+    it("Initializes the platform config", async () => {
+        // Minting through the program needs the platform authority, so the platform is set up here
+        // with the shared mint and house wallet that the game suites use.
+        const transactionSignature = await testState.program.methods
+            .initializePlatform()
+            .accounts({
+                authority: testState.wallet.publicKey,
+                platformConfig: testState.platformConfigPda,
+                houseWallet: testState.houseWalletKeypair.publicKey,
+                cashinoTokenMint: testState.cashinoMintPublicKey,
+                token2022Program: TOKEN_2022_PROGRAM_ID,
+                systemProgram: anchor.web3.SystemProgram.programId,
+            })
+            .rpc({ skipPreflight: true, commitment: "confirmed" });
+        await testState.confirmTx(transactionSignature);
+
+        const platformConfigAccount = await testState.program.account.platformConfig.fetch(testState.platformConfigPda);
+        assert.isTrue(platformConfigAccount.authority.equals(testState.wallet.publicKey), "PlatformConfig authority mismatch");
+        assert.strictEqual(platformConfigAccount.isInitialized, true, "PlatformConfig should be initialized");
+    });
+
     it("Mint Tokens to Sender's Account via Program Instruction", async () => {
         const amountToMint = new BN(50000);
 
@@ -103,6 +124,8 @@ describe('spin-wheel token tests with PDA mint authority', () => {
         const transactionSignature = await testState.program.methods
             .mintTokensToAccount(amountToMint)
             .accounts({
+                authority: testState.wallet.publicKey,
+                platformConfig: testState.platformConfigPda,
                 mintAuthorityPda: testState.mintAuthorityPda,
                 mintAccount: testState.mintKeypair.publicKey,
                 recipientTokenAccount: testState.senderTokenAccountAddress!,
@@ -115,6 +138,30 @@ describe('spin-wheel token tests with PDA mint authority', () => {
 
         const accountInfo = await getAccount(testState.connection, testState.senderTokenAccountAddress!, "confirmed", TOKEN_2022_PROGRAM_ID);
         assert.strictEqual(accountInfo.amount.toString(), amountToMint.toString(), "Sender account balance should match minted amount");
+
+        const platformConfigAccount = await testState.program.account.platformConfig.fetch(testState.platformConfigPda);
+        assert.strictEqual(platformConfigAccount.cashinoMinted.toString(), amountToMint.toString(), "Minted total should count the mint");
+    });
+
+    it("Rejects a mint not signed by the platform authority", async () => {
+        const outsider = anchor.web3.Keypair.generate();
+        try {
+            await testState.program.methods
+                .mintTokensToAccount(new BN(1))
+                .accounts({
+                    authority: outsider.publicKey,
+                    platformConfig: testState.platformConfigPda,
+                    mintAuthorityPda: testState.mintAuthorityPda,
+                    mintAccount: testState.mintKeypair.publicKey,
+                    recipientTokenAccount: testState.senderTokenAccountAddress!,
+                    tokenProgram: TOKEN_2022_PROGRAM_ID,
+                })
+                .signers([outsider])
+                .rpc({ commitment: "confirmed" });
+            assert.fail("Mint by a non-authority should fail");
+        } catch (error: any) {
+            assert.include(error.toString(), "UnauthorizedAccess");
+        }
     });
 
     it("Transfer tokens from sender to recipient", async () => {
@@ -176,42 +223,58 @@ describe('spin-wheel token tests with PDA mint authority', () => {
         console.log(`Transferred again ${amountToTransfer.toString()} tokens.`);
     });
 
-    it('Harvest Transfer Fees to Mint Account', async () => {
-        assert.ok(testState.recipientTokenAccountAddress, "Recipient ATA must be set before harvest");
-        assert.ok(testState.senderTokenAccountAddress, "Sender ATA must be set before harvest");
+    it('Sets the platform fee treasury', async () => {
+        assert.ok(testState.recipientTokenAccountAddress, "Recipient ATA must be set before setting the treasury");
 
         const transactionSignature = await testState.program.methods
-            .harvest()
+            .setFeeTreasury()
             .accounts({
-                mintAccount: testState.mintKeypair.publicKey,
+                authority: testState.wallet.publicKey,
+                platformConfig: testState.platformConfigPda,
+                cashinoMint: testState.mintKeypair.publicKey,
+                treasuryOwner: testState.recipientKeypair.publicKey,
+                feeTreasury: testState.recipientTokenAccountAddress!,
                 tokenProgram: TOKEN_2022_PROGRAM_ID,
             })
-            .remainingAccounts([
-                { pubkey: testState.senderTokenAccountAddress!, isSigner: false, isWritable: true },
-                { pubkey: testState.recipientTokenAccountAddress!, isSigner: false, isWritable: true },
-            ])
-            .rpc({ skipPreflight: true, commitment: "confirmed" });
+            .rpc({ commitment: "confirmed" });
 
         await testState.confirmTx(transactionSignature);
-        console.log('Harvested transfer fees.');
+        const platformConfig = await testState.program.account.platformConfig.fetch(testState.platformConfigPda);
+        assert.ok(platformConfig.feeTreasury.equals(testState.recipientTokenAccountAddress!));
+        console.log('Set the platform fee treasury to the recipient ATA.');
     });
 
-    it('Withdraw Transfer Fees from Mint Account to Sender ATA', async () => {
-        assert.ok(testState.senderTokenAccountAddress, "Sender ATA must be set before withdraw");
+    it('Collects withheld transfer fees into the platform fee treasury', async () => {
+        const treasury = testState.recipientTokenAccountAddress!;
+        const before = await getAccount(testState.connection, treasury, "confirmed", TOKEN_2022_PROGRAM_ID);
+        const configBefore = await testState.program.account.platformConfig.fetch(testState.platformConfigPda);
 
         const transactionSignature = await testState.program.methods
-            .withdraw()
+            .collectTransferFees()
             .accounts({
-                pdaAuthority: testState.mintAuthorityPda,
-                mintAccount: testState.mintKeypair.publicKey,
-                tokenAccount: testState.senderTokenAccountAddress!,
+                platformConfig: testState.platformConfigPda,
+                mintAuthorityPda: testState.mintAuthorityPda,
+                cashinoMint: testState.mintKeypair.publicKey,
+                feeTreasury: treasury,
+                feeBurnVault: null,
                 tokenProgram: TOKEN_2022_PROGRAM_ID,
             })
-            .signers([testState.wallet.payer])
-            .rpc({ skipPreflight: true, commitment: "confirmed" });
-
+            .remainingAccounts([
+                { pubkey: testState.senderTokenAccountAddress!, isSigner: false, isWritable: true },
+                { pubkey: testState.recipientTokenAccountAddress!, isSigner: false, isWritable: true },
+            ])
+            .rpc({ commitment: "confirmed" });
         await testState.confirmTx(transactionSignature);
-        console.log('Withdrew transfer fees from mint to sender ATA.');
+
+        const after = await getAccount(testState.connection, treasury, "confirmed", TOKEN_2022_PROGRAM_ID);
+        const configAfter = await testState.program.account.platformConfig.fetch(testState.platformConfigPda);
+        const collected = after.amount - before.amount;
+        assert.isTrue(collected > BigInt(0), "The two transfers above withheld fees");
+        assert.equal(
+            configAfter.transferFeesCollected.sub(configBefore.transferFeesCollected).toString(),
+            collected.toString()
+        );
+        assert.equal(configAfter.feesBurned.toString(), configBefore.feesBurned.toString());
     });
 
     it('Update Transfer Fee to zero', async () => {
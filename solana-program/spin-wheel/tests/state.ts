@@ -8,6 +8,18 @@ import {
 } from "@solana/spl-token";
 import type { SpinWheel } from "../target/types/spin_wheel";
 import { assert } from "chai";
+import { createHash } from "crypto";
+
+// Matches MintInitConfig::default() in lib.rs: the original CASHINO mint settings.
+export const DEFAULT_MINT_CONFIG = {
+    decimals: 2,
+    freezeAuthority: true,
+    interestRate: null,
+    permanentDelegate: null,
+    defaultAccountStateFrozen: false,
+    closeAuthority: null,
+    transferHookProgramId: null,
+};
 
 export interface TestStateOptions {
     useGloballySharedMint?: boolean;
@@ -27,6 +39,7 @@ export class TestState {
     player2Keypair: anchor.web3.Keypair;
 
     mintAuthorityPda: anchor.web3.PublicKey;
+    platformConfigPda: anchor.web3.PublicKey;
     gameStatePda: anchor.web3.PublicKey;
 
     senderTokenAccountAddress?: anchor.web3.PublicKey;
@@ -40,13 +53,16 @@ export class TestState {
     userPlatformEscrowPda?: anchor.web3.PublicKey;
     player2PlatformEscrowPda?: anchor.web3.PublicKey;
 
+    readonly TABLE_ID = new BN(0);
     readonly CASHINO_REWARD_PER_ROUND_UNITS = new BN(1_000_000);
-    readonly WITHDRAWAL_FEE_LAMPORTS = new BN(10_000_000);
+    readonly DEFAULT_WITHDRAWAL_MIN_FEE_LAMPORTS = new BN(10_000_000);
     readonly RAW_SEED = "test_seed_commitment_for_round_1";
     readonly SEED_LEN = 32;
+    revealedSeedBuffer: Buffer;
     seedCommitmentBuffer: Buffer;
 
     private static _globallySharedMintKeypair: anchor.web3.Keypair | undefined;
+    private static _globallySharedHouseWalletKeypair: anchor.web3.Keypair | undefined;
 
     constructor(options: TestStateOptions = {}) {
         this.provider = anchor.AnchorProvider.env();
@@ -72,15 +88,27 @@ export class TestState {
         }
 
         this.recipientKeypair = anchor.web3.Keypair.generate();
-        this.houseWalletKeypair = anchor.web3.Keypair.generate();
+        // Suites sharing the mint also share the platform config, and with it the house wallet
+        if (options.useGloballySharedMint) {
+            if (!TestState._globallySharedHouseWalletKeypair) {
+                TestState._globallySharedHouseWalletKeypair = anchor.web3.Keypair.generate();
+            }
+            this.houseWalletKeypair = TestState._globallySharedHouseWalletKeypair;
+        } else {
+            this.houseWalletKeypair = anchor.web3.Keypair.generate();
+        }
         this.player2Keypair = anchor.web3.Keypair.generate();
 
         [this.mintAuthorityPda] = anchor.web3.PublicKey.findProgramAddressSync(
             [Buffer.from("mint_authority")],
             this.program.programId
         );
+        [this.platformConfigPda] = anchor.web3.PublicKey.findProgramAddressSync(
+            [Buffer.from("platform_config")],
+            this.program.programId
+        );
         [this.gameStatePda] = anchor.web3.PublicKey.findProgramAddressSync(
-            [Buffer.from("game_state")],
+            [Buffer.from("game_state"), this.TABLE_ID.toBuffer("le", 8)],
             this.program.programId
         );
 
@@ -93,8 +121,10 @@ export class TestState {
             this.program.programId
         );
 
-        this.seedCommitmentBuffer = Buffer.alloc(this.SEED_LEN);
-        this.seedCommitmentBuffer.write(this.RAW_SEED, "ascii");
+        this.revealedSeedBuffer = Buffer.alloc(this.SEED_LEN);
+        this.revealedSeedBuffer.write(this.RAW_SEED, "ascii");
+        // Rounds commit to the SHA-256 of the seed revealed at finalization.
+        this.seedCommitmentBuffer = createHash("sha256").update(this.revealedSeedBuffer).digest();
     }
 
     async confirmTx(txSignature: string): Promise<void> {
@@ -117,17 +147,17 @@ export class TestState {
         }
 
         [this.roundStatePda] = anchor.web3.PublicKey.findProgramAddressSync(
-            [Buffer.from("round_state"), this.currentRoundIdForSeed.toBuffer("le", 8)],
+            [Buffer.from("round_state"), this.TABLE_ID.toBuffer("le", 8), this.currentRoundIdForSeed.toBuffer("le", 8)],
             this.program.programId
         );
 
         [this.gamePotSolPda] = anchor.web3.PublicKey.findProgramAddressSync(
-            [Buffer.from("sol_pot"), this.currentRoundIdForSeed.toBuffer("le", 8)],
+            [Buffer.from("sol_pot"), this.TABLE_ID.toBuffer("le", 8), this.currentRoundIdForSeed.toBuffer("le", 8)],
             this.program.programId
         );
 
         [this.roundCashinoRewardsPotAccountPda] = anchor.web3.PublicKey.findProgramAddressSync(
-            [Buffer.from("cashino_round_pot"), this.currentRoundIdForSeed.toBuffer("le", 8)],
+            [Buffer.from("cashino_round_pot"), this.TABLE_ID.toBuffer("le", 8), this.currentRoundIdForSeed.toBuffer("le", 8)],
             this.program.programId
         );
 